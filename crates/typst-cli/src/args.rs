@@ -271,6 +271,12 @@ pub struct CompileArgs {
     /// apart from file names and line numbers.
     #[arg(long = "timings", value_name = "OUTPUT_JSON")]
     pub timings: Option<Option<PathBuf>>,
+
+    /// Prints how much time was spent in each step of the compilation process,
+    /// e.g. in the layout of paragraphs versus tables, and how often it was
+    /// performed, e.g. how many regions were finished. (experimental)
+    #[arg(long = "timings-summary")]
+    pub timings_summary: bool,
 }
 
 /// Arguments for the construction of a world. Shared by compile, watch, and
@@ -1,18 +1,23 @@
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{self, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
+use codespan_reporting::term::{self, termcolor::WriteColor};
+use ecow::eco_format;
 use typst::diag::{bail, StrResult};
 use typst::syntax::Span;
 use typst::World;
 
 use crate::args::{CliArguments, Command};
+use crate::terminal;
 use crate::world::SystemWorld;
 
 /// Allows to record timings of function executions.
 pub struct Timer {
     /// Where to save the recorded timings of each compilation step.
     path: Option<PathBuf>,
+    /// Whether to print a summary of the recorded timings.
+    summary: bool,
     /// The current watch iteration.
     index: usize,
 }
@@ -21,35 +26,46 @@ impl Timer {
     /// Initializes the timing system and returns a timer that can be used to
     /// record timings for a specific function invocation.
     pub fn new(args: &CliArguments) -> Timer {
-        let record = match &args.command {
-            Command::Compile(command) => command.args.timings.clone(),
-            Command::Watch(command) => command.args.timings.clone(),
-            _ => None,
+        let (record, summary) = match &args.command {
+            Command::Compile(command) => {
+                (command.args.timings.clone(), command.args.timings_summary)
+            }
+            Command::Watch(command) => {
+                (command.args.timings.clone(), command.args.timings_summary)
+            }
+            _ => (None, false),
         };
 
         // Enable event collection.
-        if record.is_some() {
+        if record.is_some() || summary {
             typst_timing::enable();
         }
 
         let path =
             record.map(|path| path.unwrap_or_else(|| PathBuf::from("record-{n}.json")));
 
-        Timer { path, index: 0 }
+        Timer { path, summary, index: 0 }
     }
 
-    /// Records all timings in `f` and writes them to disk.
+    /// Records all timings in `f`, writes them to disk, and prints a summary
+    /// if requested.
     pub fn record<T>(
         &mut self,
         world: &mut SystemWorld,
         f: impl FnOnce(&mut SystemWorld) -> T,
     ) -> StrResult<T> {
-        let Some(path) = &self.path else {
+        if self.path.is_none() && !self.summary {
             return Ok(f(world));
-        };
+        }
 
         typst_timing::clear();
 
+        let Some(path) = &self.path else {
+            let output = f(world);
+            print_summary()?;
+            return Ok(output);
+        };
+
         let string = path.to_str().unwrap_or_default();
         let numbered = string.contains("{n}");
         if !numbered && self.index > 0 {
@@ -76,10 +92,43 @@ impl Timer {
                 .unwrap_or_else(|| ("unknown".to_string(), 0))
         })?;
 
+        if self.summary {
+            print_summary()?;
+        }
+
         Ok(output)
     }
 }
 
+/// Prints the recorded timings aggregated by compilation step to the terminal.
+fn print_summary() -> StrResult<()> {
+    write_summary(&mut terminal::out())
+        .map_err(|err| eco_format!("failed to print timing summary ({err})"))
+}
+
+/// Writes the recorded timings aggregated by compilation step.
+fn write_summary(out: &mut impl WriteColor) -> io::Result<()> {
+    let summaries = typst_timing::summarize();
+    let width = summaries.iter().map(|s| s.name.len()).max().unwrap_or(0);
+    let styles = term::Styles::default();
+
+    out.set_color(&styles.header_help)?;
+    writeln!(out, "{:<width$}  {:>8}  {:>12}", "step", "calls", "total")?;
+    out.reset()?;
+
+    for summary in summaries {
+        writeln!(
+            out,
+            "{:<width$}  {:>8}  {:>9.2} ms",
+            summary.name,
+            summary.calls,
+            summary.total.as_secs_f64() * 1000.0,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Turns a span into a (file, line) pair.
 fn resolve_span(world: &SystemWorld, span: Span) -> Option<(String, u32)> {
     let id = span.id()?;
@@ -13,6 +13,7 @@ use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 use fontdb::{Database, Source};
+use typst_library::foundations::Bytes;
 use typst_library::text::{Font, FontBook, FontInfo};
 use typst_timing::TimingScope;
 
@@ -30,7 +31,7 @@ pub struct FontSlot {
 
 impl FontSlot {
     /// Returns the path at which the font can be found on the system, or `None`
-    /// if the font was embedded.
+    /// if the font was embedded or added from memory.
     pub fn path(&self) -> Option<&Path> {
         self.path.as_deref()
     }
@@ -79,9 +80,13 @@ impl Fonts {
 /// Searches for fonts.
 ///
 /// Fonts are added in the following order (descending priority):
-/// 1. Font directories
-/// 2. System fonts (if included & enabled)
-/// 3. Embedded fonts (if enabled)
+/// 1. Font data added with [`add_font_data`][Self::add_font_data]
+/// 2. Font directories
+/// 3. System fonts (if included & enabled)
+/// 4. Embedded fonts (if enabled)
+///
+/// If multiple fonts have the same family name and variant, the one with the
+/// highest priority is selected.
 #[derive(Debug)]
 pub struct FontSearcher {
     db: Database,
@@ -119,6 +124,36 @@ impl FontSearcher {
         self
     }
 
+    /// Add fonts from data in memory instead of a file, e.g. a font shipped
+    /// with a tool that integrates Typst.
+    ///
+    /// The data may be a single font or a font collection (`.ttc`), in which
+    /// case all of its fonts are added. Data that doesn't contain a valid font
+    /// is ignored. The fonts can then be selected by their family name just
+    /// like fonts found on the system.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use typst_kit::fonts::FontSearcher;
+    /// # use typst_library::foundations::Bytes;
+    /// let data = std::fs::read("MyFont.ttf").unwrap();
+    /// let fonts = FontSearcher::new()
+    ///     .add_font_data(Bytes::from(data))
+    ///     .search();
+    /// assert!(fonts.book.contains_family("myfont"));
+    /// ```
+    pub fn add_font_data(&mut self, data: Bytes) -> &mut Self {
+        for (i, font) in Font::iter(data).enumerate() {
+            self.book.push(font.info().clone());
+            self.fonts.push(FontSlot {
+                path: None,
+                index: i as u32,
+                font: OnceLock::from(Some(font)),
+            });
+        }
+        self
+    }
+
     /// Start searching for and loading fonts. To additionally load fonts
     /// from specific directories, use [`search_with`][Self::search_with].
     ///
@@ -196,7 +231,7 @@ impl FontSearcher {
     #[cfg(feature = "embed-fonts")]
     fn add_embedded(&mut self) {
         for data in typst_assets::fonts() {
-            let buffer = typst_library::foundations::Bytes::from_static(data);
+            let buffer = Bytes::from_static(data);
             for (i, font) in Font::iter(buffer).enumerate() {
                 self.book.push(font.info().clone());
                 self.fonts.push(FontSlot {
@@ -214,3 +249,93 @@ impl Default for FontSearcher {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Combines single fonts into a collection by prepending a collection
+    /// header and shifting the table offsets of each font accordingly.
+    fn collection(fonts: &[&[u8]]) -> Vec<u8> {
+        let header = 12 + 4 * fonts.len();
+        let mut starts = vec![];
+        let mut start = header;
+        for font in fonts {
+            starts.push(start);
+            start += font.len().next_multiple_of(4);
+        }
+
+        let mut out = b"ttcf".to_vec();
+        out.extend(0x0001_0000_u32.to_be_bytes());
+        out.extend((fonts.len() as u32).to_be_bytes());
+        for &start in &starts {
+            out.extend((start as u32).to_be_bytes());
+        }
+
+        for (font, &start) in fonts.iter().zip(&starts) {
+            let mut data = font.to_vec();
+            data.resize(font.len().next_multiple_of(4), 0);
+            let tables = u16::from_be_bytes([data[4], data[5]]) as usize;
+            for i in 0..tables {
+                let at = 12 + 16 * i + 8;
+                let offset = u32::from_be_bytes(data[at..at + 4].try_into().unwrap());
+                let shifted = offset + start as u32;
+                data[at..at + 4].copy_from_slice(&shifted.to_be_bytes());
+            }
+            out.extend(data);
+        }
+
+        out
+    }
+
+    #[test]
+    fn test_font_data_takes_precedence() {
+        // Pick two single fonts with distinct families.
+        let mut picked: Vec<(&'static [u8], FontInfo)> = vec![];
+        for data in typst_dev_assets::fonts() {
+            if data.starts_with(b"ttcf") {
+                continue;
+            }
+            let Some(font) = Font::iter(Bytes::from_static(data)).next() else {
+                continue;
+            };
+            if picked.iter().all(|(_, info)| info.family != font.info().family) {
+                picked.push((data, font.info().clone()));
+            }
+            if picked.len() == 2 {
+                break;
+            }
+        }
+        let [(first, a), (second, b)] = picked.as_slice() else {
+            panic!("expected at least two fonts");
+        };
+
+        // Provide a duplicate of the first font through a font directory.
+        let dir = std::env::temp_dir().join(format!("typst-kit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("duplicate.ttf"), *first).unwrap();
+
+        let fonts = FontSearcher::new()
+            .include_system_fonts(false)
+            .add_font_data(Bytes::from(collection(&[*first, *second])))
+            .search_with([&dir]);
+        fs::remove_dir_all(&dir).unwrap();
+
+        // Both fonts of the collection are registered, followed by the
+        // duplicate from the directory.
+        assert!(fonts.fonts.len() >= 3);
+        assert_eq!(fonts.fonts[0].index(), 0);
+        assert_eq!(fonts.fonts[1].index(), 1);
+        assert!(fonts.fonts[2].path().is_some());
+
+        // The in-memory fonts are selected over the duplicate.
+        let select = |info: &FontInfo| {
+            fonts.book.select(&info.family.to_lowercase(), info.variant).unwrap()
+        };
+        assert_eq!(select(a), 0);
+        assert_eq!(select(b), 1);
+        assert!(fonts.fonts[0].path().is_none());
+        assert_eq!(fonts.fonts[0].get().unwrap().info().family, a.family);
+        assert_eq!(fonts.fonts[1].get().unwrap().info().family, b.family);
+    }
+}
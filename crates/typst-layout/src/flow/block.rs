@@ -1,18 +1,18 @@
 use std::cell::LazyCell;
 
 use smallvec::SmallVec;
-use typst_library::diag::SourceResult;
+use typst_library::diag::{bail, SourceResult};
 use typst_library::engine::Engine;
 use typst_library::foundations::{Packed, Resolve, StyleChain};
-use typst_library::introspection::Locator;
+use typst_library::introspection::{Locator, Tag};
 use typst_library::layout::{
-    Abs, Axes, BlockBody, BlockElem, Fragment, Frame, FrameKind, Region, Regions, Rel,
-    Sides, Size, Sizing,
+    Abs, Axes, BlockBody, BlockElem, Corners, Fragment, Frame, FrameItem, FrameKind,
+    Overflow, Point, Region, Regions, Rel, Sides, Size, Sizing,
 };
 use typst_library::visualize::Stroke;
 use typst_utils::Numeric;
 
-use crate::shapes::{clip_rect, fill_and_stroke};
+use crate::shapes::{clip_rect, fill_and_stroke, fill_styles};
 
 /// Lay this out as an unbreakable block.
 #[typst_macros::time(name = "block", span = elem.span())]
@@ -31,6 +31,13 @@ pub fn layout_single_block(
     // Build the pod regions.
     let pod = unbreakable_pod(&width.into(), &height, &inset, styles, region.size);
 
+    // Text with an automatic fill contrasts with the block's own fill. The
+    // block's body is a flow that distributes fractional spacing as
+    // configured on the block.
+    let mut local = fill_styles(elem.fill(styles).as_ref());
+    local.set(BlockElem::set_fr_order(elem.fr_order(styles)));
+    let styles = styles.chain(&local);
+
     // Layout the body.
     let body = elem.body(styles);
     let mut frame = match body {
@@ -121,6 +128,13 @@ pub fn layout_multi_block(
     // Build the pod regions.
     let pod = breakable_pod(&width.into(), &height, &inset, styles, regions, &mut buf);
 
+    // Text with an automatic fill contrasts with the block's own fill. The
+    // block's body is a flow that distributes fractional spacing as
+    // configured on the block.
+    let mut local = fill_styles(elem.fill(styles).as_ref());
+    local.set(BlockElem::set_fr_order(elem.fr_order(styles)));
+    let styles = styles.chain(&local);
+
     // Layout the body.
     let body = elem.body(styles);
     let mut fragment = match body {
@@ -187,6 +201,42 @@ pub fn layout_multi_block(
         }
     };
 
+    // If the regions are bounded because of a fixed height, the flow keeps
+    // producing frames for content that does not fit into them. Handle these
+    // according to the overflow policy.
+    if pod.last.is_none() {
+        let available = pod.iter().count();
+        if fragment.len() > available {
+            match elem.overflow(styles) {
+                Overflow::Grow => {}
+                Overflow::Error => bail!(
+                    elem.span(), "content does not fit into the block";
+                    hint: "try increasing its height or using `overflow: \"clip\"`",
+                ),
+                Overflow::Clip => {
+                    let mut frames = fragment.into_frames();
+                    let clipped = frames.split_off(available);
+
+                    // Keep the tags of the clipped frames at the end of the
+                    // last one, so that introspection still finds the
+                    // elements that were cut off.
+                    if let Some(last) = frames.last_mut() {
+                        let mut tags = vec![];
+                        for frame in &clipped {
+                            collect_tags(frame, &mut tags);
+                        }
+                        let pos = Point::with_y(last.height());
+                        last.push_multiple(
+                            tags.into_iter().map(|tag| (pos, FrameItem::Tag(tag))),
+                        );
+                    }
+
+                    fragment = Fragment::frames(frames);
+                }
+            }
+        }
+    }
+
     // Prepare fill and stroke.
     let fill = elem.fill(styles);
     let stroke = elem
@@ -200,6 +250,7 @@ pub fn layout_multi_block(
 
     // Fetch/compute these outside of the loop.
     let clip = elem.clip(styles);
+    let open_breaks = elem.open_breaks(styles);
     let has_fill_or_stroke = fill.is_some() || stroke.iter().any(Option::is_some);
     let has_inset = !inset.is_zero();
     let is_explicit = matches!(body, None | Some(BlockBody::Content(_)));
@@ -214,6 +265,8 @@ pub fn layout_multi_block(
     }
 
     // Post-process to apply insets, clipping, fills, and strokes.
+    let first = usize::from(skip_first);
+    let last = fragment.len().saturating_sub(1);
     for (i, (frame, region)) in fragment.iter_mut().zip(pod.iter()).enumerate() {
         // Explicit blocks are boundaries for gradient relativeness.
         if is_explicit {
@@ -229,13 +282,28 @@ pub fn layout_multi_block(
             crate::pad::grow(frame, &inset);
         }
 
+        // Nothing more to do without clipping or decorations.
+        let decorate = has_fill_or_stroke && i >= first;
+        if !clip && !decorate {
+            continue;
+        }
+
+        // With open breaks, the edges at which the block was split get
+        // neither a stroke nor rounded corners, both for clipping and for
+        // decorating.
+        let mut stroke = stroke.clone();
+        let mut radius = *radius;
+        if open_breaks {
+            open_edges(&mut stroke, &mut radius, i > first, i < last);
+        }
+
         // Clip the contents, if requested.
         if clip {
             frame.clip(clip_rect(frame.size(), &radius, &stroke, &outset));
         }
 
         // Add fill and/or stroke.
-        if has_fill_or_stroke && (i > 0 || !skip_first) {
+        if decorate {
             fill_and_stroke(frame, fill.clone(), &stroke, &outset, &radius, elem.span());
         }
     }
@@ -250,6 +318,26 @@ pub fn layout_multi_block(
     Ok(fragment)
 }
 
+/// Removes the stroke and the rounding of the corners at the top and/or bottom
+/// edge of a block fragment at which the block was split.
+fn open_edges(
+    stroke: &mut Sides<Option<Stroke<Abs>>>,
+    radius: &mut Corners<Rel<Abs>>,
+    top: bool,
+    bottom: bool,
+) {
+    if top {
+        stroke.top = None;
+        radius.top_left = Rel::zero();
+        radius.top_right = Rel::zero();
+    }
+    if bottom {
+        stroke.bottom = None;
+        radius.bottom_left = Rel::zero();
+        radius.bottom_right = Rel::zero();
+    }
+}
+
 /// Builds the pod region for an unbreakable sized container.
 pub(crate) fn unbreakable_pod(
     width: &Sizing,
@@ -398,3 +486,41 @@ fn distribute<'a>(
     // fixed.
     (buf[0], &mut buf[1..])
 }
+
+/// Collects the tags in a frame and its descendants.
+fn collect_tags(frame: &Frame, tags: &mut Vec<Tag>) {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => collect_tags(&group.frame, tags),
+            FrameItem::Tag(tag) => tags.push(tag.clone()),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_edges_at_inner_fragment() {
+        let closed = Some(Stroke::default());
+        let round: Rel<Abs> = Abs::pt(5.0).into();
+        let mut stroke = Sides::splat(closed.clone());
+        let mut radius = Corners::splat(round);
+        open_edges(&mut stroke, &mut radius, true, true);
+        assert_eq!(stroke, Sides::new(closed.clone(), None, closed, None));
+        assert_eq!(radius, Corners::splat(Rel::zero()));
+    }
+
+    #[test]
+    fn test_open_edges_at_first_fragment() {
+        let closed = Some(Stroke::default());
+        let round: Rel<Abs> = Abs::pt(5.0).into();
+        let mut stroke = Sides::splat(closed.clone());
+        let mut radius = Corners::splat(round);
+        open_edges(&mut stroke, &mut radius, false, true);
+        assert_eq!(stroke, Sides::new(closed.clone(), closed.clone(), closed, None));
+        assert_eq!(radius, Corners::new(round, round, Rel::zero(), Rel::zero()));
+    }
+}
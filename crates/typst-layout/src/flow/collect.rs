@@ -13,15 +13,16 @@ use typst_library::introspection::{
 };
 use typst_library::layout::{
     Abs, AlignElem, Alignment, Axes, BlockElem, ColbreakElem, FixedAlignment, FlushElem,
-    Fr, Fragment, Frame, PagebreakElem, PlaceElem, PlacementScope, Ratio, Region,
-    Regions, Rel, Size, Sizing, Spacing, VElem,
+    Fr, Fragment, Frame, PageElem, PagebreakElem, Penalty, PlaceElem, PlacementScope,
+    Ratio, Region, Regions, Rel, Size, Sizing, Spacing, SpacingCollapse, VElem,
 };
-use typst_library::model::ParElem;
+use typst_library::model::{ParElem, SpacingMode};
 use typst_library::routines::{Pair, Routines};
 use typst_library::text::TextElem;
 use typst_library::World;
+use typst_syntax::Span;
 
-use super::{layout_multi_block, layout_single_block};
+use super::{layout_multi_block, layout_single_block, WrapChild};
 
 /// Collects all elements of the flow into prepared children. These are much
 /// simpler to handle than the raw elements.
@@ -33,6 +34,7 @@ pub fn collect<'a>(
     locator: Locator<'a>,
     base: Size,
     expand: bool,
+    wrap: bool,
 ) -> SourceResult<Vec<Child<'a>>> {
     Collector {
         engine,
@@ -41,8 +43,12 @@ pub fn collect<'a>(
         locator: locator.split(),
         base,
         expand,
+        wrap,
         output: Vec::with_capacity(children.len()),
         last_was_par: false,
+        short_spacing: None,
+        started: false,
+        trailing: vec![],
     }
     .run()
 }
@@ -54,9 +60,16 @@ struct Collector<'a, 'x, 'y> {
     children: &'x [Pair<'a>],
     base: Size,
     expand: bool,
+    wrap: bool,
     locator: SplitLocator<'a>,
     output: Vec<Child<'a>>,
     last_was_par: bool,
+    /// The reduced spacing after the last paragraph, if it was short.
+    short_spacing: Option<Abs>,
+    /// Whether any content apart from tags and spacing was collected.
+    started: bool,
+    /// Indices of trimmable spacing children after the last content.
+    trailing: Vec<usize>,
 }
 
 impl<'a> Collector<'a, '_, '_> {
@@ -65,9 +78,16 @@ impl<'a> Collector<'a, '_, '_> {
         for &(child, styles) in self.children {
             if let Some(elem) = child.to_packed::<TagElem>() {
                 self.output.push(Child::Tag(&elem.tag));
+                continue;
             } else if let Some(elem) = child.to_packed::<VElem>() {
                 self.v(elem, styles);
-            } else if let Some(elem) = child.to_packed::<ParElem>() {
+                continue;
+            }
+
+            self.started = true;
+            self.trailing.clear();
+
+            if let Some(elem) = child.to_packed::<ParElem>() {
                 self.par(elem, styles)?;
             } else if let Some(elem) = child.to_packed::<BlockElem>() {
                 self.block(elem, styles);
@@ -91,15 +111,40 @@ impl<'a> Collector<'a, '_, '_> {
             }
         }
 
+        // Drop trimmable spacing after the last content.
+        for i in self.trailing.into_iter().rev() {
+            self.output.remove(i);
+        }
+
         Ok(self.output)
     }
 
     /// Collect vertical spacing into a relative or fractional child.
     fn v(&mut self, elem: &'a Packed<VElem>, styles: StyleChain<'a>) {
+        // Trimmable spacing before any content is dropped right away. After
+        // content, it is remembered in case no more content follows.
+        let trim = elem.trim(styles);
+        if trim && !self.started {
+            return;
+        }
+
+        let start = self.output.len();
         self.output.push(match elem.amount {
+            Spacing::Rel(rel)
+                if !elem.weak(styles)
+                    && elem.collapse(styles) == SpacingCollapse::Max =>
+            {
+                Child::Max(rel.resolve(styles))
+            }
             Spacing::Rel(rel) => Child::Rel(rel.resolve(styles), elem.weak(styles) as u8),
             Spacing::Fr(fr) => Child::Fr(fr),
         });
+        if let Smart::Custom(penalty) = elem.penalty(styles) {
+            self.output.push(Child::Penalty(penalty));
+        }
+        if trim {
+            self.trailing.extend(start..self.output.len());
+        }
     }
 
     /// Collect a paragraph into [`LineChild`]ren. This already performs line
@@ -109,10 +154,14 @@ impl<'a> Collector<'a, '_, '_> {
         elem: &'a Packed<ParElem>,
         styles: StyleChain<'a>,
     ) -> SourceResult<()> {
+        if self.wrap && !PageElem::exclude_in(styles).is_empty() {
+            self.wrapped_par(elem, styles);
+            return Ok(());
+        }
+
         let align = AlignElem::alignment_in(styles).resolve(styles);
-        let leading = ParElem::leading_in(styles);
         let spacing = ParElem::spacing_in(styles);
-        let costs = TextElem::costs_in(styles);
+        let grid = BlockElem::grid_in(styles);
 
         let lines = crate::layout_inline(
             self.engine,
@@ -125,51 +174,62 @@ impl<'a> Collector<'a, '_, '_> {
         )?
         .into_frames();
 
-        self.output.push(Child::Rel(spacing.into(), 4));
+        let before = self.spacing_before_par(spacing);
+        self.output.push(Child::Rel(before.into(), 4));
 
-        // Determine whether to prevent widow and orphans.
         let len = lines.len();
-        let prevent_orphans =
-            costs.orphan() > Ratio::zero() && len >= 2 && !lines[1].is_empty();
-        let prevent_widows =
-            costs.widow() > Ratio::zero() && len >= 2 && !lines[len - 2].is_empty();
-        let prevent_all = len == 3 && prevent_orphans && prevent_widows;
-
-        // Store the heights of lines at the edges because we'll potentially
-        // need these later when `lines` is already moved.
-        let height_at = |i| lines.get(i).map(Frame::height).unwrap_or_default();
-        let front_1 = height_at(0);
-        let front_2 = height_at(1);
-        let back_2 = height_at(len.saturating_sub(2));
-        let back_1 = height_at(len.saturating_sub(1));
-
-        for (i, frame) in lines.into_iter().enumerate() {
+        for (i, (gap, line)) in line_children(lines, styles, align, grid).enumerate() {
             if i > 0 {
-                self.output.push(Child::Rel(leading.into(), 5));
+                self.output.push(Child::Rel(gap.into(), 5));
             }
+            self.output.push(Child::Line(self.boxed(line)));
+        }
 
-            // To prevent widows and orphans, we require enough space for
-            // - all lines if it's just three
-            // - the first two lines if we're at the first line
-            // - the last two lines if we're at the second to last line
-            let need = if prevent_all && i == 0 {
-                front_1 + leading + front_2 + leading + back_1
-            } else if prevent_orphans && i == 0 {
-                front_1 + leading + front_2
-            } else if prevent_widows && i >= 2 && i + 2 == len {
-                back_2 + leading + back_1
-            } else {
-                frame.height()
-            };
+        // Reduce the spacing after short paragraphs if requested.
+        let after = match ParElem::short_spacing_in(styles) {
+            Some(ratio) if len > 0 => {
+                let reduction = (1.0 - ratio.get()) / len as f64;
+                let after = spacing * (1.0 - reduction);
+                self.short_spacing = Some(after);
+                after
+            }
+            _ => spacing,
+        };
+
+        self.output.push(Child::Rel(after.into(), 4));
+        self.last_was_par = true;
+
+        Ok(())
+    }
 
-            self.output
-                .push(Child::Line(self.boxed(LineChild { frame, align, need })));
+    /// The spacing before a paragraph. If it directly follows a short
+    /// paragraph, that paragraph's reduced spacing wins.
+    fn spacing_before_par(&mut self, spacing: Abs) -> Abs {
+        match self.short_spacing.take() {
+            Some(after) if self.last_was_par => after,
+            _ => spacing,
         }
+    }
 
+    /// Collect a paragraph on a page with exclusions into a [`WrapChild`].
+    /// Which lines must be shortened depends on where the paragraph ends up,
+    /// so its line layout is deferred to distribution.
+    fn wrapped_par(&mut self, elem: &'a Packed<ParElem>, styles: StyleChain<'a>) {
+        let spacing = ParElem::spacing_in(styles);
+        let before = self.spacing_before_par(spacing);
+        self.output.push(Child::Rel(before.into(), 4));
+        self.output.push(Child::Wrap(self.boxed(WrapChild {
+            align: AlignElem::alignment_in(styles).resolve(styles),
+            grid: BlockElem::grid_in(styles),
+            elem,
+            styles,
+            locator: self.locator.next(&elem.span()),
+            consecutive: self.last_was_par,
+            base: self.base,
+            expand: self.expand,
+        })));
         self.output.push(Child::Rel(spacing.into(), 4));
         self.last_was_par = true;
-
-        Ok(())
     }
 
     /// Collect a block into a [`SingleChild`] or [`MultiChild`] depending on
@@ -180,14 +240,22 @@ impl<'a> Collector<'a, '_, '_> {
         let alone = self.children.len() == 1;
         let sticky = elem.sticky(styles);
         let breakable = elem.breakable(styles);
+        let keep_together = elem.keep_together(styles);
+        let grid = elem.grid(styles);
+        let grid_report = elem.grid_report(styles);
         let fr = match elem.height(styles) {
             Sizing::Fr(fr) => Some(fr),
             _ => None,
         };
 
         let fallback = LazyCell::new(|| ParElem::spacing_in(styles));
+        let exact = elem.spacing_mode(styles) == SpacingMode::Exact;
         let spacing = |amount| match amount {
+            Smart::Auto if exact => Child::Baseline((*fallback).into(), 4),
             Smart::Auto => Child::Rel((*fallback).into(), 4),
+            Smart::Custom(Spacing::Rel(rel)) if exact => {
+                Child::Baseline(rel.resolve(styles), 3)
+            }
             Smart::Custom(Spacing::Rel(rel)) => Child::Rel(rel.resolve(styles), 3),
             Smart::Custom(Spacing::Fr(fr)) => Child::Fr(fr),
         };
@@ -200,6 +268,8 @@ impl<'a> Collector<'a, '_, '_> {
                 sticky,
                 alone,
                 fr,
+                grid,
+                grid_report,
                 elem,
                 styles,
                 locator,
@@ -209,7 +279,10 @@ impl<'a> Collector<'a, '_, '_> {
             self.output.push(Child::Multi(self.boxed(MultiChild {
                 align,
                 sticky,
+                keep_together,
                 alone,
+                grid,
+                grid_report,
                 elem,
                 styles,
                 locator,
@@ -292,10 +365,18 @@ pub enum Child<'a> {
     Tag(&'a Tag),
     /// Relative spacing with a specific weakness level.
     Rel(Rel<Abs>, u8),
+    /// Strong relative spacing that combines with directly preceding strong
+    /// spacing by taking the maximum.
+    Max(Rel<Abs>),
+    /// Relative spacing with a specific weakness level that is measured
+    /// between the baselines of the adjacent lines.
+    Baseline(Rel<Abs>, u8),
     /// Fractional spacing.
     Fr(Fr),
     /// An already layouted line of a paragraph.
     Line(BumpBox<'a, LineChild>),
+    /// A paragraph whose lines flow around exclusions.
+    Wrap(BumpBox<'a, WrapChild<'a>>),
     /// An unbreakable block.
     Single(BumpBox<'a, SingleChild<'a>>),
     /// A breakable block.
@@ -306,6 +387,8 @@ pub enum Child<'a> {
     Flush,
     /// An explicit column break.
     Break(bool),
+    /// A break penalty for the preceding spacing.
+    Penalty(Penalty),
 }
 
 /// A child that encapsulates a layouted line of a paragraph.
@@ -314,6 +397,75 @@ pub struct LineChild {
     pub frame: Frame,
     pub align: Axes<FixedAlignment>,
     pub need: Abs,
+    pub grid: Option<Abs>,
+}
+
+/// Turns the lines of a paragraph into [`LineChild`]ren, each paired with the
+/// spacing to its predecessor.
+pub fn line_children(
+    lines: Vec<Frame>,
+    styles: StyleChain,
+    align: Axes<FixedAlignment>,
+    grid: Option<Abs>,
+) -> impl Iterator<Item = (Abs, LineChild)> {
+    let leading = ParElem::leading_in(styles);
+    let costs = TextElem::costs_in(styles);
+
+    // Determine whether to prevent widow and orphans.
+    let len = lines.len();
+    let prevent_orphans =
+        costs.orphan() > Ratio::zero() && len >= 2 && !lines[1].is_empty();
+    let prevent_widows =
+        costs.widow() > Ratio::zero() && len >= 2 && !lines[len - 2].is_empty();
+    let prevent_all = len == 3 && prevent_orphans && prevent_widows;
+
+    // Determine the spacing between each line and its predecessor. In exact
+    // mode, the leading is the distance between the baselines.
+    let exact = ParElem::spacing_mode_in(styles) == SpacingMode::Exact;
+    let gaps: Vec<Abs> = (0..len)
+        .map(|i| match i {
+            0 => Abs::zero(),
+            _ if exact => {
+                let above = &lines[i - 1];
+                leading - (above.height() - above.baseline()) - lines[i].baseline()
+            }
+            _ => leading,
+        })
+        .collect();
+
+    // The heights of the lines at the edges and the gaps between them.
+    let height_at = |i| lines.get(i).map(Frame::height).unwrap_or_default();
+    let gap_at = |i| gaps.get(i).copied().unwrap_or_default();
+    let front_1 = height_at(0);
+    let front_2 = height_at(1);
+    let back_2 = height_at(len.saturating_sub(2));
+    let back_1 = height_at(len.saturating_sub(1));
+
+    // To prevent widows and orphans, we require enough space for
+    // - all lines if it's just three
+    // - the first two lines if we're at the first line
+    // - the last two lines if we're at the second to last line
+    let needs: Vec<Abs> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| {
+            if prevent_all && i == 0 {
+                front_1 + gap_at(1) + front_2 + gap_at(2) + back_1
+            } else if prevent_orphans && i == 0 {
+                front_1 + gap_at(1) + front_2
+            } else if prevent_widows && i >= 2 && i + 2 == len {
+                back_2 + gap_at(len - 1) + back_1
+            } else {
+                frame.height()
+            }
+        })
+        .collect();
+
+    lines
+        .into_iter()
+        .zip(gaps)
+        .zip(needs)
+        .map(move |((frame, gap), need)| (gap, LineChild { frame, align, need, grid }))
 }
 
 /// A child that encapsulates a prepared unbreakable block.
@@ -323,6 +475,8 @@ pub struct SingleChild<'a> {
     pub sticky: bool,
     pub alone: bool,
     pub fr: Option<Fr>,
+    pub grid: Option<Abs>,
+    pub grid_report: bool,
     elem: &'a Packed<BlockElem>,
     styles: StyleChain<'a>,
     locator: Locator<'a>,
@@ -330,6 +484,11 @@ pub struct SingleChild<'a> {
 }
 
 impl SingleChild<'_> {
+    /// The span of the block.
+    pub fn span(&self) -> Span {
+        self.elem.span()
+    }
+
     /// Build the child's frame given the region's base size.
     pub fn layout(&self, engine: &mut Engine, region: Region) -> SourceResult<Frame> {
         self.cell.get_or_init(region, |mut region| {
@@ -386,6 +545,9 @@ fn layout_single_impl(
 pub struct MultiChild<'a> {
     pub align: Axes<FixedAlignment>,
     pub sticky: bool,
+    pub keep_together: bool,
+    pub grid: Option<Abs>,
+    pub grid_report: bool,
     alone: bool,
     elem: &'a Packed<BlockElem>,
     styles: StyleChain<'a>,
@@ -394,6 +556,11 @@ pub struct MultiChild<'a> {
 }
 
 impl<'a> MultiChild<'a> {
+    /// The span of the block.
+    pub fn span(&self) -> Span {
+        self.elem.span()
+    }
+
     /// Build the child's frames given regions.
     pub fn layout<'b>(
         &'b self,
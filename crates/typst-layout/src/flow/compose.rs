@@ -29,6 +29,7 @@ use super::{distribute, Config, FlowResult, LineNumberConfig, PlacedChild, Stop,
 ///
 /// To lay out the in-flow contents of individual subregions, the composer
 /// invokes [distribution](distribute).
+#[typst_macros::time]
 pub fn compose(
     engine: &mut Engine,
     work: &mut Work,
@@ -75,6 +76,13 @@ pub struct Composer<'a, 'b, 'x, 'y> {
 }
 
 impl<'a, 'b> Composer<'a, 'b, '_, '_> {
+    /// The combined height of the top insertions of the current page and
+    /// column, i.e. the distance from the top of the region to the start of
+    /// the distributed content.
+    pub fn top_insertions(&self) -> Abs {
+        self.page_insertions.top_size + self.column_insertions.top_size
+    }
+
     /// Lay out a container/page region, including container/page insertions.
     fn page(mut self, locator: Locator, regions: Regions) -> SourceResult<Frame> {
         // This loop can restart region layout when requested to do so by a
@@ -375,6 +383,7 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         let mut relayout = false;
         let mut regions = *regions;
         let mut migratable = migratable && !breakable && regions.may_progress();
+        let consumed = regions.base().y - regions.size.y;
 
         for (y, elem) in notes {
             // The amount of space used by the in-flow content that contains the
@@ -382,6 +391,21 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
             // the marker. For an unbreakable frame, it's the full height.
             let flow_need = if breakable { y } else { flow_need };
 
+            // Defer the footnote to the next region if there isn't enough body
+            // content above its marker yet.
+            if consumed + flow_need < self.config.footnote.min_body
+                && regions.may_progress()
+            {
+                let loc = elem.location().unwrap();
+                let queued =
+                    self.footnote_queue.iter().any(|note| note.location() == Some(loc));
+                if !elem.is_ref() && !self.skipped(loc) && !queued {
+                    self.footnote_queue.push(elem);
+                }
+                migratable = false;
+                continue;
+            }
+
             // Process the footnote.
             match self.footnote(elem, &mut regions, flow_need, migratable) {
                 // The footnote was already processed or queued.
@@ -436,8 +460,12 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         let mut separator = None;
         let mut separator_need = Abs::zero();
         if area.footnotes.is_empty() {
-            let frame =
-                layout_footnote_separator(self.engine, self.config, regions.base())?;
+            let frame = layout_footnote_separator(
+                self.engine,
+                self.config,
+                &self.config.footnote.separator,
+                regions.base(),
+            )?;
             separator_need += self.config.footnote.clearance + frame.height();
             separator = Some(frame);
         }
@@ -520,8 +548,14 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
     ) -> SourceResult<()> {
         let area = &mut self.column_insertions;
 
-        // Create and save the separator.
-        let separator = layout_footnote_separator(self.engine, self.config, base)?;
+        // Create and save the separator. Since this is the remainder of a
+        // footnote from a previous region, we use the continuation separator.
+        let separator = layout_footnote_separator(
+            self.engine,
+            self.config,
+            &self.config.footnote.continuation,
+            base,
+        )?;
         area.push_footnote_separator(self.config, separator);
 
         // Save the footnote's frame.
@@ -550,15 +584,16 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
     }
 }
 
-/// Lay out the footnote separator, typically a line.
+/// Lay out a footnote separator, typically a line.
 fn layout_footnote_separator(
     engine: &mut Engine,
     config: &Config,
+    separator: &Content,
     base: Size,
 ) -> SourceResult<Frame> {
     crate::layout_frame(
         engine,
-        &config.footnote.separator,
+        separator,
         Locator::root(),
         config.shared,
         Region::new(base, Axes::new(config.footnote.expand, false)),
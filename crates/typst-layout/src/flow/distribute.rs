@@ -1,14 +1,24 @@
+use std::rc::Rc;
+
+use typst_library::diag::warning;
+use typst_library::foundations::Repr;
 use typst_library::introspection::Tag;
 use typst_library::layout::{
-    Abs, Axes, FixedAlignment, Fr, Frame, FrameItem, Point, Region, Regions, Rel, Size,
+    Abs, Axes, FixedAlignment, Fr, FrOrder, Frame, FrameItem, Penalty, Point, Region,
+    Regions, Rel, Size,
 };
+use typst_syntax::Span;
 use typst_utils::Numeric;
 
 use super::{
     Child, Composer, FlowResult, LineChild, MultiChild, MultiSpill, PlacedChild,
-    SingleChild, Stop, Work,
+    SingleChild, Stop, Work, WrapChild, WrapSpill,
 };
 
+/// The largest share of a region that may be left empty to end it at a
+/// preferred break rather than where its content runs out of space.
+const PREFERRED_BREAK_LOSS: f64 = 0.5;
+
 /// Distributes as many children as fit from `composer.work` into the first
 /// region and returns the resulting frame.
 pub fn distribute(composer: &mut Composer, regions: Regions) -> FlowResult<Frame> {
@@ -18,6 +28,9 @@ pub fn distribute(composer: &mut Composer, regions: Regions) -> FlowResult<Frame
         items: vec![],
         sticky: None,
         stickable: false,
+        preferred: None,
+        off_grid: None,
+        deviations: vec![],
     };
     let init = distributor.snapshot();
     let forced = match distributor.run() {
@@ -41,10 +54,40 @@ struct Distributor<'a, 'b, 'x, 'y, 'z> {
     items: Vec<Item<'a, 'b>>,
     /// A snapshot which can be restored to migrate a suffix of sticky blocks to
     /// the next region.
+    ///
+    /// Instead of measuring upcoming children ahead of time, the distributor
+    /// optimistically lays out the children following a sticky block. Only
+    /// if the region ends before a non-sticky frame made it in, it rolls back
+    /// to the snapshot. This way, keep-with-next decisions account for the
+    /// actual size of the next child without laying it out twice in the
+    /// common case.
     sticky: Option<DistributionSnapshot<'a, 'b>>,
     /// Whether there was at least one proper block. Otherwise, sticky blocks
     /// are disabled (or else they'd keep being migrated).
     stickable: bool,
+    /// A snapshot at the most recent spacing that was marked as a preferred
+    /// break, along with the remaining height at that point. If the region
+    /// ends before the flow does, it is restored so that the region ends at
+    /// that spacing instead, unless that would leave too much of it empty.
+    preferred: Option<(DistributionSnapshot<'a, 'b>, Abs)>,
+    /// The most recent block that ended off the baseline grid and should be
+    /// reported, with its deviation from the grid.
+    off_grid: Option<(Span, Abs)>,
+    /// Reported blocks that fell off the baseline grid.
+    deviations: Vec<GridDeviation>,
+}
+
+/// A block that fell off the baseline grid.
+struct GridDeviation {
+    /// The span of the block.
+    span: Span,
+    /// The index of the spacing item that brought the following content back
+    /// onto the grid.
+    item: usize,
+    /// How far the block's bottom edge is below the previous grid line.
+    deviation: Abs,
+    /// The space absorbed before the following content.
+    offset: Abs,
 }
 
 /// A snapshot of the distribution state.
@@ -57,8 +100,9 @@ struct DistributionSnapshot<'a, 'b> {
 enum Item<'a, 'b> {
     /// An introspection tag.
     Tag(&'a Tag),
-    /// Absolute spacing and its weakness level.
-    Abs(Abs, u8),
+    /// Absolute spacing, its weakness level, and whether it is measured
+    /// between the baselines of the adjacent frames.
+    Abs(Abs, u8, bool),
     /// Fractional spacing or a fractional block.
     Fr(Fr, Option<&'b SingleChild<'a>>),
     /// A frame for a laid out line or block.
@@ -113,14 +157,18 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     fn child(&mut self, child: &'b Child<'a>) -> FlowResult<()> {
         match child {
             Child::Tag(tag) => self.tag(tag),
-            Child::Rel(amount, weakness) => self.rel(*amount, *weakness),
+            Child::Rel(amount, weakness) => self.rel(*amount, *weakness, false),
+            Child::Baseline(amount, weakness) => self.rel(*amount, *weakness, true),
+            Child::Max(amount) => self.max(*amount),
             Child::Fr(fr) => self.fr(*fr),
-            Child::Line(line) => self.line(line)?,
+            Child::Line(line) => self.line(line, self.avoids_break_after())?,
+            Child::Wrap(wrap) => self.wrap(wrap)?,
             Child::Single(single) => self.single(single)?,
             Child::Multi(multi) => self.multi(multi)?,
             Child::Placed(placed) => self.placed(placed)?,
             Child::Flush => self.flush()?,
             Child::Break(weak) => self.break_(*weak)?,
+            Child::Penalty(penalty) => self.penalty(*penalty),
         }
         Ok(())
     }
@@ -140,14 +188,57 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     }
 
     /// Processes relative spacing.
-    fn rel(&mut self, amount: Rel<Abs>, weakness: u8) {
+    fn rel(&mut self, amount: Rel<Abs>, weakness: u8, baseline: bool) {
         let amount = amount.relative_to(self.regions.base().y);
-        if weakness > 0 && !self.keep_spacing(amount, weakness) {
+        if weakness > 0 && !self.keep_spacing(amount, weakness, baseline) {
             return;
         }
 
         self.regions.size.y -= amount;
-        self.items.push(Item::Abs(amount, weakness));
+        self.items.push(Item::Abs(amount, weakness, baseline));
+    }
+
+    /// Processes relative spacing that collapses with directly preceding
+    /// strong spacing.
+    fn max(&mut self, amount: Rel<Abs>) {
+        let amount = amount.relative_to(self.regions.base().y);
+        for item in self.items.iter_mut().rev() {
+            match *item {
+                Item::Abs(prev_amount, 0, _) => {
+                    if amount > prev_amount {
+                        self.regions.size.y -= amount - prev_amount;
+                        *item = Item::Abs(amount, 0, false);
+                    }
+                    return;
+                }
+                Item::Tag(_) => {}
+                _ => break,
+            }
+        }
+        self.rel(amount.into(), 0, false);
+    }
+
+    /// Processes a break penalty for the preceding spacing.
+    fn penalty(&mut self, penalty: Penalty) {
+        // Breaking at the very start of the region would leave it empty.
+        if penalty == Penalty::Prefer
+            && self.items.iter().any(|item| matches!(item, Item::Frame(..)))
+        {
+            self.preferred = Some((self.snapshot(), self.regions.size.y));
+        }
+    }
+
+    /// Whether the upcoming spacing asks to avoid a break between the current
+    /// child and the next one.
+    fn avoids_break_after(&self) -> bool {
+        for child in self.composer.work.children.iter().skip(1) {
+            match child {
+                Child::Penalty(penalty) => return *penalty == Penalty::Avoid,
+                Child::Rel(..) | Child::Baseline(..) | Child::Max(_) | Child::Tag(_) => {}
+                _ => break,
+            }
+        }
+        false
     }
 
     /// Processes fractional spacing.
@@ -158,15 +249,15 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
 
     /// Decides whether to keep weak spacing based on previous items. If there
     /// is a preceding weak spacing, it might be patched in place.
-    fn keep_spacing(&mut self, amount: Abs, weakness: u8) -> bool {
+    fn keep_spacing(&mut self, amount: Abs, weakness: u8, baseline: bool) -> bool {
         for item in self.items.iter_mut().rev() {
             match *item {
-                Item::Abs(prev_amount, prev_weakness @ 1..) => {
+                Item::Abs(prev_amount, prev_weakness @ 1.., _) => {
                     if weakness <= prev_weakness
                         && (weakness < prev_weakness || amount > prev_amount)
                     {
                         self.regions.size.y -= amount - prev_amount;
-                        *item = Item::Abs(amount, weakness);
+                        *item = Item::Abs(amount, weakness, baseline);
                     }
                     return false;
                 }
@@ -182,7 +273,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     fn trim_spacing(&mut self) {
         for (i, item) in self.items.iter().enumerate().rev() {
             match *item {
-                Item::Abs(amount, 1..) => {
+                Item::Abs(amount, 1.., _) => {
                     self.regions.size.y += amount;
                     self.items.remove(i);
                     break;
@@ -197,7 +288,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     fn weak_spacing(&mut self) -> Abs {
         for item in self.items.iter().rev() {
             match *item {
-                Item::Abs(amount, 1..) => return amount,
+                Item::Abs(amount, 1.., _) => return amount,
                 Item::Tag(_) | Item::Abs(..) | Item::Placed(..) => {}
                 Item::Frame(..) | Item::Fr(..) => break,
             }
@@ -205,11 +296,92 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         Abs::zero()
     }
 
+    /// Whether a frame of the given height fits into the current region.
+    ///
+    /// If trailing weak spacing pushed a zero-height frame (e.g. an empty
+    /// block acting as an anchor) out of the region, the spacing is trimmed
+    /// instead of forcing a region break that would leave the frame alone in
+    /// an otherwise empty region. The frame only fits if that actually made
+    /// room for it.
+    fn fits(&mut self, height: Abs) -> bool {
+        if self.regions.size.y.fits(height) {
+            return true;
+        }
+
+        if height.is_zero() {
+            self.trim_spacing();
+            return self.regions.size.y.fits(height);
+        }
+
+        false
+    }
+
+    /// The height of the items distributed so far.
+    fn used(&self) -> Abs {
+        self.items
+            .iter()
+            .map(|item| match item {
+                Item::Abs(v, ..) => *v,
+                Item::Frame(frame, _) => frame.height(),
+                Item::Tag(_) | Item::Fr(..) | Item::Placed(..) => Abs::zero(),
+            })
+            .sum()
+    }
+
+    /// The pitch of the baseline grid, if there is one. Only the root flow
+    /// snaps to the grid since the regions of nested flows, like those of
+    /// blocks or columns, don't start at a grid line.
+    fn pitch(&self, grid: Option<Abs>) -> Option<Abs> {
+        grid.filter(|pitch| self.composer.config.root && *pitch > Abs::zero())
+    }
+
+    /// The distance by which content must be moved down so that the given
+    /// reference point (relative to its top edge) lands on the baseline grid.
+    fn grid_offset(&self, grid: Option<Abs>, reference: Abs) -> Abs {
+        let Some(pitch) = self.pitch(grid) else {
+            return Abs::zero();
+        };
+
+        let position = self.used() + reference;
+        let snapped = pitch * (position / pitch - 1e-6).ceil();
+        (snapped - position).max(Abs::zero())
+    }
+
+    /// Inserts strong spacing that aligns the following content with the
+    /// baseline grid.
+    fn snap(&mut self, offset: Abs) {
+        let off_grid = self.off_grid.take();
+        if offset > Abs::zero() {
+            if let Some((span, deviation)) = off_grid {
+                let item = self.items.len();
+                self.deviations.push(GridDeviation { span, item, deviation, offset });
+            }
+            self.regions.size.y -= offset;
+            self.items.push(Item::Abs(offset, 0, false));
+        }
+    }
+
+    /// Remembers a just processed block for reporting if its bottom edge
+    /// doesn't land on the baseline grid.
+    fn check_grid(&mut self, grid: Option<Abs>, report: bool, span: Span) {
+        let Some(pitch) = self.pitch(grid).filter(|_| report) else {
+            return;
+        };
+
+        let offset = self.grid_offset(grid, Abs::zero());
+        if offset > Abs::zero() {
+            self.off_grid = Some((span, pitch - offset));
+        }
+    }
+
     /// Processes a line of a paragraph.
-    fn line(&mut self, line: &'b LineChild) -> FlowResult<()> {
+    fn line(&mut self, line: &LineChild, sticky: bool) -> FlowResult<()> {
+        self.anchor_spacing(&line.frame);
+        let offset = self.grid_offset(line.grid, line.frame.baseline());
+
         // If the line doesn't fit and a followup region may improve things,
         // finish the region.
-        if !self.regions.size.y.fits(line.frame.height()) && self.regions.may_progress() {
+        if !self.fits(offset + line.frame.height()) && self.regions.may_progress() {
             return Err(Stop::Finish(false));
         }
 
@@ -227,7 +399,48 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
-        self.frame(line.frame.clone(), line.align, false, false)
+        self.snap(offset);
+        self.frame(line.frame.clone(), line.align, sticky, false)
+    }
+
+    /// Processes a paragraph that flows around exclusions.
+    fn wrap(&mut self, wrap: &'b WrapChild<'a>) -> FlowResult<()> {
+        // Which lines are shortened depends on where the paragraph starts, so
+        // it's only laid out once it is reached. If it already started in a
+        // previous region, we continue with its remaining lines.
+        if self.composer.work.wrap_spill.is_none() {
+            let top = self.composer.top_insertions() + self.used();
+            let lines = wrap.layout(self.composer.engine, self.regions, top)?;
+            self.composer.work.wrap_spill =
+                Some(WrapSpill { lines: Rc::new(lines), next: 0 });
+        }
+
+        while let Some(spill) = self.composer.work.wrap_spill.clone() {
+            let Some((gap, line)) = spill.lines.get(spill.next) else {
+                self.composer.work.wrap_spill = None;
+                break;
+            };
+
+            if spill.next > 0 {
+                self.rel((*gap).into(), 5, false);
+            }
+
+            let last = spill.next + 1 == spill.lines.len();
+            let sticky = last && self.avoids_break_after();
+            if let Err(err) = self.line(line, sticky) {
+                // If not even the first line fit, the paragraph is laid out
+                // afresh in the next region.
+                if spill.next == 0 && matches!(err, Stop::Finish(_)) {
+                    self.composer.work.wrap_spill = None;
+                }
+                return Err(err);
+            }
+
+            self.composer.work.wrap_spill =
+                Some(WrapSpill { next: spill.next + 1, ..spill });
+        }
+
+        Ok(())
     }
 
     /// Processes an unbreakable block.
@@ -249,11 +462,17 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
 
         // If the block doesn't fit and a followup region may improve things,
         // finish the region.
-        if !self.regions.size.y.fits(frame.height()) && self.regions.may_progress() {
+        self.anchor_spacing(&frame);
+        let offset = self.grid_offset(single.grid, Abs::zero());
+        if !self.fits(offset + frame.height()) && self.regions.may_progress() {
             return Err(Stop::Finish(false));
         }
 
-        self.frame(frame, single.align, single.sticky, false)
+        self.snap(offset);
+        let sticky = single.sticky || self.avoids_break_after();
+        self.frame(frame, single.align, sticky, false)?;
+        self.check_grid(single.grid, single.grid_report, single.span());
+        Ok(())
     }
 
     /// Processes a breakable block.
@@ -264,9 +483,42 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             return Err(Stop::Finish(false));
         }
 
-        // Lay out the block.
+        // Move the block onto the baseline grid before laying it out so that
+        // it is laid out into the remaining space.
+        let offset = self.grid_offset(multi.grid, Abs::zero());
+        if offset > Abs::zero() && !self.regions.size.y.fits(offset) {
+            if self.regions.may_progress() {
+                return Err(Stop::Finish(false));
+            }
+        } else {
+            self.snap(offset);
+        }
+
+        // Lay out the block. Its first baseline is only known afterwards, so
+        // the space freed up by anchoring the spacing above it only becomes
+        // available to the following content.
         let (frame, spill) = multi.layout(self.composer.engine, self.regions)?;
-        self.frame(frame, multi.align, multi.sticky, true)?;
+        self.anchor_spacing(&frame);
+
+        // If the block should be kept together, but doesn't fit into the
+        // remaining space, move it to the next region if it would fit wholly
+        // into that one. This is pointless if the current region doesn't have
+        // any content yet.
+        if spill.is_some()
+            && multi.keep_together
+            && self.regions.may_progress()
+            && self.items.iter().any(|item| matches!(item, Item::Frame(..)))
+        {
+            let mut next = self.regions;
+            next.next();
+            if multi.layout(self.composer.engine, next)?.1.is_none() {
+                return Err(Stop::Finish(false));
+            }
+        }
+
+        let sticky = multi.sticky || self.avoids_break_after();
+        self.frame(frame, multi.align, sticky, true)?;
+        self.check_grid(multi.grid, multi.grid_report, multi.span());
 
         // If the block didn't fully fit into the current region, save it into
         // the `spill` and finish the region.
@@ -391,6 +643,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
     /// Arranges the produced items into an output frame.
     ///
     /// This performs alignment and resolves fractional spacing and blocks.
+    #[typst_macros::time(name = "finish region")]
     fn finalize(
         mut self,
         region: Region,
@@ -404,15 +657,22 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             // Restore the initial state of all items are migratable.
             self.restore(init);
         } else {
-            // If we ended on a sticky block, but are not yet at the end of
-            // the flow, restore the saved checkpoint to move the sticky
-            // suffix to the next region.
-            if let Some(snapshot) = self.sticky.take() {
+            // If we passed a preferred break or ended on a sticky block, but
+            // are not yet at the end of the flow, restore the saved
+            // checkpoint to move the suffix to the next region.
+            let limit = PREFERRED_BREAK_LOSS * region.size.y;
+            let preferred = self
+                .preferred
+                .take()
+                .filter(|&(_, remaining)| remaining - self.regions.size.y <= limit)
+                .map(|(snapshot, _)| snapshot);
+            if let Some(snapshot) = preferred.or_else(|| self.sticky.take()) {
                 self.restore(snapshot)
             }
         }
 
         self.trim_spacing();
+        self.report_grid();
 
         let mut frs = Fr::zero();
         let mut used = Size::zero();
@@ -421,7 +681,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         // Determine the amount of used space and the sum of fractionals.
         for item in &self.items {
             match item {
-                Item::Abs(v, _) => used.y += *v,
+                Item::Abs(v, ..) => used.y += *v,
                 Item::Fr(v, child) => {
                     frs += *v;
                     has_fr_child |= child.is_some();
@@ -441,12 +701,31 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
             used.y = region.size.y;
         }
 
+        // Determine the length of each fractional item. By default, the
+        // fractional items share all of the remaining space, leaving none for
+        // aligned blocks to move into. When they are distributed after
+        // alignment, the blocks are aligned within that space first and the
+        // fractional items fill the gaps that the alignment leaves.
+        let after_align =
+            frs.get() > 0.0 && self.composer.config.fr_order == FrOrder::AfterAlign;
+        let fr_lengths = if after_align {
+            self.fr_lengths_after_align(fr_space)
+        } else {
+            self.items
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Fr(v, _) => Some(v.share(frs, fr_space)),
+                    _ => None,
+                })
+                .collect()
+        };
+
         // Lay out fractionally sized blocks.
         let mut fr_frames = vec![];
         if has_fr_child {
-            for item in &self.items {
-                let Item::Fr(v, Some(single)) = item else { continue };
-                let length = v.share(frs, fr_space);
+            let frs = self.items.iter().filter(|item| matches!(item, Item::Fr(..)));
+            for (item, &length) in frs.zip(&fr_lengths) {
+                let Item::Fr(_, Some(single)) = item else { continue };
                 let pod = Region::new(Size::new(region.size.x, length), region.expand);
                 let frame = single.layout(self.composer.engine, pod)?;
                 used.x.set_max(frame.width());
@@ -461,40 +740,49 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
 
         // Determine the region's size.
         let size = region.expand.select(region.size, used.min(region.size));
-        let free = size.y - used.y;
+        let free = if after_align { fr_space } else { size.y - used.y };
 
         let mut output = Frame::soft(size);
         let mut ruler = FixedAlignment::Start;
         let mut offset = Abs::zero();
         let mut fr_frames = fr_frames.into_iter();
+        let mut fr_lengths = fr_lengths.into_iter();
+
+        // The offset of items due to their alignment. When fractional spacing
+        // is distributed after alignment, it already makes up for the
+        // alignment of the blocks following it.
+        let mut shift = Abs::zero();
+        let mut absorbed = Abs::zero();
 
         // Position all items.
         for item in self.items {
             match item {
                 Item::Tag(tag) => {
-                    let y = offset + ruler.position(free);
-                    let pos = Point::with_y(y);
+                    let pos = Point::with_y(offset + shift);
                     output.push(pos, FrameItem::Tag(tag.clone()));
                 }
-                Item::Abs(v, _) => {
+                Item::Abs(v, ..) => {
                     offset += v;
                 }
-                Item::Fr(v, single) => {
-                    let length = v.share(frs, fr_space);
+                Item::Fr(_, single) => {
+                    let length = fr_lengths.next().unwrap();
                     if let Some(single) = single {
                         let frame = fr_frames.next().unwrap();
                         let x = single.align.x.position(size.x - frame.width());
-                        let pos = Point::new(x, offset);
+                        let pos = Point::new(x, offset + shift);
                         output.push_frame(pos, frame);
                     }
                     offset += length;
+                    if after_align {
+                        absorbed += length;
+                    }
                 }
                 Item::Frame(frame, align) => {
                     ruler = ruler.max(align.y);
+                    shift = ruler.position(free) - absorbed;
 
                     let x = align.x.position(size.x - frame.width());
-                    let y = offset + ruler.position(free);
-                    let pos = Point::new(x, y);
+                    let pos = Point::new(x, offset + shift);
                     offset += frame.height();
 
                     output.push_frame(pos, frame);
@@ -503,7 +791,7 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
                     let x = placed.align_x.position(size.x - frame.width());
                     let y = match placed.align_y.unwrap_or_default() {
                         Some(align) => align.position(size.y - frame.height()),
-                        _ => offset + ruler.position(free),
+                        _ => offset + shift,
                     };
 
                     let pos = Point::new(x, y)
@@ -517,6 +805,80 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         Ok(output)
     }
 
+    /// Determines the lengths of the fractional items in the region when they
+    /// are distributed after alignment.
+    ///
+    /// Whenever the alignment of the blocks changes, the free space moves the
+    /// following blocks further down. The fractional items right before such
+    /// a block share this jump. Fractional items after the last block share
+    /// the space below it.
+    fn fr_lengths_after_align(&self, free: Abs) -> Vec<Abs> {
+        let mut lengths = vec![];
+        let mut pending = vec![];
+        let mut ruler = FixedAlignment::Start;
+        let mut position = Abs::zero();
+
+        // Shares the given space among the pending fractional items.
+        let mut share = |pending: &mut Vec<Fr>, space: Abs| {
+            let total = pending.iter().copied().sum();
+            lengths.extend(pending.drain(..).map(|v| v.share(total, space)));
+        };
+
+        for item in &self.items {
+            match item {
+                Item::Fr(v, _) => pending.push(*v),
+                Item::Frame(_, align) => {
+                    ruler = ruler.max(align.y);
+                    let next = ruler.position(free);
+                    share(&mut pending, next - position);
+                    position = next;
+                }
+                _ => {}
+            }
+        }
+
+        share(&mut pending, free - position);
+        lengths
+    }
+
+    /// Turns pending spacing that is measured between baselines into spacing
+    /// between the edges of the previous frame and the `next` one, which is
+    /// about to be added. The space this frees up becomes available again.
+    /// Spacing next to a frame without a baseline stays as is.
+    fn anchor_spacing(&mut self, next: &Frame) {
+        let Some(i) = self.items.iter().rposition(|item| {
+            matches!(item, Item::Abs(_, _, true) | Item::Frame(..) | Item::Fr(..))
+        }) else {
+            return;
+        };
+
+        let Item::Abs(amount, weakness, true) = self.items[i] else { return };
+        let before = adjacent_frame(self.items[..i].iter().rev())
+            .and_then(|frame| Some(frame.height() - frame.baselines()?.1));
+        let after = next.baselines().map(|(first, _)| first);
+        if let (Some(before), Some(after)) = (before, after) {
+            let anchored = (amount - before - after).max(Abs::zero());
+            self.regions.size.y += amount - anchored;
+            self.items[i] = Item::Abs(anchored, weakness, false);
+        }
+    }
+
+    /// Warns about the blocks in the region that fell off the baseline grid.
+    /// Deviations that belong to items which were moved to the next region are
+    /// skipped.
+    fn report_grid(&mut self) {
+        let len = self.items.len();
+        for deviation in self.deviations.drain(..).filter(|d| d.item < len) {
+            self.composer.engine.sink.warn(warning!(
+                deviation.span,
+                "block is {} off the baseline grid",
+                deviation.deviation.repr();
+                hint: "the following content was moved down by {} to return to the grid",
+                deviation.offset.repr(),
+            ));
+        }
+    }
+
     /// Create a snapshot of the work and items.
     fn snapshot(&self) -> DistributionSnapshot<'a, 'b> {
         DistributionSnapshot {
@@ -531,3 +893,16 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
         self.items.truncate(snapshot.items);
     }
 }
+
+/// The first frame among the items up to the next fractional item.
+fn adjacent_frame<'x>(
+    mut items: impl Iterator<Item = &'x Item<'_, '_>>,
+) -> Option<&'x Frame> {
+    items
+        .find_map(|item| match item {
+            Item::Frame(frame, _) => Some(Some(frame)),
+            Item::Fr(..) => Some(None),
+            Item::Tag(_) | Item::Abs(..) | Item::Placed(..) => None,
+        })
+        .flatten()
+}
@@ -4,6 +4,7 @@ mod block;
 mod collect;
 mod compose;
 mod distribute;
+mod wrap;
 
 pub(crate) use self::block::unbreakable_pod;
 
@@ -21,8 +22,8 @@ use typst_library::introspection::{
     Introspector, Location, Locator, LocatorLink, SplitLocator, Tag,
 };
 use typst_library::layout::{
-    Abs, ColumnsElem, Dir, Em, Fragment, Frame, PageElem, PlacementScope, Region,
-    Regions, Rel, Size,
+    Abs, Axes, BlockElem, ColumnsElem, Dir, Em, FrOrder, Fragment, Frame, FrameItem,
+    PageElem, PlacementScope, Point, Region, Regions, Rel, Size,
 };
 use typst_library::model::{FootnoteElem, FootnoteEntry, LineNumberingScope, ParLine};
 use typst_library::routines::{Arenas, Pair, RealizationKind, Routines};
@@ -36,6 +37,7 @@ use self::collect::{
 };
 use self::compose::{compose, Composer};
 use self::distribute::distribute;
+use self::wrap::{WrapChild, WrapSpill};
 
 /// Lays out content into a single region, producing a single frame.
 pub fn layout_frame(
@@ -87,22 +89,63 @@ pub fn layout_columns(
     styles: StyleChain,
     regions: Regions,
 ) -> SourceResult<Fragment> {
-    layout_fragment_impl(
-        engine.routines,
-        engine.world,
-        engine.introspector,
-        engine.traced,
-        TrackedMut::reborrow_mut(&mut engine.sink),
-        engine.route.track(),
-        &elem.body,
-        locator.track(),
-        styles,
-        regions,
-        elem.count(styles),
-        elem.gutter(styles),
-    )
+    let count = elem.count(styles);
+    let gutter = elem.gutter(styles);
+    let layout = |engine: &mut Engine, locator: Locator, regions: Regions| {
+        layout_fragment_impl(
+            engine.routines,
+            engine.world,
+            engine.introspector,
+            engine.traced,
+            TrackedMut::reborrow_mut(&mut engine.sink),
+            engine.route.track(),
+            &elem.body,
+            locator.track(),
+            styles,
+            regions,
+            count,
+            gutter,
+        )
+    };
+
+    if elem.balance(styles) && count.get() > 1 && regions.size.y.is_finite() {
+        // A region of the given height. Repeating it ensures that content
+        // which doesn't fit overflows into a second frame instead of
+        // overflowing the last column.
+        let pod = |height| {
+            Regions::repeat(
+                Size::new(regions.size.x, height),
+                Axes::new(regions.expand.x, false),
+            )
+        };
+
+        // Balancing only makes sense if all content fits into the current
+        // region. Otherwise, we fill the columns as usual.
+        if layout(engine, locator.relayout(), pod(regions.size.y))?.len() == 1 {
+            // Search for the smallest height at which the content still fits
+            // into a single region.
+            let mut low = Abs::zero();
+            let mut high = regions.size.y;
+            while high - low > Abs::pt(BALANCE_TOLERANCE) {
+                let mid = (low + high) / 2.0;
+                if layout(engine, locator.relayout(), pod(mid))?.len() == 1 {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+
+            return layout(engine, locator, pod(high));
+        }
+    }
+
+    layout(engine, locator, regions)
 }
 
+/// The precision (in points) up to which the height of balanced columns is
+/// determined.
+const BALANCE_TOLERANCE: f64 = 0.5;
+
 /// The cached, internal implementation of [`layout_fragment`].
 #[comemo::memoize]
 #[allow(clippy::too_many_arguments)]
@@ -191,8 +234,11 @@ pub(crate) fn layout_flow(
         },
         footnote: FootnoteConfig {
             separator: FootnoteEntry::separator_in(shared),
+            continuation: FootnoteEntry::continuation_in(shared)
+                .unwrap_or_else(|| FootnoteEntry::separator_in(shared)),
             clearance: FootnoteEntry::clearance_in(shared),
             gap: FootnoteEntry::gap_in(shared),
+            min_body: FootnoteEntry::min_body_in(shared),
             expand: regions.expand.x,
         },
         line_numbers: root.then(|| LineNumberConfig {
@@ -207,6 +253,7 @@ pub(crate) fn layout_flow(
                     .clamp(Em::new(0.75).resolve(shared), Em::new(2.5).resolve(shared))
             },
         }),
+        fr_order: BlockElem::fr_order_in(shared),
     };
 
     // Collect the elements into pre-processed children. These are much easier
@@ -219,6 +266,7 @@ pub(crate) fn layout_flow(
         locator.next(&()),
         Size::new(config.columns.width, regions.full),
         regions.expand.x,
+        root && config.columns.count == 1,
     )?;
 
     let mut work = Work::new(&children);
@@ -229,6 +277,20 @@ pub(crate) fn layout_flow(
         let frame = compose(engine, &mut work, &config, locator.next(&()), regions)?;
         finished.push(frame);
 
+        // If only weak spacing is left, a followup region would be empty
+        // because the spacing collapses at its start. Instead, attach the
+        // pending tags to the end of the last region and drop the spacing.
+        // Expanding regions still drain their backlog below.
+        if !work.done() && work.trivial() {
+            let frame = finished.last_mut().unwrap();
+            let pos = Point::with_y(frame.height());
+            for tag in work.tags.iter() {
+                frame.push(pos, FrameItem::Tag((*tag).clone()));
+            }
+            work.tags.clear();
+            work.children = &[];
+        }
+
         // Terminate the loop when everything is processed, though draining the
         // backlog if necessary.
         if work.done() && (!regions.expand.y || regions.backlog.is_empty()) {
@@ -252,6 +314,9 @@ struct Work<'a, 'b> {
     children: &'b [Child<'a>],
     /// Leftovers from a breakable block.
     spill: Option<MultiSpill<'a, 'b>>,
+    /// The not yet distributed lines of a paragraph that flows around
+    /// exclusions. Only set while that paragraph is the `head()` child.
+    wrap_spill: Option<WrapSpill>,
     /// Queued floats that didn't fit in previous regions.
     floats: EcoVec<&'b PlacedChild<'a>>,
     /// Queued footnotes that didn't fit in previous regions.
@@ -272,6 +337,7 @@ impl<'a, 'b> Work<'a, 'b> {
         Self {
             children,
             spill: None,
+            wrap_spill: None,
             floats: EcoVec::new(),
             footnotes: EcoVec::new(),
             footnote_spill: None,
@@ -299,6 +365,21 @@ impl<'a, 'b> Work<'a, 'b> {
             && self.footnotes.is_empty()
     }
 
+    /// Whether the remaining work can't produce any output in a new region.
+    /// This is the case if there are only pending tags and weak spacing left.
+    fn trivial(&self) -> bool {
+        self.spill.is_none()
+            && self.floats.is_empty()
+            && self.footnote_spill.is_none()
+            && self.footnotes.is_empty()
+            && self.children.iter().all(|child| {
+                matches!(
+                    child,
+                    Child::Rel(_, 1..) | Child::Baseline(_, 1..) | Child::Penalty(_)
+                )
+            })
+    }
+
     /// Add skipped floats and footnotes from the insertion areas to the skip
     /// set.
     fn extend_skips(&mut self, skips: &[Location]) {
@@ -322,16 +403,22 @@ struct Config<'x> {
     footnote: FootnoteConfig,
     /// Settings for line numbers.
     line_numbers: Option<LineNumberConfig>,
+    /// When fractional spacing is distributed relative to alignment.
+    fr_order: FrOrder,
 }
 
 /// Configuration of footnotes.
 struct FootnoteConfig {
     /// The separator between flow content and footnotes. Typically a line.
     separator: Content,
+    /// The separator above the remainder of a broken footnote.
+    continuation: Content,
     /// The amount of space left above the separator.
     clearance: Abs,
     /// The gap between footnote entries.
     gap: Abs,
+    /// The body content needed above a marker to place its entry.
+    min_body: Abs,
     /// Whether horizontal expansion is enabled for footnotes.
     expand: bool,
 }
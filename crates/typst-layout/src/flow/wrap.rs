@@ -0,0 +1,188 @@
+use std::rc::Rc;
+
+use typst_library::diag::SourceResult;
+use typst_library::engine::Engine;
+use typst_library::foundations::{Packed, Resolve, StyleChain};
+use typst_library::introspection::Locator;
+use typst_library::layout::{
+    Abs, Axes, FixedAlignment, Length, PageElem, Point, Regions, Rel, Size,
+};
+use typst_library::model::ParElem;
+
+use super::collect::{line_children, LineChild};
+
+/// A child that encapsulates a paragraph on a page with exclusions.
+///
+/// Which lines must be shortened depends on where the paragraph ends up, so
+/// its line layout is deferred to distribution. The resulting lines are then
+/// distributed just like those of any other paragraph.
+#[derive(Debug)]
+pub struct WrapChild<'a> {
+    pub align: Axes<FixedAlignment>,
+    pub grid: Option<Abs>,
+    pub elem: &'a Packed<ParElem>,
+    pub styles: StyleChain<'a>,
+    pub locator: Locator<'a>,
+    pub consecutive: bool,
+    pub base: Size,
+    pub expand: bool,
+}
+
+impl WrapChild<'_> {
+    /// Lays out the paragraph's lines, each paired with the spacing to its
+    /// predecessor.
+    ///
+    /// The paragraph is first laid out at full width. From the heights of its
+    /// lines, we determine where each line ends up in the regions, starting
+    /// `top` below the top edge of the page body, and shorten those lines that
+    /// vertically overlap an exclusion. If the shortened lines make the
+    /// paragraph longer, we repeat this for the additional lines.
+    pub fn layout(
+        &self,
+        engine: &mut Engine,
+        regions: Regions,
+        top: Abs,
+    ) -> SourceResult<Vec<(Abs, LineChild)>> {
+        let styles = self.styles;
+        let base = self.base;
+        let exclusions: Vec<(Point, Size)> = PageElem::exclude_in(styles)
+            .into_iter()
+            .map(|exclusion| {
+                let resolve =
+                    |rel: Rel<Length>, full: Abs| rel.resolve(styles).relative_to(full);
+                let pos = Point::new(
+                    resolve(exclusion.x, base.x),
+                    resolve(exclusion.y, base.y),
+                );
+                let size = Size::new(
+                    resolve(exclusion.width, base.x),
+                    resolve(exclusion.height, base.y),
+                );
+                (pos, size)
+            })
+            .collect();
+
+        let mut lines = self.layout_lines(engine, vec![])?;
+        loop {
+            let insets = estimate_insets(&exclusions, regions, top, base.x, &lines);
+            if insets.is_empty() {
+                break;
+            }
+
+            let count = lines.len();
+            lines = self.layout_lines(engine, insets)?;
+            if lines.len() <= count {
+                break;
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// Lays out the paragraph's lines with the given insets.
+    fn layout_lines(
+        &self,
+        engine: &mut Engine,
+        insets: Vec<(Abs, Abs)>,
+    ) -> SourceResult<Vec<(Abs, LineChild)>> {
+        let local = ParElem::set_insets(insets).wrap();
+        let lines = crate::layout_inline(
+            engine,
+            &self.elem.children,
+            self.locator.relayout(),
+            self.styles.chain(&local),
+            self.consecutive,
+            self.base,
+            self.expand,
+        )?;
+        Ok(line_children(lines.into_frames(), self.styles, self.align, self.grid)
+            .collect())
+    }
+}
+
+/// Determines how much each of the given lines must be shortened from the
+/// left and the right, based on where it ends up in the regions.
+fn estimate_insets(
+    exclusions: &[(Point, Size)],
+    regions: Regions,
+    top: Abs,
+    width: Abs,
+    lines: &[(Abs, LineChild)],
+) -> Vec<(Abs, Abs)> {
+    let mut cursor = Cursor::new(regions, top);
+    let mut insets: Vec<(Abs, Abs)> = lines
+        .iter()
+        .map(|(gap, line)| {
+            let height = line.frame.height();
+            let top = cursor.advance(height, *gap) + cursor.origin;
+            let mut inset = (Abs::zero(), Abs::zero());
+            for &(pos, size) in exclusions {
+                if top < pos.y + size.y && pos.y < top + height {
+                    if pos.x <= Abs::zero() {
+                        inset.0.set_max(pos.x + size.x);
+                    } else {
+                        inset.1.set_max(width - pos.x);
+                    }
+                }
+            }
+            inset
+        })
+        .collect();
+
+    // Lines past the last exclusion keep their full width.
+    while insets
+        .last()
+        .is_some_and(|(left, right)| left.is_zero() && right.is_zero())
+    {
+        insets.pop();
+    }
+
+    insets
+}
+
+/// Tracks where consecutive lines end up in the regions.
+struct Cursor<'a> {
+    /// The regions the lines are placed into.
+    regions: Regions<'a>,
+    /// The distance from the top of the page body to the start of the
+    /// available space in the current region.
+    origin: Abs,
+    /// The used height in the available space of the current region.
+    used: Abs,
+}
+
+impl<'a> Cursor<'a> {
+    /// Start at the given distance from the top of the page body in the first
+    /// region.
+    fn new(regions: Regions<'a>, origin: Abs) -> Self {
+        Self { regions, origin, used: Abs::zero() }
+    }
+
+    /// Place a line of the given height, separated by the gap from a preceding
+    /// line in the same region. Moves to the next region if the line doesn't
+    /// fit. Returns the line's offset from the start of the available space.
+    fn advance(&mut self, height: Abs, gap: Abs) -> Abs {
+        let mut top = if self.used.is_zero() { Abs::zero() } else { self.used + gap };
+        if !self.regions.size.y.fits(top + height)
+            && !self.used.is_zero()
+            && (!self.regions.backlog.is_empty() || self.regions.last.is_some())
+        {
+            self.regions.next();
+            self.origin = Abs::zero();
+            top = Abs::zero();
+        }
+
+        self.used = top + height;
+        top
+    }
+}
+
+/// The laid out lines of a [`WrapChild`] that are yet to be distributed.
+#[derive(Debug, Clone)]
+pub struct WrapSpill {
+    /// All lines of the paragraph, each paired with the spacing to its
+    /// predecessor.
+    pub lines: Rc<Vec<(Abs, LineChild)>>,
+    /// The index of the next line to distribute.
+    pub next: usize,
+}
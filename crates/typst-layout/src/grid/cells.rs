@@ -231,15 +231,16 @@ impl<'a> CellGrid<'a> {
     /// Resolves and positions all cells in the grid before creating it.
     /// Allows them to keep track of their final properties and positions
     /// and adjust their fields accordingly.
-    /// Cells must implement Clone as they will be owned. Additionally, they
-    /// must implement Default in order to fill positions in the grid which
-    /// weren't explicitly specified by the user with empty cells.
+    /// Cells must implement Clone as they will be owned. Additionally, the
+    /// `empty` function creates the cells which fill positions in the grid
+    /// that weren't explicitly specified by the user.
     #[allow(clippy::too_many_arguments)]
     pub fn resolve<T, C, I>(
         tracks: Axes<&[Sizing]>,
         gutter: Axes<&[Sizing]>,
         locator: Locator<'a>,
         children: C,
+        empty: impl Fn() -> T,
         fill: &Celled<Option<Paint>>,
         align: &Celled<Smart<Alignment>>,
         inset: &Celled<Sides<Option<Rel<Length>>>>,
@@ -249,7 +250,7 @@ impl<'a> CellGrid<'a> {
         span: Span,
     ) -> SourceResult<Self>
     where
-        T: ResolvableCell + Default,
+        T: ResolvableCell,
         I: Iterator<Item = ResolvableGridItem<T>>,
         C: IntoIterator<Item = ResolvableGridChild<T, I>>,
         C::IntoIter: ExactSizeIterator,
@@ -813,7 +814,7 @@ impl<'a> CellGrid<'a> {
 
                     // Ensure all absent entries are affected by show rules and
                     // grid styling by turning them into resolved empty cells.
-                    let new_cell = T::default().resolve_cell(
+                    let new_cell = empty().resolve_cell(
                         x,
                         y,
                         &fill.resolve(engine, styles, x, y)?,
@@ -2,10 +2,11 @@ use std::fmt::Debug;
 
 use typst_library::diag::{bail, SourceResult};
 use typst_library::engine::Engine;
-use typst_library::foundations::{Resolve, StyleChain};
+use typst_library::foundations::{Content, Resolve, StyleChain};
+use typst_library::introspection::{Locator, SplitLocator};
 use typst_library::layout::{
-    Abs, Axes, Dir, Fr, Fragment, Frame, FrameItem, Length, Point, Region, Regions, Rel,
-    Size, Sizing,
+    Abs, Axes, ColumnMaxWidths, Dir, Fr, Fragment, Frame, FrameItem, Length, Point,
+    Region, Regions, Rel, Size, Sizing,
 };
 use typst_library::text::TextElem;
 use typst_library::visualize::Geometry;
@@ -58,6 +59,17 @@ pub struct GridLayouter<'a> {
     pub(super) footer_height: Abs,
     /// The span of the grid element.
     pub(super) span: Span,
+    /// The maximum widths of auto columns.
+    pub(super) max_widths: ColumnMaxWidths,
+    /// The note to display below unfinished parts of broken rows, together
+    /// with the locator to lay out each of its instances with.
+    pub(super) continued: Option<(&'a Content, SplitLocator<'a>)>,
+    /// The height of the continuation note. Measured once the columns are
+    /// known.
+    pub(super) continued_height: Abs,
+    /// In how many regions, starting with the current one, the auto row
+    /// that is being measured reserves space for the continuation note.
+    pub(super) continued_in: usize,
 }
 
 /// Details about a resulting row piece.
@@ -122,13 +134,37 @@ impl<'a> GridLayouter<'a> {
             header_height: Abs::zero(),
             footer_height: Abs::zero(),
             span,
+            max_widths: ColumnMaxWidths::default(),
+            continued: None,
+            continued_height: Abs::zero(),
+            continued_in: 0,
         }
     }
 
+    /// Limits how wide the grid's auto columns may grow.
+    pub fn with_max_widths(mut self, max_widths: ColumnMaxWidths) -> Self {
+        self.max_widths = max_widths;
+        self
+    }
+
+    /// Displays a note below each part of a row that is broken across
+    /// regions.
+    pub fn with_continued(
+        mut self,
+        continued: Option<(&'a Content, Locator<'a>)>,
+    ) -> Self {
+        self.continued = continued.map(|(body, locator)| (body, locator.split()));
+        self
+    }
+
     /// Determines the columns sizes and then layouts the grid row-by-row.
     pub fn layout(mut self, engine: &mut Engine) -> SourceResult<Fragment> {
         self.measure_columns(engine)?;
 
+        if let Some(note) = self.layout_continued(engine)? {
+            self.continued_height = note.height();
+        }
+
         if let Some(Repeatable::Repeated(footer)) = &self.grid.footer {
             // Ensure rows in the first region will be aware of the possible
             // presence of the footer.
@@ -847,6 +883,12 @@ impl<'a> GridLayouter<'a> {
                 resolved.set_max(frame.width() - already_covered_width);
             }
 
+            // Stop growing at the column's maximum width, in which case its
+            // cells' content wraps instead.
+            if let Some(max) = self.max_width(x) {
+                resolved.set_min(max);
+            }
+
             self.rcols[x] = resolved;
             auto += resolved;
             count += 1;
@@ -855,6 +897,23 @@ impl<'a> GridLayouter<'a> {
         Ok((auto, count))
     }
 
+    /// The maximum width of the track with the given index, if it is a
+    /// column with a maximum width and not a gutter.
+    fn max_width(&self, x: usize) -> Option<Abs> {
+        let index = if self.grid.has_gutter {
+            if x % 2 == 1 {
+                return None;
+            }
+            x / 2
+        } else {
+            x
+        };
+
+        self.max_widths
+            .get(index)
+            .map(|max| max.resolve(self.styles).relative_to(self.regions.base().x))
+    }
+
     /// Distribute remaining space to fractional columns.
     fn grow_fractional_columns(&mut self, remaining: Abs, fr: Fr) {
         if fr.is_zero() {
@@ -934,6 +993,24 @@ impl<'a> GridLayouter<'a> {
             }
         };
 
+        // Reserve space for the continuation note in each region the row
+        // continues past. Reserving space can only make the row span more
+        // regions, so we widen the reservation until the row ends in the
+        // first region without one.
+        while self.continued.is_some() && resolved.len() > self.continued_in + 1 {
+            self.continued_in = resolved.len() - 1;
+            resolved = self
+                .measure_auto_row(
+                    engine,
+                    disambiguator,
+                    y,
+                    false,
+                    self.unbreakable_rows_left,
+                    None,
+                )?
+                .unwrap();
+        }
+
         // Nothing to layout.
         if resolved.is_empty() {
             return Ok(());
@@ -976,14 +1053,25 @@ impl<'a> GridLayouter<'a> {
                         self.header_height + self.footer_height
                     } else {
                         Abs::zero()
-                    },
+                    }
+                    - self.continued_height_in(i),
             );
         }
+        self.continued_in = 0;
 
         // Layout into multiple regions.
         let fragment = self.layout_multi_row(engine, disambiguator, &resolved, y)?;
         let len = fragment.len();
-        for (i, frame) in fragment.into_iter().enumerate() {
+        for (i, mut frame) in fragment.into_iter().enumerate() {
+            // Display the continuation note in the space reserved for it.
+            if i + 1 < len {
+                if let Some(note) = self.layout_continued(engine)? {
+                    let pos = Point::with_y(frame.height());
+                    frame.size_mut().y += note.height();
+                    frame.push_frame(pos, note);
+                }
+            }
+
             self.push_row(frame, y, i + 1 == len);
             if i + 1 < len {
                 self.finish_region(engine, false)?;
@@ -1328,6 +1416,23 @@ impl<'a> GridLayouter<'a> {
         Ok(Fragment::frames(outputs))
     }
 
+    /// Lay out a new instance of the continuation note, if there is one.
+    fn layout_continued(&mut self, engine: &mut Engine) -> SourceResult<Option<Frame>> {
+        let Some((body, locator)) = &mut self.continued else { return Ok(None) };
+        let pod = Region::new(Size::new(self.width, Abs::inf()), Axes::new(true, false));
+        crate::layout_frame(engine, body, locator.next(&()), self.styles, pod).map(Some)
+    }
+
+    /// The height to reserve for the continuation note in the region with the
+    /// given index, where zero is the current region.
+    pub(super) fn continued_height_in(&self, i: usize) -> Abs {
+        if i < self.continued_in {
+            self.continued_height
+        } else {
+            Abs::zero()
+        }
+    }
+
     /// Push a row frame into the current region.
     /// The `is_last` parameter must be `true` if this is the last frame which
     /// will be pushed for this particular row. It can be `false` for rows
@@ -1338,6 +1443,7 @@ impl<'a> GridLayouter<'a> {
     }
 
     /// Finish rows for one region.
+    #[typst_macros::time(name = "finish grid region")]
     pub(super) fn finish_region(
         &mut self,
         engine: &mut Engine,
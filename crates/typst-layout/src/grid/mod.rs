@@ -78,6 +78,7 @@ pub fn layout_grid(
         gutter,
         locator,
         children,
+        Packed::<GridCell>::default,
         fill,
         align,
         &inset,
@@ -88,7 +89,8 @@ pub fn layout_grid(
     )
     .trace(engine.world, tracepoint, elem.span())?;
 
-    let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
+    let layouter = GridLayouter::new(&grid, regions, styles, elem.span())
+        .with_max_widths(elem.max_width(styles).clone());
 
     // Measure the columns and layout the grid row-by-row.
     layouter.layout(engine)
@@ -111,6 +113,9 @@ pub fn layout_table(
     let row_gutter = elem.row_gutter(styles);
     let fill = elem.fill(styles);
     let stroke = elem.stroke(styles);
+    let empty = elem.empty(styles);
+    let continued = elem.continued(styles);
+    let mut locator = locator.split();
 
     let tracks = Axes::new(columns.0.as_slice(), rows.0.as_slice());
     let gutter = Axes::new(column_gutter.0.as_slice(), row_gutter.0.as_slice());
@@ -135,8 +140,12 @@ pub fn layout_table(
     let grid = CellGrid::resolve(
         tracks,
         gutter,
-        locator,
+        locator.next(&()),
         children,
+        || match &empty {
+            Some(body) => Packed::new(TableCell::new(body.clone())),
+            None => Packed::default(),
+        },
         fill,
         align,
         &inset,
@@ -147,7 +156,9 @@ pub fn layout_table(
     )
     .trace(engine.world, tracepoint, elem.span())?;
 
-    let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
+    let layouter = GridLayouter::new(&grid, regions, styles, elem.span())
+        .with_max_widths(elem.max_width(styles).clone())
+        .with_continued(continued.as_ref().map(|body| (body, locator.next(&()))));
     layouter.layout(engine)
 }
 
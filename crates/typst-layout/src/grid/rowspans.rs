@@ -5,7 +5,7 @@ use typst_library::layout::{Abs, Axes, Frame, Point, Region, Regions, Size, Sizi
 use typst_utils::MaybeReverseIter;
 
 use super::layouter::{in_last_with_offset, points, Row, RowPiece};
-use super::repeated::Repeatable;
+use super::repeated::{Footer, Repeatable};
 use super::{Cell, GridLayouter};
 
 /// All information needed to layout a single rowspan.
@@ -235,14 +235,21 @@ impl GridLayouter<'_> {
                     amount_unbreakable_rows = Some(header.end);
                 }
             }
+            let mut kept_with_footer = false;
             if let Some(Repeatable::NotRepeated(footer)) = &self.grid.footer {
                 if current_row >= footer.start {
                     // Non-repeated footer, so keep it unbreakable.
                     amount_unbreakable_rows = Some(self.grid.rows.len() - footer.start);
+                } else if self.is_last_body_row(current_row, footer) {
+                    // Keep the last body row together with the non-repeated
+                    // footer, so that the footer isn't orphaned at the top of
+                    // a new region.
+                    amount_unbreakable_rows = Some(self.grid.rows.len() - current_row);
+                    kept_with_footer = true;
                 }
             }
 
-            let row_group = self.simulate_unbreakable_row_group(
+            let mut row_group = self.simulate_unbreakable_row_group(
                 current_row,
                 amount_unbreakable_rows,
                 &self.regions,
@@ -250,6 +257,23 @@ impl GridLayouter<'_> {
                 0,
             )?;
 
+            // If the last body row and the footer wouldn't even fit together
+            // into a fresh region, let the last body row break normally.
+            if kept_with_footer
+                && !self
+                    .regions
+                    .full
+                    .fits(row_group.height + self.header_height + self.footer_height)
+            {
+                row_group = self.simulate_unbreakable_row_group(
+                    current_row,
+                    None,
+                    &self.regions,
+                    engine,
+                    0,
+                )?;
+            }
+
             // Skip to fitting region.
             while !self.regions.size.y.fits(row_group.height)
                 && !in_last_with_offset(
@@ -285,6 +309,18 @@ impl GridLayouter<'_> {
         Ok(())
     }
 
+    /// Whether the given row is the last content row before a footer which
+    /// isn't part of the header.
+    fn is_last_body_row(&self, y: usize, footer: &Footer) -> bool {
+        let last = (0..footer.start).rev().find(|&y| !self.grid.is_gutter_track(y));
+        last == Some(y)
+            && self
+                .grid
+                .header
+                .as_ref()
+                .map_or(true, |header| header.unwrap().end <= y)
+    }
+
     /// Simulates a group of unbreakable rows, starting with the index of the
     /// first row in the group. If `amount_unbreakable_rows` is `None`, keeps
     /// adding rows to the group until none have unbreakable cells in common.
@@ -395,20 +431,31 @@ impl GridLayouter<'_> {
             // region.
             if breakable
                 && (matches!(self.grid.header, Some(Repeatable::Repeated(_)))
-                    || matches!(self.grid.footer, Some(Repeatable::Repeated(_))))
+                    || matches!(self.grid.footer, Some(Repeatable::Repeated(_)))
+                    || self.continued_in > 1)
             {
                 // Subtract header and footer height from all upcoming regions
                 // when measuring the cell, including the last repeated region.
+                // The space reserved for the continuation note is subtracted
+                // from the upcoming regions the row continues past.
                 //
                 // This will update the 'custom_backlog' vector with the
                 // updated heights of the upcoming regions.
-                let mapped_regions = self.regions.map(&mut custom_backlog, |size| {
-                    Size::new(size.x, size.y - self.header_height - self.footer_height)
+                let reserved = self.header_height + self.footer_height;
+                let last = self
+                    .regions
+                    .map(&mut custom_backlog, |size| Size::new(size.x, size.y - reserved))
+                    .last;
+                for (i, height) in custom_backlog.iter_mut().enumerate() {
+                    *height -= self.continued_height_in(i + 1);
+                }
+                let last = last.map(|height| {
+                    height - self.continued_height_in(custom_backlog.len() + 1)
                 });
 
                 // Callees must use the custom backlog instead of the current
                 // backlog, so we return 'None'.
-                return (None, mapped_regions.last);
+                return (None, last);
             }
 
             // No need to change the backlog or last region.
@@ -448,7 +495,11 @@ impl GridLayouter<'_> {
             // 3. Use the same full region height.
             // 4. No height occupied by this cell in this region so far.
             // 5. Yes, this cell started in this region.
-            height = if breakable { self.regions.size.y } else { Abs::inf() };
+            height = if breakable {
+                self.regions.size.y - self.continued_height_in(0)
+            } else {
+                Abs::inf()
+            };
             (backlog, last) = subtract_header_footer_height_from_regions();
             full = if breakable { self.regions.full } else { Abs::inf() };
             height_in_this_region = Abs::zero();
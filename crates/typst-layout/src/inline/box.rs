@@ -2,14 +2,18 @@ use std::cell::LazyCell;
 
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
-use typst_library::foundations::{Packed, StyleChain};
+use typst_library::foundations::{Content, Packed, StyleChain};
 use typst_library::introspection::Locator;
-use typst_library::layout::{BoxElem, Frame, FrameKind, Size};
-use typst_library::visualize::Stroke;
+use typst_library::layout::{
+    Abs, Axes, BoxElem, Em, Frame, FrameItem, FrameKind, Point, Region, Size,
+    TextOverflow,
+};
+use typst_library::text::{TextElem, TextSize};
+use typst_library::visualize::{Path, Stroke};
 use typst_utils::Numeric;
 
 use crate::flow::unbreakable_pod;
-use crate::shapes::{clip_rect, fill_and_stroke};
+use crate::shapes::{clip_rect, fill_and_stroke, fill_styles};
 
 /// Lay out a box as part of a paragraph.
 #[typst_macros::time(name = "box", span = elem.span())]
@@ -28,7 +32,16 @@ pub fn layout_box(
     // Build the pod region.
     let pod = unbreakable_pod(&width, &height.into(), &inset, styles, region);
 
+    // Text with an automatic fill contrasts with the box's own fill.
+    let local = fill_styles(elem.fill(styles).as_ref());
+    let styles = styles.chain(&local);
+
+    // Fitting and overflow handling need a width to fit into, which an
+    // automatically sized box doesn't have.
+    let fixed = !width.is_auto() && pod.size.x.is_finite();
+
     // Layout the body.
+    let overflow = elem.text_overflow(styles);
     let mut frame = match elem.body(styles) {
         // If we have no body, just create an empty frame. If necessary,
         // its size will be adjusted below.
@@ -36,6 +49,20 @@ pub fn layout_box(
 
         // If we have a child, layout it into the body. Boxes are boundaries
         // for gradient relativeness, so we set the `FrameKind` to `Hard`.
+        Some(body) if fixed && elem.fit(styles) => {
+            let min = elem.fit_min(styles);
+            let scale = fit_scale(engine, body, &locator, styles, pod.size, min)?;
+            let scaled = body
+                .clone()
+                .styled(TextElem::set_size(TextSize(Em::new(scale).into())));
+            crate::layout_frame(engine, &scaled, locator, styles, pod)?
+                .with_kind(FrameKind::Hard)
+        }
+        Some(body) if fixed && overflow.is_some() => {
+            let overflow = overflow.unwrap();
+            layout_single_line(engine, body, locator, styles, pod.size.x, overflow)?
+                .with_kind(FrameKind::Hard)
+        }
         Some(body) => crate::layout_frame(engine, body, locator, styles, pod)?
             .with_kind(FrameKind::Hard),
     };
@@ -85,3 +112,148 @@ pub fn layout_box(
 
     Ok(frame)
 }
+
+/// Determines the factor by which the text in a box's body must be scaled so
+/// that the body fits into the given size without wrapping.
+///
+/// The factor is at most one and scales the text size to at least `min`.
+fn fit_scale(
+    engine: &mut Engine,
+    body: &Content,
+    locator: &Locator,
+    styles: StyleChain,
+    size: Size,
+    min: Abs,
+) -> SourceResult<f64> {
+    // Lay out the body at its natural size so that it doesn't wrap.
+    let natural = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+    let fits = |engine: &mut Engine, scale: f64| -> SourceResult<bool> {
+        let scaled = body
+            .clone()
+            .styled(TextElem::set_size(TextSize(Em::new(scale).into())));
+        let frame =
+            crate::layout_frame(engine, &scaled, locator.relayout(), styles, natural)?;
+        Ok(size.x.fits(frame.width())
+            && (!size.y.is_finite() || size.y.fits(frame.height())))
+    };
+
+    if fits(engine, 1.0)? {
+        return Ok(1.0);
+    }
+
+    let mut low = (min / TextElem::size_in(styles)).min(1.0);
+    if !fits(engine, low)? {
+        return Ok(low);
+    }
+
+    // Search for the largest factor at which the body still fits.
+    let mut high = 1.0;
+    while high - low > FIT_TOLERANCE {
+        let mid = (low + high) / 2.0;
+        if fits(engine, mid)? {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(low)
+}
+
+/// The precision up to which the text scale of a fitted box is determined.
+const FIT_TOLERANCE: f64 = 0.01;
+
+/// Lays out a box's body on a single line and cuts it off at the given width.
+///
+/// With an ellipsis, the glyphs that don't fit together with it are removed
+/// and the ellipsis is put after the last remaining one.
+fn layout_single_line(
+    engine: &mut Engine,
+    body: &Content,
+    locator: Locator,
+    styles: StyleChain,
+    width: Abs,
+    overflow: TextOverflow,
+) -> SourceResult<Frame> {
+    let natural = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+    let dots = match overflow {
+        TextOverflow::Clip => None,
+        TextOverflow::Ellipsis => {
+            let dots = TextElem::packed("…").spanned(body.span());
+            Some(crate::layout_frame(engine, &dots, locator.relayout(), styles, natural)?)
+        }
+    };
+
+    let frame = crate::layout_frame(engine, body, locator, styles, natural)?;
+    if width.fits(frame.width()) {
+        return Ok(frame);
+    }
+
+    let limit =
+        (width - dots.as_ref().map_or(Abs::zero(), Frame::width)).max(Abs::zero());
+    let (mut frame, end) = match dots {
+        Some(_) => truncate(&frame, limit),
+        None => (frame, limit),
+    };
+
+    // Leave some room above and below for glyphs that overshoot the line.
+    let overshoot = frame.height();
+    let mut clip = Path::rect(Size::new(limit + overshoot, 3.0 * overshoot));
+    clip.translate(Point::splat(-overshoot));
+    frame.clip(clip);
+
+    if let Some(dots) = dots {
+        let y = frame.baseline() - dots.baseline();
+        frame.push_frame(Point::new(end, y), dots);
+    }
+
+    Ok(frame)
+}
+
+/// Removes the glyphs of a frame that extend past `limit`, along with other
+/// items that start after it. Also removes spaces before the cut.
+///
+/// Returns the truncated frame and where its remaining content ends.
+fn truncate(frame: &Frame, limit: Abs) -> (Frame, Abs) {
+    let mut output = Frame::soft(frame.size());
+    output.set_baseline(frame.baseline());
+
+    let mut end = Abs::zero();
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Text(text) => {
+                let mut text = text.clone();
+                let mut x = pos.x;
+                let count = text
+                    .glyphs
+                    .iter()
+                    .take_while(|glyph| {
+                        x += glyph.x_advance.at(text.size);
+                        x <= limit
+                    })
+                    .count();
+                text.glyphs.truncate(count);
+                while text
+                    .glyphs
+                    .last()
+                    .is_some_and(|glyph| text.text[glyph.range()].trim().is_empty())
+                {
+                    text.glyphs.pop();
+                }
+                if !text.glyphs.is_empty() {
+                    end.set_max(pos.x + text.width());
+                    output.push(*pos, FrameItem::Text(text));
+                }
+            }
+            FrameItem::Group(group) if pos.x < limit => {
+                end.set_max((pos.x + group.frame.width()).min(limit));
+                output.push(*pos, item.clone());
+            }
+            FrameItem::Tag(_) => output.push(*pos, item.clone()),
+            _ if pos.x < limit => output.push(*pos, item.clone()),
+            _ => {}
+        }
+    }
+
+    (output, end)
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use typst_library::diag::bail;
 use typst_library::foundations::{Packed, Resolve};
 use typst_library::introspection::{SplitLocator, Tag, TagElem};
@@ -6,11 +8,12 @@ use typst_library::layout::{
     Spacing,
 };
 use typst_library::text::{
-    is_default_ignorable, LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes,
-    SpaceElem, TextElem,
+    is_default_ignorable, KernElem, Lang, LinebreakElem, SmartQuoteElem, SmartQuoter,
+    SmartQuotes, SpaceElem, TextDir, TextElem,
 };
 use typst_syntax::Span;
 use typst_utils::Numeric;
+use unicode_bidi::Direction;
 
 use super::*;
 
@@ -19,6 +22,10 @@ use super::*;
 const SPACING_REPLACE: &str = " "; // Space
 const OBJ_REPLACE: &str = "\u{FFFC}"; // Object Replacement Character
 
+// The character by which manual kerns are replaced. Unlike a space, it does not
+// provide a line break opportunity.
+const KERN_REPLACE: &str = "\u{2060}"; // Word Joiner
+
 // Unicode BiDi control characters.
 const LTR_EMBEDDING: &str = "\u{202A}";
 const RTL_EMBEDDING: &str = "\u{202B}";
@@ -33,6 +40,8 @@ pub enum Item<'a> {
     Text(ShapedText<'a>),
     /// Absolute spacing between other items, and whether it is weak.
     Absolute(Abs, bool),
+    /// A manual kern between two glyphs. Never provides a break opportunity.
+    Kern(Abs),
     /// Fractional spacing between other items.
     Fractional(Fr, Option<(&'a Packed<BoxElem>, Locator<'a>, StyleChain<'a>)>),
     /// Layouted inline-level content.
@@ -67,6 +76,7 @@ impl<'a> Item<'a> {
         match self {
             Self::Text(shaped) => shaped.text,
             Self::Absolute(_, _) | Self::Fractional(_, _) => SPACING_REPLACE,
+            Self::Kern(_) => KERN_REPLACE,
             Self::Frame(_, _) => OBJ_REPLACE,
             Self::Tag(_) => "",
             Self::Skip(s) => s,
@@ -82,7 +92,7 @@ impl<'a> Item<'a> {
     pub fn natural_width(&self) -> Abs {
         match self {
             Self::Text(shaped) => shaped.width,
-            Self::Absolute(v, _) => *v,
+            Self::Absolute(v, _) | Self::Kern(v) => *v,
             Self::Frame(frame, _) => frame.width(),
             Self::Fractional(_, _) | Self::Tag(_) => Abs::zero(),
             Self::Skip(_) => Abs::zero(),
@@ -121,17 +131,17 @@ pub fn collect<'a>(
     engine: &mut Engine<'_>,
     locator: &mut SplitLocator<'a>,
     styles: &'a StyleChain<'a>,
+    outer_dir: Dir,
     region: Size,
     consecutive: bool,
 ) -> SourceResult<(String, Vec<Segment<'a>>, SpanMapper)> {
     let mut collector = Collector::new(2 + children.len());
     let mut quoter = SmartQuoter::new();
 
-    let outer_dir = TextElem::dir_in(*styles);
     let first_line_indent = ParElem::first_line_indent_in(*styles);
     if !first_line_indent.is_zero()
         && consecutive
-        && AlignElem::alignment_in(*styles).resolve(*styles).x == outer_dir.start().into()
+        && AlignElem::alignment_in(*styles).fix(outer_dir).x == outer_dir.start().into()
     {
         collector.push_item(Item::Absolute(first_line_indent.resolve(*styles), false));
         collector.spans.push(1, Span::detached());
@@ -143,14 +153,34 @@ pub fn collect<'a>(
         collector.spans.push(1, Span::detached());
     }
 
-    for (child, styles) in children.iter(styles) {
+    let mut iter = children.iter(styles).peekable();
+    while let Some((child, styles)) = iter.next() {
         let prev_len = collector.full.len();
 
         if child.is::<SpaceElem>() {
-            collector.push_text(" ", styles);
+            // In French, spaces before high punctuation and inside of
+            // guillemets become narrow no-break spaces.
+            let narrow = has_french_spacing(styles)
+                && (collector.full.chars().rev().find(|&c| !is_default_ignorable(c))
+                    == Some('«')
+                    || iter
+                        .peek()
+                        .and_then(|(next, _)| next.to_packed::<TextElem>())
+                        .is_some_and(|next| {
+                            let mut chars = next.text().chars();
+                            chars
+                                .next()
+                                .is_some_and(|c| is_french_closing(c, chars.next()))
+                        }));
+            collector.push_text(if narrow { "\u{202F}" } else { " " }, styles);
         } else if let Some(elem) = child.to_packed::<TextElem>() {
             collector.build_text(styles, |full| {
-                let dir = TextElem::dir_in(styles);
+                // Runs without an explicit direction follow the paragraph.
+                let dir = if TextDir::is_inferred_in(styles) {
+                    outer_dir
+                } else {
+                    TextElem::dir_in(styles)
+                };
                 if dir != outer_dir {
                     // Insert "Explicit Directional Embedding".
                     match dir {
@@ -160,11 +190,18 @@ pub fn collect<'a>(
                     }
                 }
 
+                let mut text = Cow::Borrowed(elem.text().as_str());
                 if let Some(case) = TextElem::case_in(styles) {
-                    full.push_str(&case.apply(elem.text()));
-                } else {
-                    full.push_str(elem.text());
+                    text = Cow::Owned(case.apply(&text));
                 }
+                if let Some(form) = TextElem::normalization_in(styles) {
+                    text = Cow::Owned(form.apply(&text));
+                }
+                if has_french_spacing(styles) {
+                    let before = full.chars().rev().find(|&c| !is_default_ignorable(c));
+                    text = Cow::Owned(french_spacing(&text, before));
+                }
+                full.push_str(&text);
 
                 if dir != outer_dir {
                     // Insert "Pop Directional Formatting".
@@ -184,6 +221,13 @@ pub fn collect<'a>(
                     elem.weak(styles),
                 ),
             });
+        } else if let Some(elem) = child.to_packed::<KernElem>() {
+            let amount = elem.amount().resolve(styles);
+            if amount.is_zero() {
+                continue;
+            }
+
+            collector.push_item(Item::Kern(amount));
         } else if let Some(elem) = child.to_packed::<LinebreakElem>() {
             collector
                 .push_text(if elem.justify(styles) { "\u{2028}" } else { "\n" }, styles);
@@ -198,12 +242,24 @@ pub fn collect<'a>(
                 );
                 let before =
                     collector.full.chars().rev().find(|&c| !is_default_ignorable(c));
-                let quote = quoter.quote(before, &quotes, double);
+                let after = iter
+                    .peek()
+                    .and_then(|(next, _)| next.to_packed::<TextElem>())
+                    .and_then(|text| text.text().chars().next());
+                let quote = quoter.quote(before, after, &quotes, double);
                 collector.push_text(quote, styles);
             } else {
                 collector.push_text(if double { "\"" } else { "'" }, styles);
             }
         } else if let Some(elem) = child.to_packed::<InlineElem>() {
+            // Separate inline math from a preceding opening guillemet.
+            if has_french_spacing(styles)
+                && collector.full.chars().rev().find(|&c| !is_default_ignorable(c))
+                    == Some('«')
+            {
+                collector.push_text("\u{202F}", styles);
+            }
+
             collector.push_item(Item::Skip(LTR_ISOLATE));
 
             for item in elem.layout(engine, locator.next(&elem.span()), styles, region)? {
@@ -239,6 +295,88 @@ pub fn collect<'a>(
     Ok((collector.full, collector.segments, collector.spans))
 }
 
+/// Determines the base direction of a paragraph.
+///
+/// This is the text direction, unless neither it nor the language are set. In
+/// that case, the paragraph follows the first character with a strong
+/// direction in its text.
+pub fn base_dir(children: &StyleVec, styles: StyleChain) -> Dir {
+    let dir = TextElem::dir_in(styles);
+    if !TextDir::is_inferred_in(styles) {
+        return dir;
+    }
+
+    children
+        .iter(&styles)
+        .filter_map(|(child, _)| child.to_packed::<TextElem>())
+        .find_map(|elem| match unicode_bidi::get_base_direction(elem.text().as_str()) {
+            Direction::Ltr => Some(Dir::LTR),
+            Direction::Rtl => Some(Dir::RTL),
+            Direction::Mixed => None,
+        })
+        .unwrap_or(dir)
+}
+
+/// Whether punctuation spacing applies to text with the given styles.
+fn has_french_spacing(styles: StyleChain) -> bool {
+    TextElem::punctuation_spacing_in(styles) && TextElem::lang_in(styles) == Lang::FRENCH
+}
+
+/// Inserts narrow no-break spaces before high punctuation and inside of
+/// guillemets, as is customary in French typography.
+///
+/// The `before` character is the one preceding the text in the paragraph.
+/// Spaces directly before punctuation or after an opening guillemet are
+/// replaced instead of being added to.
+fn french_spacing(text: &str, before: Option<char>) -> String {
+    const NNBSP: char = '\u{202F}';
+
+    let mut out = String::with_capacity(text.len() + 3);
+    let mut prev = before;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let next = chars.peek().copied();
+        if is_french_closing(c, next) {
+            if out.ends_with(' ') {
+                out.pop();
+                out.push(NNBSP);
+            } else if prev.is_some_and(|p| !p.is_whitespace() && !is_high_punct(p)) {
+                out.push(NNBSP);
+            }
+        }
+
+        out.push(c);
+        prev = Some(c);
+
+        if c == '«' {
+            if next == Some(' ') {
+                chars.next();
+                out.push(NNBSP);
+                prev = Some(NNBSP);
+            } else if next.is_some_and(|n| !n.is_whitespace()) {
+                out.push(NNBSP);
+                prev = Some(NNBSP);
+            }
+        }
+    }
+
+    out
+}
+
+/// Whether a narrow no-break space belongs before the character `c`, given the
+/// character that follows it.
+fn is_french_closing(c: char, next: Option<char>) -> bool {
+    // Only treat high punctuation at the end of a word as such, so that times
+    // like "10:30" stay as they are.
+    let ends_word = next.map_or(true, |n| n.is_whitespace() || is_high_punct(n));
+    (is_high_punct(c) && ends_word) || c == '»'
+}
+
+/// Whether the character is high punctuation in French typography.
+fn is_high_punct(c: char) -> bool {
+    matches!(c, ';' | ':' | '!' | '?')
+}
+
 /// Collects segments.
 struct Collector<'a> {
     full: String,
@@ -267,9 +405,11 @@ impl<'a> Collector<'a> {
         f(&mut self.full);
         let segment_len = self.full.len() - prev;
 
-        // Merge adjacent text segments with the same styles.
+        // Merge adjacent text segments with the same styles, so that they are
+        // shaped as one run. The styles may also stem from separate, but
+        // identical rules, e.g. in `#text(red)[a]#text(red)[b]`.
         if let Some(Segment::Text(last_len, last_styles)) = self.segments.last_mut() {
-            if *last_styles == styles {
+            if last_styles.equivalent(styles) {
                 *last_len += segment_len;
                 return;
             }
@@ -2,9 +2,9 @@ use kurbo::{BezPath, Line, ParamCurve};
 use ttf_parser::{GlyphId, OutlineBuilder};
 use typst_library::layout::{Abs, Em, Frame, FrameItem, Point, Size};
 use typst_library::text::{
-    BottomEdge, DecoLine, Decoration, TextEdgeBounds, TextItem, TopEdge,
+    BottomEdge, DecoLine, Decoration, Glyph, TextEdgeBounds, TextItem, TopEdge, ZeroForm,
 };
-use typst_library::visualize::{FixedStroke, Geometry};
+use typst_library::visualize::{FixedStroke, Geometry, Path};
 use typst_syntax::Span;
 
 use crate::shapes::styled_rect;
@@ -136,6 +136,51 @@ pub fn decorate(
     }
 }
 
+/// Draw a slash or a dot over a zero glyph whose font can't provide the
+/// requested form itself.
+///
+/// The `pos` is the position of the glyph's origin on the baseline.
+pub fn synthesize_zero(
+    frame: &mut Frame,
+    form: ZeroForm,
+    text: &TextItem,
+    glyph: &Glyph,
+    pos: Point,
+) {
+    let Some(bbox) = text.font.ttf().glyph_bounding_box(GlyphId(glyph.id)) else {
+        return;
+    };
+
+    let to_abs = |units: i16| text.font.to_em(units).at(text.size);
+    let (left, right) = (pos.x + to_abs(bbox.x_min), pos.x + to_abs(bbox.x_max));
+    let (top, bottom) = (pos.y - to_abs(bbox.y_max), pos.y - to_abs(bbox.y_min));
+    let (width, height) = (right - left, bottom - top);
+    let thickness = text.font.metrics().underline.thickness.at(text.size);
+    let paint = text.fill.as_decoration();
+
+    let (origin, shape) = match form {
+        // A slash from the lower left to the upper right inside the glyph.
+        ZeroForm::Slashed => {
+            let from = Point::new(left + 0.2 * width, bottom - 0.15 * height);
+            let to = Point::new(left + 0.8 * width, top + 0.15 * height);
+            let shape = Geometry::Line(to - from)
+                .stroked(FixedStroke::from_pair(paint, thickness));
+            (from, shape)
+        }
+        // A dot in the center of the glyph.
+        ZeroForm::Dotted => {
+            let radius = 1.25 * thickness;
+            let center = Point::new(left + width / 2.0, top + height / 2.0);
+            let origin = center - Point::new(radius, radius);
+            let shape =
+                Geometry::Path(Path::ellipse(Size::splat(2.0 * radius))).filled(paint);
+            (origin, shape)
+        }
+    };
+
+    frame.push(origin, FrameItem::Shape(shape, glyph.span.0));
+}
+
 // Return the top/bottom edge of the text given the metric of the font.
 fn determine_edges(
     text: &TextItem,
@@ -211,3 +256,76 @@ impl OutlineBuilder for BezPathBuilder {
         self.path.close_path();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::Bytes;
+    use typst_library::text::{Font, Lang};
+    use typst_library::visualize::Color;
+
+    use super::*;
+
+    /// Synthesize the given zero form over a 10pt zero on the baseline of a
+    /// 10pt high frame and return the frame and the glyph's extent.
+    fn synthesized(form: ZeroForm) -> (Frame, Abs) {
+        let font = typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .find(|font| font.info().family == "DejaVu Sans Mono")
+            .unwrap();
+        let id = font.ttf().glyph_index('0').unwrap().0;
+        let glyph = Glyph {
+            id,
+            x_advance: font.advance(id).unwrap(),
+            x_offset: Em::zero(),
+            range: 0..1,
+            span: (Span::detached(), 0),
+        };
+        let size = Abs::pt(10.0);
+        let advance = glyph.x_advance.at(size);
+        let text = TextItem {
+            font,
+            size,
+            fill: Color::BLACK.into(),
+            stroke: None,
+            lang: Lang::ENGLISH,
+            region: None,
+            text: "0".into(),
+            glyphs: vec![glyph.clone()],
+        };
+
+        let mut frame = Frame::soft(Size::new(advance, size));
+        synthesize_zero(&mut frame, form, &text, &glyph, Point::with_y(size));
+        (frame, advance)
+    }
+
+    #[test]
+    fn test_synthesized_slash_crosses_the_zero() {
+        let (frame, advance) = synthesized(ZeroForm::Slashed);
+        let items: Vec<_> = frame.items().collect();
+        assert_eq!(items.len(), 1);
+
+        let (pos, FrameItem::Shape(shape, _)) = items[0] else { panic!() };
+        let Geometry::Line(delta) = shape.geometry else { panic!() };
+        assert!(shape.stroke.is_some() && shape.fill.is_none());
+
+        // From the lower left to the upper right, within the glyph.
+        assert!(delta.x > Abs::zero() && delta.y < Abs::zero());
+        assert!(pos.x > Abs::zero() && pos.x + delta.x < advance);
+        assert!(pos.y < Abs::pt(10.0) && pos.y + delta.y > Abs::zero());
+    }
+
+    #[test]
+    fn test_synthesized_dot_is_centered() {
+        let (frame, advance) = synthesized(ZeroForm::Dotted);
+        let items: Vec<_> = frame.items().collect();
+        assert_eq!(items.len(), 1);
+
+        let (pos, FrameItem::Shape(shape, _)) = items[0] else { panic!() };
+        let Geometry::Path(_) = shape.geometry else { panic!() };
+        assert!(shape.fill.is_some() && shape.stroke.is_none());
+
+        let diameter = shape.geometry.bbox_size().x;
+        let center = pos.x + diameter / 2.0;
+        assert!((center - advance / 2.0).abs() < Abs::pt(0.5));
+    }
+}
@@ -1,4 +1,5 @@
 use typst_library::introspection::SplitLocator;
+use typst_library::layout::Point;
 use typst_utils::Numeric;
 
 use super::*;
@@ -29,7 +30,20 @@ pub fn finalize(
     // Stack the lines into one frame per region.
     lines
         .iter()
-        .map(|line| commit(engine, p, line, width, region.y, locator, styles))
+        .enumerate()
+        .map(|(i, line)| {
+            let Some(&(left, right)) = p.insets.get(i) else {
+                return commit(engine, p, line, width, region.y, locator, styles);
+            };
+
+            // Build a shortened line and move it past the left inset.
+            let shortened = width - left - right;
+            let mut frame =
+                commit(engine, p, line, shortened, region.y, locator, styles)?;
+            frame.translate(Point::with_x(left));
+            frame.size_mut().x = width;
+            Ok(frame)
+        })
         .collect::<SourceResult<_>>()
         .map(Fragment::frames)
 }
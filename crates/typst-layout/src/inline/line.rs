@@ -92,7 +92,7 @@ impl Line<'_> {
     /// Whether the line has items with negative width.
     pub fn has_negative_width_items(&self) -> bool {
         self.items.iter().any(|item| match item {
-            Item::Absolute(amount, _) => *amount < Abs::zero(),
+            Item::Absolute(amount, _) | Item::Kern(amount) => *amount < Abs::zero(),
             Item::Frame(frame, _) => frame.width() < Abs::zero(),
             _ => false,
         })
@@ -502,7 +502,7 @@ pub fn commit(
         };
 
         match item {
-            Item::Absolute(v, _) => {
+            Item::Absolute(v, _) | Item::Kern(v) => {
                 offset += *v;
             }
             Item::Fractional(v, elem) => {
@@ -28,6 +28,8 @@ type Cost = f64;
 // so things work a bit differently.
 const DEFAULT_HYPH_COST: Cost = 135.0;
 const DEFAULT_RUNT_COST: Cost = 100.0;
+const DEFAULT_BALANCE_COST: Cost = 100.0;
+const OVERSTRETCH_COST: Cost = 10_000.0;
 
 // Other parameters.
 const MIN_RATIO: f64 = -1.0;
@@ -110,13 +112,19 @@ pub fn linebreak<'a>(
     p: &'a Preparation<'a>,
     width: Abs,
 ) -> Vec<Line<'a>> {
-    let linebreaks = p.linebreaks.unwrap_or_else(|| {
-        if p.justify {
-            Linebreaks::Optimized
-        } else {
-            Linebreaks::Simple
-        }
-    });
+    // The optimized algorithm assumes a uniform line width, so paragraphs
+    // with shortened lines are always broken in simple first-fit style.
+    let linebreaks = if !p.insets.is_empty() {
+        Linebreaks::Simple
+    } else {
+        p.linebreaks.unwrap_or_else(|| {
+            if p.justify {
+                Linebreaks::Optimized
+            } else {
+                Linebreaks::Simple
+            }
+        })
+    };
 
     match linebreaks {
         Linebreaks::Simple => linebreak_simple(engine, p, width),
@@ -137,29 +145,33 @@ fn linebreak_simple<'a>(
     let mut start = 0;
     let mut last = None;
 
-    breakpoints(p, |end, breakpoint| {
+    breakpoints(p, width, |end, breakpoint| {
         // Compute the line and its size.
         let mut attempt = line(engine, p, start..end, breakpoint, lines.last());
+        let mut available = p.line_width(width, lines.len());
 
         // If the line doesn't fit anymore, we push the last fitting attempt
         // into the stack and rebuild the line from the attempt's end. The
         // resulting line cannot be broken up further.
-        if !width.fits(attempt.width) {
+        if !available.fits(attempt.width) {
             if let Some((last_attempt, last_end)) = last.take() {
                 lines.push(last_attempt);
                 start = last_end;
                 attempt = line(engine, p, start..end, breakpoint, lines.last());
+                available = p.line_width(width, lines.len());
             }
         }
 
         // Finish the current line if there is a mandatory line break (i.e. due
         // to "\n") or if the line doesn't fit horizontally already since then
         // no shorter line will be possible.
-        if breakpoint == Breakpoint::Mandatory || !width.fits(attempt.width) {
+        if breakpoint == Breakpoint::Mandatory || !available.fits(attempt.width) {
             lines.push(attempt);
             start = end;
             last = None;
-        } else {
+        } else if fills_for_hyphen(p, available, breakpoint, attempt.width) {
+            // Lines that are too empty to end with a hyphen don't become
+            // candidates, so the last fitting attempt is kept instead.
             last = Some((attempt, end));
         }
     });
@@ -229,7 +241,7 @@ fn linebreak_optimized_bounded<'a>(
     let mut active = 0;
     let mut prev_end = 0;
 
-    breakpoints(p, |end, breakpoint| {
+    breakpoints(p, width, |end, breakpoint| {
         // Find the optimal predecessor.
         let mut best: Option<Entry> = None;
 
@@ -258,6 +270,7 @@ fn linebreak_optimized_bounded<'a>(
                 width,
                 &pred.line,
                 &attempt,
+                end,
                 breakpoint,
                 unbreakable,
             );
@@ -292,9 +305,12 @@ fn linebreak_optimized_bounded<'a>(
                 line_lower_bound = Some(line_cost);
             }
 
-            // If the cost already exceeds the upper bound, we don't need to
-            // integrate this result into the table.
-            if total > upper_bound + BOUND_EPS {
+            // If the cost already exceeds the upper bound or the line is too
+            // empty to end with a hyphen, we don't need to integrate this
+            // result into the table.
+            if total > upper_bound + BOUND_EPS
+                || !fills_for_hyphen(p, width, breakpoint, attempt.width)
+            {
                 continue;
             }
 
@@ -376,7 +392,7 @@ fn linebreak_optimized_approximate(
     let mut active = 0;
     let mut prev_end = 0;
 
-    breakpoints(p, |end, breakpoint| {
+    breakpoints(p, width, |end, breakpoint| {
         // Find the optimal predecessor.
         let mut best: Option<Entry> = None;
         for (pred_index, pred) in table.iter().enumerate().skip(active) {
@@ -397,15 +413,16 @@ fn linebreak_optimized_approximate(
             // account trailing spaces. This is, again, only an approximation of
             // the real behaviour of `line`.
             let trimmed_end = start + p.text[start..end].trim_end().len();
+            let line_width = estimates.widths.estimate(start..trimmed_end)
+                + if breakpoint.is_hyphen() {
+                    metrics.approx_hyphen_width
+                } else {
+                    Abs::zero()
+                };
             let line_ratio = raw_ratio(
                 p,
                 width,
-                estimates.widths.estimate(start..trimmed_end)
-                    + if breakpoint.is_hyphen() {
-                        metrics.approx_hyphen_width
-                    } else {
-                        Abs::zero()
-                    },
+                line_width,
                 estimates.stretchability.estimate(start..trimmed_end),
                 estimates.shrinkability.estimate(start..trimmed_end),
                 estimates.justifiables.estimate(start..trimmed_end),
@@ -419,6 +436,7 @@ fn linebreak_optimized_approximate(
                 justify,
                 unbreakable,
                 consecutive_dash,
+                0.0,
                 true,
             );
 
@@ -428,6 +446,11 @@ fn linebreak_optimized_approximate(
                 active += 1;
             }
 
+            // Lines that are too empty to end with a hyphen are out.
+            if !fills_for_hyphen(p, width, breakpoint, line_width) {
+                continue;
+            }
+
             // The total cost of this line and its chain of predecessors.
             let total = pred.total + line_cost;
 
@@ -473,8 +496,16 @@ fn linebreak_optimized_approximate(
         let Entry { end, breakpoint, unbreakable, .. } = table[idx];
 
         let attempt = line(engine, p, start..end, breakpoint, Some(&pred));
-        let (ratio, line_cost) =
-            ratio_and_cost(p, metrics, width, &pred, &attempt, breakpoint, unbreakable);
+        let (ratio, line_cost) = ratio_and_cost(
+            p,
+            metrics,
+            width,
+            &pred,
+            &attempt,
+            end,
+            breakpoint,
+            unbreakable,
+        );
 
         // If approximation produces a valid layout without too much shrinking,
         // exact layout is guaranteed to find the same layout. If, however, the
@@ -482,7 +513,11 @@ fn linebreak_optimized_approximate(
         // becomes useless and actively harmful (it could be lower than what
         // optimal layout produces). Thus, we immediately bail with an infinite
         // bound in this case.
-        if ratio < metrics.min_ratio {
+        // The same holds for lines that the estimate deemed full enough to
+        // end with a hyphen, but that actually aren't.
+        if ratio < metrics.min_ratio
+            || !fills_for_hyphen(p, width, breakpoint, attempt.width)
+        {
             return Cost::INFINITY;
         }
 
@@ -502,6 +537,7 @@ fn ratio_and_cost(
     available_width: Abs,
     pred: &Line,
     attempt: &Line,
+    end: usize,
     breakpoint: Breakpoint,
     unbreakable: bool,
 ) -> (f64, Cost) {
@@ -514,6 +550,18 @@ fn ratio_and_cost(
         attempt.justifiables(),
     );
 
+    // How much shorter the final line of the paragraph is than its
+    // predecessor, relative to the available width. Lines ending in a forced
+    // linebreak within the paragraph are not balanced.
+    let imbalance = if breakpoint == Breakpoint::Mandatory
+        && end == p.text.len()
+        && !pred.items.is_empty()
+    {
+        ((pred.width - attempt.width) / available_width).max(0.0)
+    } else {
+        0.0
+    };
+
     let cost = raw_cost(
         metrics,
         breakpoint,
@@ -521,12 +569,35 @@ fn ratio_and_cost(
         attempt.justify,
         unbreakable,
         pred.dash.is_some() && attempt.dash.is_some(),
+        imbalance,
         false,
     );
 
     (ratio, cost)
 }
 
+/// Whether more than half of the justified lines would need to be stretched
+/// beyond the given ratio.
+pub fn overstretched(p: &Preparation, lines: &[Line], width: Abs, limit: f64) -> bool {
+    let mut justified = 0;
+    let mut exceeding = 0;
+    for line in lines.iter().filter(|line| line.justify) {
+        let ratio = raw_ratio(
+            p,
+            width,
+            line.width,
+            line.stretchability(),
+            line.shrinkability(),
+            line.justifiables(),
+        );
+        justified += 1;
+        if ratio > limit {
+            exceeding += 1;
+        }
+    }
+    2 * exceeding > justified
+}
+
 /// Determine the stretch ratio for a line given raw metrics.
 ///
 /// - A ratio < min_ratio indicates an overfull line.
@@ -589,6 +660,7 @@ fn raw_ratio(
 ///
 /// This mostly follows the formula in the Knuth-Plass paper, but there are some
 /// adjustments.
+#[allow(clippy::too_many_arguments)]
 fn raw_cost(
     metrics: &CostMetrics,
     breakpoint: Breakpoint,
@@ -596,6 +668,7 @@ fn raw_cost(
     justify: bool,
     unbreakable: bool,
     consecutive_dash: bool,
+    imbalance: f64,
     approx: bool,
 ) -> Cost {
     // Determine the stretch/shrink cost of the line.
@@ -613,6 +686,14 @@ fn raw_cost(
         0.0
     };
 
+    // Justified lines that are stretched beyond the configured limit are
+    // avoided unless there is no other way.
+    let badness = if justify && ratio > metrics.max_ratio {
+        badness + OVERSTRETCH_COST
+    } else {
+        badness
+    };
+
     // Compute penalties.
     let mut penalty = 0.0;
 
@@ -621,6 +702,9 @@ fn raw_cost(
         penalty += metrics.runt_cost;
     }
 
+    // Penalize final lines that are much shorter than the line before them.
+    penalty += metrics.balance_cost * imbalance;
+
     // Penalize hyphenation.
     if let Breakpoint::Hyphen(l, r) = breakpoint {
         // We penalize hyphenations close to the edges of the word (< LIMIT
@@ -655,7 +739,7 @@ fn raw_cost(
 /// This is an internal instead of an external iterator because it makes the
 /// code much simpler and the consumers of this function don't need the
 /// composability and flexibility of external iteration anyway.
-fn breakpoints(p: &Preparation, mut f: impl FnMut(usize, Breakpoint)) {
+fn breakpoints(p: &Preparation, width: Abs, mut f: impl FnMut(usize, Breakpoint)) {
     let text = p.text;
 
     // Single breakpoint at the end for empty text.
@@ -664,6 +748,35 @@ fn breakpoints(p: &Preparation, mut f: impl FnMut(usize, Breakpoint)) {
         return;
     }
 
+    // Discard optional breakpoints within matches of the paragraph's
+    // unbreakable patterns.
+    let guarded: Vec<Range> = p
+        .unbreakable
+        .iter()
+        .flat_map(|regex| regex.find_iter(text).map(|m| m.range()))
+        .collect();
+    let mut f = |offset: usize, breakpoint: Breakpoint| {
+        if breakpoint == Breakpoint::Mandatory
+            || !guarded.iter().any(|range| range.start < offset && offset < range.end)
+        {
+            f(offset, breakpoint);
+        }
+    };
+
+    // As a last resort, break words that don't fit into a line on their own
+    // between any two graphemes.
+    let emergency = p.emergency_breaks && width.is_finite();
+    let mut prev = 0;
+    let mut f = |offset: usize, breakpoint: Breakpoint| {
+        if emergency && natural_width(p, prev..offset) > width {
+            for (i, _) in text[prev..offset].grapheme_indices(true).skip(1) {
+                f(prev + i, Breakpoint::Normal);
+            }
+        }
+        prev = offset;
+        f(offset, breakpoint);
+    };
+
     let hyphenate = p.hyphenate != Some(false);
     let lb = LINEBREAK_DATA.as_borrowed();
     let segmenter = match p.lang {
@@ -726,6 +839,35 @@ fn breakpoints(p: &Preparation, mut f: impl FnMut(usize, Breakpoint)) {
     }
 }
 
+/// Whether a line with the given width is full enough to end at the given
+/// breakpoint, given the paragraph's minimum fill for hyphenated lines.
+fn fills_for_hyphen(
+    p: &Preparation,
+    width: Abs,
+    breakpoint: Breakpoint,
+    line_width: Abs,
+) -> bool {
+    !breakpoint.is_hyphen()
+        || !width.is_finite()
+        || line_width >= p.hyphenation_fill.of(width)
+}
+
+/// The natural width of a range of the paragraph's text.
+fn natural_width(p: &Preparation, range: Range) -> Abs {
+    p.slice(range.clone())
+        .map(|(item_range, item)| match item {
+            Item::Text(shaped) => shaped
+                .glyphs
+                .iter()
+                .filter(|glyph| range.contains(&glyph.range.start))
+                .map(|glyph| glyph.x_advance.at(shaped.size))
+                .sum(),
+            _ if range.contains(&item_range.start) => item.natural_width(),
+            _ => Abs::zero(),
+        })
+        .sum()
+}
+
 /// Generate breakpoints for hyphenations within a word.
 fn hyphenations(
     p: &Preparation,
@@ -835,7 +977,11 @@ fn hyphenate_at(p: &Preparation, offset: usize) -> bool {
         .or_else(|| {
             let (_, item) = p.get(offset);
             let styles = item.text()?.styles;
-            Some(TextElem::hyphenate_in(styles))
+            Some(if p.justify {
+                TextElem::hyphenate_in(styles)
+            } else {
+                ragged_hyphenate_in(styles)
+            })
         })
         .unwrap_or(false)
 }
@@ -856,23 +1002,29 @@ fn lang_at(p: &Preparation, offset: usize) -> Option<hypher::Lang> {
 struct CostMetrics {
     min_ratio: f64,
     min_approx_ratio: f64,
+    max_ratio: f64,
     approx_hyphen_width: Abs,
     hyph_cost: Cost,
     runt_cost: Cost,
+    balance_cost: Cost,
 }
 
 impl CostMetrics {
     /// Compute shared metrics for paragraph optimization.
     fn compute(p: &Preparation) -> Self {
+        let shrink = p.justify_shrink.get().clamp(0.0, 1.0);
         Self {
-            // When justifying, we may stretch spaces below their natural width.
-            min_ratio: if p.justify { MIN_RATIO } else { 0.0 },
-            min_approx_ratio: if p.justify { MIN_APPROX_RATIO } else { 0.0 },
+            // When justifying, we may shrink spaces below their natural width,
+            // but not further than configured.
+            min_ratio: if p.justify { MIN_RATIO * shrink } else { 0.0 },
+            min_approx_ratio: if p.justify { MIN_APPROX_RATIO * shrink } else { 0.0 },
+            max_ratio: p.justify_stretch.map_or(f64::INFINITY, |ratio| ratio.get()),
             // Approximate hyphen width for estimates.
             approx_hyphen_width: Em::new(0.33).at(p.size),
             // Costs.
             hyph_cost: DEFAULT_HYPH_COST * p.costs.hyphenation().get(),
             runt_cost: DEFAULT_RUNT_COST * p.costs.runt().get(),
+            balance_cost: DEFAULT_BALANCE_COST * p.costs.balance().get(),
         }
     }
 
@@ -20,12 +20,12 @@ use typst_library::model::ParElem;
 use typst_library::routines::Routines;
 use typst_library::World;
 
-use self::collect::{collect, Item, Segment, SpanMapper};
-use self::deco::decorate;
+use self::collect::{base_dir, collect, Item, Segment, SpanMapper};
+use self::deco::{decorate, synthesize_zero};
 use self::finalize::finalize;
 use self::line::{commit, line, Line};
-use self::linebreak::{linebreak, Breakpoint};
-use self::prepare::{prepare, Preparation};
+use self::linebreak::{linebreak, overstretched, Breakpoint};
+use self::prepare::{prepare, ragged_hyphenate_in, Preparation};
 use self::shaping::{
     cjk_punct_style, is_of_cj_script, shape_range, ShapedGlyph, ShapedText,
     BEGIN_PUNCT_PAT, END_PUNCT_PAT,
@@ -35,6 +35,7 @@ use self::shaping::{
 type Range = std::ops::Range<usize>;
 
 /// Layouts content inline.
+#[typst_macros::time]
 pub fn layout_inline(
     engine: &mut Engine,
     children: &StyleVec,
@@ -91,14 +92,34 @@ fn layout_inline_impl(
     let mut locator = locator.split();
 
     // Collect all text into one string for BiDi analysis.
+    let dir = base_dir(children, styles);
     let (text, segments, spans) =
-        collect(children, &mut engine, &mut locator, &styles, region, consecutive)?;
+        collect(children, &mut engine, &mut locator, &styles, dir, region, consecutive)?;
 
     // Perform BiDi analysis and then prepares paragraph layout.
-    let p = prepare(&mut engine, children, &text, segments, spans, styles)?;
+    let mut p = prepare(&mut engine, children, &text, segments, spans, styles, dir)?;
+    let width = region.x - p.hang;
+
+    // Don't justify in measures that are too narrow for it.
+    if p.justify && width < ParElem::justify_min_width_in(styles) {
+        p.set_ragged(children, styles);
+    }
 
     // Break the paragraph into lines.
-    let lines = linebreak(&engine, &p, region.x - p.hang);
+    let lines = linebreak(&engine, &p, width);
+
+    // If justification would stretch most lines beyond the limit, set the
+    // paragraph ragged instead.
+    let ragged = p.justify
+        && ParElem::justify_limit_in(styles)
+            .is_some_and(|limit| overstretched(&p, &lines, width, limit.get()));
+    if !ragged {
+        return finalize(&mut engine, &p, &lines, styles, region, expand, &mut locator);
+    }
+
+    drop(lines);
+    p.set_ragged(children, styles);
+    let lines = linebreak(&engine, &p, width);
 
     // Turn the selected lines into frames.
     finalize(&mut engine, &p, &lines, styles, region, expand, &mut locator)
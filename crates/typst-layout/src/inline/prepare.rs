@@ -1,5 +1,6 @@
-use typst_library::foundations::{Resolve, Smart};
-use typst_library::layout::{Abs, AlignElem, Dir, Em, FixedAlignment};
+use typst_library::diag::warning;
+use typst_library::foundations::{Regex, Resolve, Smart};
+use typst_library::layout::{Abs, AlignElem, Dir, Em, FixedAlignment, Ratio};
 use typst_library::model::Linebreaks;
 use typst_library::text::{Costs, Lang, TextElem};
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
@@ -37,6 +38,10 @@ pub struct Preparation<'a> {
     pub align: FixedAlignment,
     /// Whether to justify the paragraph.
     pub justify: bool,
+    /// How much the spaces of justified lines may be stretched.
+    pub justify_stretch: Option<Ratio>,
+    /// How much the spaces of justified lines may be shrunk.
+    pub justify_shrink: Ratio,
     /// The paragraph's hanging indent.
     pub hang: Abs,
     /// Whether to add spacing between CJK and Latin characters.
@@ -45,8 +50,16 @@ pub struct Preparation<'a> {
     pub fallback: bool,
     /// How to determine line breaks.
     pub linebreaks: Smart<Linebreaks>,
+    /// Patterns within which lines must not be broken.
+    pub unbreakable: Vec<Regex>,
+    /// Whether to break words that are too wide for a line on their own.
+    pub emergency_breaks: bool,
+    /// How full a line must be before it may end with a hyphenated word.
+    pub hyphenation_fill: Ratio,
     /// The text size.
     pub size: Abs,
+    /// How much the first lines are shortened from the left and the right.
+    pub insets: Vec<(Abs, Abs)>,
 }
 
 impl<'a> Preparation<'a> {
@@ -56,6 +69,13 @@ impl<'a> Preparation<'a> {
         &self.items[idx]
     }
 
+    /// The width available to the line with the given index.
+    pub fn line_width(&self, width: Abs, index: usize) -> Abs {
+        self.insets
+            .get(index)
+            .map_or(width, |&(left, right)| width - left - right)
+    }
+
     /// Iterate over the items that intersect the given `sliced` range.
     pub fn slice(&self, sliced: Range) -> impl Iterator<Item = &(Range, Item<'a>)> {
         // Usually, we don't want empty-range items at the start of the line
@@ -69,6 +89,20 @@ impl<'a> Preparation<'a> {
             range.start < sliced.end || range.end <= sliced.end
         })
     }
+
+    /// Sets the paragraph ragged although it asked for justification.
+    pub fn set_ragged(&mut self, children: &StyleVec, styles: StyleChain) {
+        self.justify = false;
+        self.hyphenate = children.shared_get(styles, ragged_hyphenate_in);
+    }
+}
+
+/// Whether to hyphenate text in a paragraph that is set ragged. Automatic
+/// hyphenation follows justification, so it is resolved as if justification
+/// were disabled.
+pub fn ragged_hyphenate_in(styles: StyleChain) -> bool {
+    let local = ParElem::set_justify(false).wrap();
+    TextElem::hyphenate_in(styles.chain(&local))
 }
 
 /// Performs BiDi analysis and then prepares paragraph layout by building a
@@ -82,8 +116,8 @@ pub fn prepare<'a>(
     segments: Vec<Segment<'a>>,
     spans: SpanMapper,
     styles: StyleChain<'a>,
+    dir: Dir,
 ) -> SourceResult<Preparation<'a>> {
-    let dir = TextElem::dir_in(styles);
     let default_level = match dir {
         Dir::RTL => BidiLevel::rtl(),
         _ => BidiLevel::ltr(),
@@ -114,6 +148,9 @@ pub fn prepare<'a>(
         cursor = end;
     }
 
+    check_variations(engine, &items, &spans);
+    check_substitutions(engine, &items, &spans);
+
     // Build the mapping from byte to item indices.
     let mut indices = Vec::with_capacity(text.len());
     for (i, (range, _)) in items.iter().enumerate() {
@@ -135,16 +172,94 @@ pub fn prepare<'a>(
         costs: TextElem::costs_in(styles),
         dir,
         lang: children.shared_get(styles, TextElem::lang_in),
-        align: AlignElem::alignment_in(styles).resolve(styles).x,
+        align: AlignElem::alignment_in(styles).fix(dir).x,
         justify: ParElem::justify_in(styles),
+        justify_stretch: ParElem::justify_stretch_in(styles),
+        justify_shrink: ParElem::justify_shrink_in(styles),
         hang: ParElem::hanging_indent_in(styles),
         cjk_latin_spacing,
         fallback: TextElem::fallback_in(styles),
         linebreaks: ParElem::linebreaks_in(styles),
+        unbreakable: ParElem::unbreakable_in(styles),
+        emergency_breaks: ParElem::emergency_breaks_in(styles),
+        hyphenation_fill: ParElem::hyphenation_fill_in(styles),
         size: TextElem::size_in(styles),
+        insets: ParElem::insets_in(styles),
     })
 }
 
+/// Warn about explicit variation coordinates that are out of range for the
+/// fonts they are applied to. Shaping clamps them into range.
+fn check_variations(engine: &mut Engine, items: &[(Range, Item)], spans: &SpanMapper) {
+    let mut reported = vec![];
+    for shaped in items.iter().filter_map(|(_, item)| item.text()) {
+        let coords = TextElem::variations_in(shaped.styles).0;
+        if coords.is_empty() {
+            continue;
+        }
+
+        for glyph in shaped.glyphs.iter() {
+            let font = &glyph.font;
+            for &(tag, value) in &coords {
+                let value = value.get() as f32;
+                let Some(axis) =
+                    font.ttf().variation_axes().into_iter().find(|axis| axis.tag == tag)
+                else {
+                    continue;
+                };
+
+                let key = (font.info().family.clone(), tag);
+                if (axis.min_value..=axis.max_value).contains(&value)
+                    || reported.contains(&key)
+                {
+                    continue;
+                }
+
+                let bytes = tag.to_bytes();
+                let name = std::str::from_utf8(&bytes).unwrap_or_default();
+                engine.sink.warn(warning!(
+                    spans.span_at(glyph.range.start).0,
+                    "coordinate {value} is out of range for the `{name}` axis of {}",
+                    font.info().family;
+                    hint: "it was clamped to the range from {} to {}",
+                    axis.min_value,
+                    axis.max_value,
+                ));
+                reported.push(key);
+            }
+        }
+    }
+}
+
+/// Warn about glyph substitutions referring to glyphs that don't exist in the
+/// fonts they are applied to. Shaping keeps the original glyphs then.
+fn check_substitutions(engine: &mut Engine, items: &[(Range, Item)], spans: &SpanMapper) {
+    let mut reported = vec![];
+    for shaped in items.iter().filter_map(|(_, item)| item.text()) {
+        let substitutions = TextElem::substitutions_in(shaped.styles);
+        if substitutions.0.is_empty() {
+            continue;
+        }
+
+        for glyph in shaped.glyphs.iter() {
+            let Some(target) = substitutions.get(glyph.c) else { continue };
+            let key = (glyph.font.clone(), glyph.c);
+            if target.resolve(&glyph.font).is_some() || reported.contains(&key) {
+                continue;
+            }
+
+            engine.sink.warn(warning!(
+                spans.span_at(glyph.range.start).0,
+                "font {} does not contain the glyph {target}",
+                glyph.font.info().family;
+                hint: "the character {:?} is shaped as usual",
+                glyph.c,
+            ));
+            reported.push(key);
+        }
+    }
+}
+
 /// Add some spacing between Han characters and western characters. See
 /// Requirements for Chinese Text Layout, Section 3.2.2 Mixed Text Composition
 /// in Horizontal Written Mode
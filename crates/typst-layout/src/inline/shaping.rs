@@ -6,20 +6,22 @@ use std::sync::Arc;
 use az::SaturatingAs;
 use ecow::EcoString;
 use rustybuzz::{BufferFlags, ShapePlan, UnicodeBuffer};
-use ttf_parser::Tag;
+use ttf_parser::gsub::{SingleSubstitution, SubstitutionSubtable};
+use ttf_parser::{GlyphId, Tag};
 use typst_library::engine::Engine;
 use typst_library::foundations::{Smart, StyleChain};
 use typst_library::layout::{Abs, Dir, Em, Frame, FrameItem, Point, Size};
 use typst_library::text::{
-    families, features, is_default_ignorable, variant, Font, FontVariant, Glyph, Lang,
-    Region, TextEdgeBounds, TextElem, TextItem,
+    families, features, is_default_ignorable, optical_size, variant, variations, Font,
+    FontVariant, Glyph, GlyphSubstitutions, Lang, Region, TextEdgeBounds, TextElem,
+    TextItem, ZeroForm,
 };
 use typst_library::World;
 use typst_utils::SliceExt;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 use unicode_script::{Script, UnicodeScript};
 
-use super::{decorate, Item, Range, SpanMapper};
+use super::{decorate, synthesize_zero, Item, Range, SpanMapper};
 
 /// The result of shaping text.
 ///
@@ -228,6 +230,7 @@ impl<'a> ShapedText<'a> {
         let fill = TextElem::fill_in(self.styles);
         let stroke = TextElem::stroke_in(self.styles);
         let span_offset = TextElem::span_offset_in(self.styles);
+        let zero = TextElem::zero_in(self.styles).custom();
 
         for ((font, y_offset), group) in
             self.glyphs.as_ref().group_by_key(|g| (g.font.clone(), g.y_offset))
@@ -312,6 +315,21 @@ impl<'a> ShapedText<'a> {
                 glyphs,
             };
 
+            // Fonts that can't provide the requested zero form themselves get
+            // the zero's mark drawn over the glyph.
+            let synthesized =
+                zero.filter(|&form| zero_feature(&item.font, form).is_none());
+            if let Some(form) = synthesized {
+                let mut x = pos.x;
+                for (glyph, shaped) in item.glyphs.iter().zip(group) {
+                    if shaped.c == '0' {
+                        let origin = Point::new(x + glyph.x_offset.at(self.size), pos.y);
+                        synthesize_zero(&mut frame, form, &item, glyph, origin);
+                    }
+                    x += glyph.x_advance.at(self.size);
+                }
+            }
+
             let width = item.width();
             if decos.is_empty() {
                 frame.push(pos, FrameItem::Text(item));
@@ -671,6 +689,10 @@ fn shape<'a>(
         styles,
         variant: variant(styles),
         features: features(styles),
+        variations: variations(styles),
+        optical_size: optical_size(styles),
+        substitutions: TextElem::substitutions_in(styles),
+        zero: TextElem::zero_in(styles).custom(),
         fallback: TextElem::fallback_in(styles),
         dir,
     };
@@ -710,6 +732,10 @@ struct ShapingContext<'a, 'v> {
     size: Abs,
     variant: FontVariant,
     features: Vec<rustybuzz::Feature>,
+    variations: Vec<(Tag, f32)>,
+    optical_size: Abs,
+    substitutions: GlyphSubstitutions,
+    zero: Option<ZeroForm>,
     fallback: bool,
     dir: Dir,
 }
@@ -757,6 +783,20 @@ fn shape_segment<'a>(
 
     ctx.used.push(font.clone());
 
+    // Instantiate variable fonts at the requested axis coordinates. The
+    // optical size follows the text size unless set explicitly, but only for
+    // fonts that actually have that axis.
+    let opsz = Tag::from_bytes(b"opsz");
+    let font = if font.has_axis(opsz) && ctx.variations.iter().all(|&(t, _)| t != opsz) {
+        let mut coords = ctx.variations.clone();
+        coords.push((opsz, ctx.optical_size.to_pt() as f32));
+        font.with_variations(&coords)
+    } else if ctx.variations.is_empty() {
+        font
+    } else {
+        font.with_variations(&ctx.variations)
+    };
+
     // Fill the buffer with our text.
     let mut buffer = UnicodeBuffer::new();
     buffer.push_str(text);
@@ -779,6 +819,19 @@ fn shape_segment<'a>(
     // text extraction.
     buffer.set_flags(BufferFlags::REMOVE_DEFAULT_IGNORABLES);
 
+    // Enable the font's feature for the requested zero form. If that's a
+    // stylistic set, it is restricted to the zeros since it may change other
+    // glyphs, too.
+    let mut features = Cow::Borrowed(ctx.features.as_slice());
+    if let Some(tag) = ctx.zero.and_then(|form| zero_feature(&font, form)) {
+        if features.iter().all(|feature| feature.tag != tag) {
+            features.to_mut().extend(
+                text.match_indices('0')
+                    .map(|(i, _)| rustybuzz::Feature::new(tag, 1, i..i + 1)),
+            );
+        }
+    }
+
     // Prepare the shape plan. This plan depends on direction, script, language,
     // and features, but is independent from the text and can thus be memoized.
     let plan = create_shape_plan(
@@ -786,7 +839,7 @@ fn shape_segment<'a>(
         buffer.direction(),
         buffer.script(),
         buffer.language().as_ref(),
-        &ctx.features,
+        &features,
     );
 
     // Shape!
@@ -813,10 +866,23 @@ fn shape_segment<'a>(
 
             let c = text[cluster..].chars().next().unwrap();
             let script = c.script();
-            let x_advance = font.to_em(pos[i].x_advance);
+            let mut glyph_id = info.glyph_id as u16;
+            let mut x_advance = font.to_em(pos[i].x_advance);
+
+            // Apply explicit glyph substitutions, but don't break up clusters
+            // spanning multiple characters.
+            if end - start == c.len_utf8() {
+                if let Some(id) =
+                    ctx.substitutions.get(c).and_then(|glyph| glyph.resolve(&font))
+                {
+                    glyph_id = id;
+                    x_advance = font.advance(id).unwrap_or(x_advance);
+                }
+            }
+
             ctx.glyphs.push(ShapedGlyph {
                 font: font.clone(),
-                glyph_id: info.glyph_id as u16,
+                glyph_id,
                 // TODO: Don't ignore y_advance.
                 x_advance,
                 x_offset: font.to_em(pos[i].x_offset),
@@ -1173,3 +1239,55 @@ fn is_justifiable(
         || is_cjk_right_aligned_punctuation(c, x_advance, stretchability)
         || is_cjk_center_aligned_punctuation(c, style)
 }
+
+/// The OpenType feature with which the font gives its zero the requested form,
+/// if any.
+///
+/// For a slashed zero, this is the `zero` feature. Otherwise, a stylistic set
+/// qualifies if it replaces the zero with a glyph whose name hints at the form,
+/// like `zero.slash` or `zero.dotted`.
+#[comemo::memoize]
+fn zero_feature(font: &Font, form: ZeroForm) -> Option<Tag> {
+    let ttf = font.ttf();
+    let gsub = ttf.tables().gsub?;
+    let slashed = Tag::from_bytes(b"zero");
+    if form == ZeroForm::Slashed && gsub.features.find(slashed).is_some() {
+        return Some(slashed);
+    }
+
+    let zero = ttf.glyph_index('0')?;
+    let hint = match form {
+        ZeroForm::Slashed => "slash",
+        ZeroForm::Dotted => "dot",
+    };
+
+    (1..=20)
+        .map(|set| Tag::from_bytes(&[b's', b's', b'0' + set / 10, b'0' + set % 10]))
+        .find(|&tag| {
+            let Some(feature) = gsub.features.find(tag) else { return false };
+            feature
+                .lookup_indices
+                .into_iter()
+                .filter_map(|index| gsub.lookups.get(index))
+                .flat_map(|lookup| lookup.subtables.into_iter::<SubstitutionSubtable>())
+                .filter_map(|subtable| match subtable {
+                    SubstitutionSubtable::Single(single) => substitute(single, zero),
+                    _ => None,
+                })
+                .filter_map(|id| ttf.glyph_name(id))
+                .any(|name| name.to_ascii_lowercase().contains(hint))
+        })
+}
+
+/// The glyph a single substitution replaces the given one with.
+fn substitute(single: SingleSubstitution, glyph: GlyphId) -> Option<GlyphId> {
+    match single {
+        SingleSubstitution::Format1 { coverage, delta } => {
+            coverage.get(glyph)?;
+            Some(GlyphId(glyph.0.wrapping_add(delta as u16)))
+        }
+        SingleSubstitution::Format2 { coverage, substitutes } => {
+            substitutes.get(coverage.get(glyph)?)
+        }
+    }
+}
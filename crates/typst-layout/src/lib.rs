@@ -21,10 +21,10 @@ pub use self::lists::{layout_enum, layout_list};
 pub use self::math::{layout_equation_block, layout_equation_inline};
 pub use self::pad::layout_pad;
 pub use self::pages::layout_document;
-pub use self::repeat::layout_repeat;
+pub use self::repeat::{layout_repeat, layout_watermark};
 pub use self::shapes::{
     layout_circle, layout_ellipse, layout_line, layout_path, layout_polygon, layout_rect,
-    layout_square,
+    layout_square, layout_text_on_path,
 };
 pub use self::stack::layout_stack;
 pub use self::transforms::{layout_move, layout_rotate, layout_scale, layout_skew};
@@ -3,10 +3,15 @@ use smallvec::smallvec;
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
 use typst_library::foundations::{Content, Context, Depth, Packed, StyleChain};
-use typst_library::introspection::Locator;
-use typst_library::layout::{Axes, Fragment, HAlignment, Regions, Sizing, VAlignment};
+use typst_library::introspection::{Locator, SplitLocator, Tag, TagElem};
+use typst_library::layout::{
+    Abs, Axes, Dir, Fragment, HAlignment, Point, Region, Regions, Size, Sizing,
+    VAlignment,
+};
 use typst_library::model::{EnumElem, ListElem, Numbering, ParElem};
 use typst_library::text::TextElem;
+use typst_syntax::Span;
+use typst_utils::Numeric;
 
 use crate::grid::{Cell, CellGrid, GridLayouter};
 
@@ -39,12 +44,21 @@ pub fn layout_list(
     let mut cells = vec![];
     let mut locator = locator.split();
 
+    // Hanging markers need the width of the marker column.
+    let mut hang = Abs::zero();
+    if elem.hanging(styles) {
+        let pod = Region::new(regions.base(), Axes::splat(false));
+        let frame = crate::layout_frame(engine, &marker, locator.next(&()), styles, pod)?;
+        hang = frame.width() + body_indent;
+    }
+
     for item in elem.children() {
         cells.push(Cell::new(Content::empty(), locator.next(&())));
         cells.push(Cell::new(marker.clone(), locator.next(&marker.span())));
         cells.push(Cell::new(Content::empty(), locator.next(&())));
+        let body = tag_item(engine, &mut locator, item.clone().pack(), &item.body);
         cells.push(Cell::new(
-            item.body.clone().styled(ListElem::set_depth(Depth(1))),
+            body.styled(ListElem::set_depth(Depth(1))),
             locator.next(&item.body.span()),
         ));
     }
@@ -59,9 +73,7 @@ pub fn layout_list(
         Axes::with_y(&[gutter.into()]),
         cells,
     );
-    let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
-
-    layouter.layout(engine)
+    layout_items(engine, &grid, regions, styles, elem.span(), hang)
 }
 
 /// Layout the enumeration.
@@ -90,6 +102,8 @@ pub fn layout_enum(
     let mut parents = EnumElem::parents_in(styles);
 
     let full = elem.full(styles);
+    let hanging = elem.hanging(styles);
+    let mut widest = Abs::zero();
 
     // Horizontally align based on the given respective parameter.
     // Vertically align to the top to avoid inheriting `horizon` or `bottom`
@@ -120,11 +134,20 @@ pub fn layout_enum(
         let resolved =
             resolved.aligned(number_align).styled(TextElem::set_overhang(false));
 
+        // Hanging numbers need the width of the number column.
+        if hanging {
+            let pod = Region::new(regions.base(), Axes::splat(false));
+            let frame =
+                crate::layout_frame(engine, &resolved, locator.next(&()), styles, pod)?;
+            widest.set_max(frame.width());
+        }
+
         cells.push(Cell::new(Content::empty(), locator.next(&())));
         cells.push(Cell::new(resolved, locator.next(&())));
         cells.push(Cell::new(Content::empty(), locator.next(&())));
+        let body = tag_item(engine, &mut locator, item.clone().pack(), &item.body);
         cells.push(Cell::new(
-            item.body.clone().styled(EnumElem::set_parents(smallvec![number])),
+            body.styled(EnumElem::set_parents(smallvec![number])),
             locator.next(&item.body.span()),
         ));
         number = number.saturating_add(1);
@@ -140,7 +163,60 @@ pub fn layout_enum(
         Axes::with_y(&[gutter.into()]),
         cells,
     );
-    let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
+    let hang = if hanging { widest + body_indent } else { Abs::zero() };
+    layout_items(engine, &grid, regions, styles, elem.span(), hang)
+}
+
+/// Surround the body of a list item with tags for the item.
+///
+/// Items are not realized on their own since they would be grouped into a new
+/// list, so we create the tags here. This makes items part of the document's
+/// structure, e.g. for tagged PDF export.
+fn tag_item(
+    engine: &Engine,
+    locator: &mut SplitLocator,
+    mut item: Content,
+    body: &Content,
+) -> Content {
+    let key = typst_utils::hash128(&item);
+    let loc = locator.next_location(engine.introspector, key);
+    item.set_location(loc);
+    Content::sequence([
+        TagElem::packed(Tag::Start(item)),
+        body.clone(),
+        TagElem::packed(Tag::End(loc, key)),
+    ])
+}
+
+/// Lay out the grid of a list or an enumeration.
+///
+/// With a non-zero `hang`, the grid is widened by that amount and moved
+/// towards the start, so that the markers hang into the margin while the
+/// bodies are flush with the surrounding text.
+fn layout_items(
+    engine: &mut Engine,
+    grid: &CellGrid,
+    regions: Regions,
+    styles: StyleChain,
+    span: Span,
+    hang: Abs,
+) -> SourceResult<Fragment> {
+    if hang.is_zero() {
+        return GridLayouter::new(grid, regions, styles, span).layout(engine);
+    }
+
+    let mut pod = regions;
+    pod.size.x += hang;
+    let mut fragment = GridLayouter::new(grid, pod, styles, span).layout(engine)?;
+
+    // In right-to-left text, the grid is mirrored and the markers are already
+    // at the end of the widened region.
+    let shift = if TextElem::dir_in(styles) == Dir::RTL { Abs::zero() } else { -hang };
+    for frame in &mut fragment {
+        frame.translate(Point::with_x(shift));
+        let width = (frame.width() - hang).max(Abs::zero());
+        frame.set_size(Size::new(width, frame.height()));
+    }
 
-    layouter.layout(engine)
+    Ok(fragment)
 }
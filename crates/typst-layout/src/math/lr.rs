@@ -4,7 +4,9 @@ use typst_library::layout::{Abs, Axis, Length, Rel};
 use typst_library::math::{EquationElem, LrElem, MidElem};
 use unicode_math_class::MathClass;
 
-use super::{stretch_fragment, MathContext, MathFragment, DELIM_SHORT_FALL};
+use super::{
+    stretch_fragment, FrameFragment, MathContext, MathFragment, MathRun, DELIM_SHORT_FALL,
+};
 
 /// Lays out an [`LrElem`].
 #[typst_macros::time(name = "math.lr", span = elem.span())]
@@ -34,14 +36,32 @@ pub fn layout_lr(
         .iter()
         .position(|f| !f.is_ignorant())
         .unwrap_or(fragments.len());
-    let end_idx = fragments
+    let mut end_idx = fragments
         .iter()
         .skip(start_idx)
         .rposition(|f| !f.is_ignorant())
         .map_or(start_idx, |i| start_idx + i + 1);
-    let inner_fragments = &mut fragments[start_idx..end_idx];
 
     let axis = scaled!(ctx, styles, axis_height);
+
+    // Lay out multi-line content between two delimiters as a single block
+    // centered on the math axis, so that the delimiters enclose all rows
+    // instead of ending up in the first and last one. Content with alignment
+    // points is left as is because its rows align with the rest of the
+    // equation.
+    if end_idx >= start_idx + 3
+        && matches!(fragments[start_idx].class(), MathClass::Opening | MathClass::Fence)
+        && matches!(fragments[end_idx - 1].class(), MathClass::Closing | MathClass::Fence)
+        && is_stack(&fragments[start_idx + 1..end_idx - 1])
+    {
+        let middle: Vec<_> = fragments.drain(start_idx + 1..end_idx - 1).collect();
+        let mut frame = MathRun::new(middle).into_frame(ctx, styles);
+        frame.set_baseline(frame.height() / 2.0 + axis);
+        fragments.insert(start_idx + 1, FrameFragment::new(ctx, styles, frame).into());
+        end_idx = start_idx + 3;
+    }
+
+    let inner_fragments = &mut fragments[start_idx..end_idx];
     let max_extent = inner_fragments
         .iter()
         .map(|fragment| (fragment.ascent() - axis).max(fragment.descent() + axis))
@@ -122,6 +142,12 @@ pub fn layout_mid(
     Ok(())
 }
 
+/// Whether the fragments form multiple rows without alignment points.
+fn is_stack(fragments: &[MathFragment]) -> bool {
+    fragments.iter().any(|f| matches!(f, MathFragment::Linebreak))
+        && !fragments.iter().any(|f| matches!(f, MathFragment::Align))
+}
+
 /// Scale a math fragment to a height.
 fn scale(
     ctx: &mut MathContext,
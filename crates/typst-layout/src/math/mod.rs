@@ -109,13 +109,53 @@ pub fn layout_equation_block(
     let font = find_math_font(engine, styles, span)?;
 
     let mut locator = locator.split();
+
+    // The number is laid out upfront so that wrapping can leave room for it.
+    let number = match (**elem).numbering(styles) {
+        Some(numbering) => {
+            let pod = Region::new(regions.base(), Axes::splat(false));
+            let counter = Counter::of(EquationElem::elem())
+                .display_at_loc(engine, elem.location().unwrap(), styles, numbering)?
+                .spanned(span);
+            Some((engine.routines.layout_frame)(
+                engine,
+                &counter,
+                locator.next(&()),
+                styles,
+                pod,
+            )?)
+        }
+        None => None,
+    };
+
+    static NUMBER_GUTTER: Em = Em::new(0.5);
+    let full_number_width = number
+        .as_ref()
+        .map_or(Abs::zero(), |number| number.width() + NUMBER_GUTTER.resolve(styles));
+
+    let equation_align = AlignElem::alignment_in(styles).resolve(styles).x;
     let mut ctx = MathContext::new(engine, &mut locator, styles, regions.base(), &font);
-    let full_equation_builder = ctx
-        .layout_into_run(&elem.body, styles)?
-        .multiline_frame_builder(&ctx, styles);
+    let mut run = ctx.layout_into_run(&elem.body, styles)?;
+    if elem.wrap(styles) && regions.size.x.is_finite() {
+        // A centered equation must stay clear of the number on both sides.
+        let reserved = match equation_align {
+            FixedAlignment::Center => 2.0 * full_number_width,
+            _ => full_number_width,
+        };
+        run = run.wrap(regions.size.x - reserved, elem.wrap_indent(styles));
+    }
+
+    let hang =
+        if elem.hang_punctuation(styles) && equation_align == FixedAlignment::Center {
+            run.trailing_punctuation_width()
+        } else {
+            Abs::zero()
+        };
+
+    let full_equation_builder = run.multiline_frame_builder(&ctx, styles);
     let width = full_equation_builder.size.x;
 
-    let equation_builders = if BlockElem::breakable_in(styles) {
+    let mut equation_builders = if BlockElem::breakable_in(styles) {
         let mut rows = full_equation_builder.frames.into_iter().peekable();
         let mut equation_builders = vec![];
         let mut last_first_pos = Point::zero();
@@ -182,7 +222,18 @@ pub fn layout_equation_block(
         vec![full_equation_builder]
     };
 
-    let Some(numbering) = (**elem).numbering(styles) else {
+    // Let trailing punctuation hang outside of the centered equation by
+    // padding the equation on the left by the same amount.
+    if !hang.is_zero() {
+        if let Some(builder) = equation_builders.last_mut() {
+            builder.size.x += hang;
+            for (_, pos) in &mut builder.frames {
+                pos.x += hang;
+            }
+        }
+    }
+
+    let Some(number) = number else {
         let frames = equation_builders
             .into_iter()
             .map(MathRunFrameBuilder::build)
@@ -190,16 +241,6 @@ pub fn layout_equation_block(
         return Ok(Fragment::frames(frames));
     };
 
-    let pod = Region::new(regions.base(), Axes::splat(false));
-    let counter = Counter::of(EquationElem::elem())
-        .display_at_loc(engine, elem.location().unwrap(), styles, numbering)?
-        .spanned(span);
-    let number =
-        (engine.routines.layout_frame)(engine, &counter, locator.next(&()), styles, pod)?;
-
-    static NUMBER_GUTTER: Em = Em::new(0.5);
-    let full_number_width = number.width() + NUMBER_GUTTER.resolve(styles);
-
     let number_align = match elem.number_align(styles) {
         SpecificAlignment::H(h) => SpecificAlignment::Both(h, VAlignment::Horizon),
         SpecificAlignment::V(v) => SpecificAlignment::Both(OuterHAlignment::End, v),
@@ -219,9 +260,10 @@ pub fn layout_equation_block(
                 builder,
                 number.clone(),
                 number_align.resolve(styles),
-                AlignElem::alignment_in(styles).resolve(styles).x,
+                equation_align,
                 regions.size.x,
                 full_number_width,
+                elem.number_break(styles),
             )
         })
         .collect();
@@ -254,6 +296,7 @@ fn add_equation_number(
     equation_align: FixedAlignment,
     region_size_x: Abs,
     full_number_width: Abs,
+    number_break: bool,
 ) -> Frame {
     let first =
         equation_builder.frames.first().map_or(
@@ -274,6 +317,25 @@ fn add_equation_number(
         equation.width() + 2.0 * full_number_width
     };
 
+    if number_break
+        && region_size_x.is_finite()
+        && number_overlaps(
+            equation.width(),
+            number_align.x,
+            equation_align,
+            width,
+            full_number_width,
+        )
+    {
+        return add_equation_number_line(
+            equation,
+            number,
+            number_align,
+            equation_align,
+            width,
+        );
+    }
+
     let is_multiline = line_count >= 2;
     let resizing_offset = resize_equation(
         &mut equation,
@@ -313,6 +375,59 @@ fn add_equation_number(
     equation
 }
 
+/// Whether the number would overlap an equation of the given width if placed
+/// next to it.
+fn number_overlaps(
+    equation_width: Abs,
+    number_align_x: FixedAlignment,
+    equation_align: FixedAlignment,
+    width: Abs,
+    full_number_width: Abs,
+) -> bool {
+    let start = match (equation_align, number_align_x) {
+        (FixedAlignment::Start, FixedAlignment::Start) => full_number_width,
+        (FixedAlignment::Start, _) => Abs::zero(),
+        (FixedAlignment::Center, _) => (width - equation_width) / 2.0,
+        (FixedAlignment::End, FixedAlignment::End) => {
+            width - equation_width - full_number_width
+        }
+        (FixedAlignment::End, _) => width - equation_width,
+    };
+
+    match number_align_x {
+        FixedAlignment::Start => start < full_number_width,
+        _ => start + equation_width > width - full_number_width,
+    }
+}
+
+/// Place the number on its own line below the equation, or above it if the
+/// number is aligned to the top.
+fn add_equation_number_line(
+    mut equation: Frame,
+    number: Frame,
+    number_align: Axes<FixedAlignment>,
+    equation_align: FixedAlignment,
+    width: Abs,
+) -> Frame {
+    let above = number_align.y == FixedAlignment::Start;
+    let height = equation.height();
+    equation.resize(
+        Size::new(width, height + number.height()),
+        Axes::new(
+            equation_align,
+            if above { FixedAlignment::End } else { FixedAlignment::Start },
+        ),
+    );
+
+    let x = match number_align.x {
+        FixedAlignment::Start => Abs::zero(),
+        _ => width - number.width(),
+    };
+    let y = if above { Abs::zero() } else { height };
+    equation.push_frame(Point::new(x, y), number);
+    equation
+}
+
 /// Resize the equation's frame accordingly so that it encompasses the number.
 fn resize_equation(
     equation: &mut Frame,
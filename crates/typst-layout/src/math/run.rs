@@ -106,6 +106,23 @@ impl MathRun {
         Self(resolved)
     }
 
+    /// The width of the punctuation at the very end of the run.
+    pub fn trailing_punctuation_width(&self) -> Abs {
+        self.0
+            .iter()
+            .rev()
+            .filter(|fragment| !matches!(fragment, MathFragment::Tag(_)))
+            .map_while(|fragment| match fragment {
+                MathFragment::Glyph(glyph)
+                    if matches!(glyph.c, '.' | ',' | ';' | ':') =>
+                {
+                    Some(fragment.width())
+                }
+                _ => None,
+            })
+            .sum()
+    }
+
     pub fn iter(&self) -> std::slice::Iter<'_, MathFragment> {
         self.0.iter()
     }
@@ -367,6 +384,99 @@ impl MathRun {
     pub fn is_multiline(&self) -> bool {
         self.iter().any(|frag| matches!(frag, MathFragment::Linebreak))
     }
+
+    /// Breaks a single-row run that is wider than `width` into multiple rows.
+    ///
+    /// Rows are broken before relations or, if no relation fits, before binary
+    /// operators. If the first row contains a relation, the continuation rows
+    /// are aligned to it. Otherwise, they start at the same position as the
+    /// first row, indented by `indent`.
+    pub fn wrap(self, width: Abs, indent: Abs) -> Self {
+        let fragments = self.0;
+        if fragments
+            .iter()
+            .any(|frag| matches!(frag, MathFragment::Linebreak | MathFragment::Align))
+        {
+            return Self(fragments);
+        }
+
+        // The horizontal offset at which each fragment starts.
+        let mut offsets = Vec::with_capacity(fragments.len() + 1);
+        let mut x = Abs::zero();
+        for fragment in &fragments {
+            offsets.push(x);
+            x += fragment.width();
+        }
+        offsets.push(x);
+
+        let extent = |start: usize, end: usize| offsets[end] - offsets[start];
+        let class = |i: usize| fragments[i].class();
+        let candidates: Vec<usize> = (1..fragments.len())
+            .filter(|&i| matches!(class(i), MathClass::Relation | MathClass::Binary))
+            .collect();
+
+        // Greedily determine the start indices of the rows.
+        let mut breaks = vec![];
+        let mut align = None;
+        let mut start = 0;
+        let mut available = width;
+        while !available.fits(extent(start, fragments.len())) {
+            let mut after = candidates.iter().copied().filter(|&i| i > start);
+            let fitting: Vec<usize> =
+                after.clone().filter(|&i| available.fits(extent(start, i))).collect();
+            let relation = fitting
+                .iter()
+                .copied()
+                .filter(|&i| class(i) == MathClass::Relation)
+                .last();
+            let Some(next) =
+                relation.or(fitting.last().copied()).or_else(|| after.next())
+            else {
+                break;
+            };
+
+            // Align the continuation rows to the first relation if it is not
+            // too far to the right. Otherwise, indent them.
+            if breaks.is_empty() {
+                align = (0..next)
+                    .find(|&i| class(i) == MathClass::Relation)
+                    .filter(|&i| offsets[i] < width / 2.0);
+                available = width - align.map_or(indent, |i| offsets[i]);
+            }
+
+            breaks.push(next);
+            start = next;
+        }
+
+        if breaks.is_empty() {
+            return Self(fragments);
+        }
+
+        // Without a relation, all rows are aligned at their start so that the
+        // indent is exact and not subject to centering.
+        let mut output = Vec::with_capacity(fragments.len() + 3 * breaks.len() + 1);
+        if align.is_none() {
+            output.push(MathFragment::Align);
+        }
+        for (i, fragment) in fragments.into_iter().enumerate() {
+            if breaks.contains(&i) {
+                // Drop spacing that would end up at the end of the row.
+                if matches!(output.last(), Some(MathFragment::Spacing(..))) {
+                    output.pop();
+                }
+                output.push(MathFragment::Linebreak);
+                output.push(MathFragment::Align);
+                if align.is_none() {
+                    output.push(MathFragment::Spacing(indent, false));
+                }
+            } else if align == Some(i) {
+                output.push(MathFragment::Align);
+            }
+            output.push(fragment);
+        }
+
+        Self(output)
+    }
 }
 
 impl<T: Into<MathFragment>> From<T> for MathRun {
@@ -470,3 +580,50 @@ fn spacing(
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::fragment::Limits;
+    use super::*;
+
+    fn fragment(width: f64, class: MathClass) -> MathFragment {
+        let frame = Frame::hard(Size::new(Abs::pt(width), Abs::pt(10.0)));
+        MathFragment::Frame(FrameFragment {
+            base_ascent: frame.ascent(),
+            frame,
+            font_size: Abs::pt(10.0),
+            class,
+            math_size: MathSize::Text,
+            limits: Limits::Never,
+            spaced: false,
+            italics_correction: Abs::zero(),
+            accent_attach: Abs::zero(),
+            text_like: false,
+            ignorant: false,
+        })
+    }
+
+    #[test]
+    fn test_wrap_indents_continuation_rows_exactly() {
+        let run = MathRun(vec![
+            fragment(10.0, MathClass::Normal),
+            fragment(10.0, MathClass::Binary),
+            fragment(10.0, MathClass::Normal),
+            fragment(10.0, MathClass::Binary),
+            fragment(10.0, MathClass::Normal),
+        ]);
+
+        let rows = run.wrap(Abs::pt(35.0), Abs::pt(5.0)).rows();
+        assert_eq!(rows.len(), 2);
+
+        let points = alignments(&rows).points;
+        let xs: Vec<_> = rows
+            .into_iter()
+            .map(|row| {
+                let frame = row.into_line_frame(&points, LeftRightAlternator::Right);
+                frame.items().next().unwrap().0.x
+            })
+            .collect();
+        assert_eq!(xs, [Abs::zero(), Abs::pt(5.0)]);
+    }
+}
@@ -163,3 +163,35 @@ fn migrate_unterminated_tags(children: &mut [Pair], mid: usize) -> usize {
     // Compute the new end index, right before the pagebreaks.
     start + children[start..end].iter().take_while(|pair| key(pair) == -1).count()
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::{Content, NativeElement};
+    use typst_library::text::TextElem;
+
+    use super::*;
+
+    /// Counts the page runs produced for the given children.
+    fn runs(contents: &[&Content]) -> usize {
+        let mut children: Vec<Pair> =
+            contents.iter().map(|&c| (c, StyleChain::default())).collect();
+        collect(&mut children, Locator::root().split(), StyleChain::default())
+            .iter()
+            .filter(|item| matches!(item, Item::Run(..)))
+            .count()
+    }
+
+    #[test]
+    fn test_weak_pagebreak_before_chapter() {
+        let weak = PagebreakElem::new().with_weak(true).pack();
+        let chapter = TextElem::packed("Chapter");
+        let text = TextElem::packed("Text");
+
+        // At the top of a fresh page, no empty page is inserted.
+        assert_eq!(runs(&[&weak, &chapter]), 1);
+        // In the middle of a page, the next chapter starts on a new one.
+        assert_eq!(runs(&[&weak, &chapter, &text, &weak, &chapter]), 2);
+        // Consecutive weak pagebreaks collapse into one.
+        assert_eq!(runs(&[&chapter, &weak, &weak, &chapter]), 2);
+    }
+}
@@ -1,7 +1,7 @@
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
 use typst_library::introspection::{ManualPageCounter, Tag};
-use typst_library::layout::{Frame, FrameItem, Page, Point};
+use typst_library::layout::{Frame, FrameItem, GroupItem, Page, Point};
 
 use super::LayoutedPage;
 
@@ -21,15 +21,18 @@ pub fn finalize(
         footer,
         background,
         foreground,
+        corners,
         fill,
         numbering,
         supplement,
+        sidenotes,
     }: LayoutedPage,
 ) -> SourceResult<Page> {
     // If two sided, left becomes inside and right becomes outside.
     // Thus, for left-bound pages, we want to swap on even pages and
     // for right-bound pages, we want to swap on odd pages.
-    if two_sided && binding.swap(counter.physical()) {
+    let swapped = two_sided && binding.swap(counter.physical());
+    if swapped {
         std::mem::swap(&mut margin.left, &mut margin.right);
     }
 
@@ -45,22 +48,36 @@ pub fn finalize(
     // important as it affects the relative ordering of introspectable elements
     // and thus how counters resolve.
     if let Some(background) = background {
-        frame.push_frame(Point::zero(), background);
+        push_artifact(&mut frame, Point::zero(), background);
     }
     if let Some(header) = header {
-        frame.push_frame(Point::with_x(margin.left), header);
+        push_artifact(&mut frame, Point::with_x(margin.left), header);
     }
 
     // Add the inner contents.
+    let width = inner.width();
     frame.push_frame(Point::new(margin.left, margin.top), inner);
 
+    // Add the sidenotes to the outside margin.
+    for note in sidenotes {
+        let x = if swapped {
+            margin.left - note.gap - note.frame.width()
+        } else {
+            margin.left + width + note.gap
+        };
+        frame.push_frame(Point::new(x, margin.top + note.y), note.frame);
+    }
+
     // Add the "after" marginals.
     if let Some(footer) = footer {
         let y = frame.height() - footer.height();
-        frame.push_frame(Point::new(margin.left, y), footer);
+        push_artifact(&mut frame, Point::new(margin.left, y), footer);
+    }
+    if let Some(corners) = corners {
+        push_artifact(&mut frame, Point::zero(), corners);
     }
     if let Some(foreground) = foreground {
-        frame.push_frame(Point::zero(), foreground);
+        push_artifact(&mut frame, Point::zero(), foreground);
     }
 
     // Apply counter updates from within the page to the manual page counter.
@@ -72,3 +89,11 @@ pub fn finalize(
 
     Ok(Page { frame, fill, numbering, supplement, number })
 }
+
+/// Add a marginal to the page frame. It is marked as an artifact because it
+/// is decorative and not part of the page's logical contents.
+fn push_artifact(frame: &mut Frame, pos: Point, marginal: Frame) {
+    let mut group = GroupItem::new(marginal);
+    group.artifact = true;
+    frame.push(pos, FrameItem::Group(group));
+}
@@ -0,0 +1,149 @@
+use std::num::NonZeroUsize;
+
+use typst_library::foundations::{Content, Smart};
+use typst_library::layout::{Frame, Page, Point, Size};
+
+/// Impose pages onto two-up sheets for a saddle-stitched booklet.
+///
+/// Each resulting page is one side of a sheet, holding two of the original
+/// pages next to each other. Slots that fall past the end of the document are
+/// left blank.
+pub fn impose(mut pages: Vec<Page>, signature: NonZeroUsize) -> Vec<Page> {
+    let Some(first) = pages.first() else { return pages };
+    let size = first.frame.size();
+
+    // Page fills are per page, but a sheet only has one fill, so we bake them
+    // into the frames.
+    for page in &mut pages {
+        if let Smart::Custom(Some(paint)) = page.fill.clone() {
+            page.frame.fill(paint);
+        }
+    }
+
+    let mut slots: Vec<Option<Page>> = pages.into_iter().map(Some).collect();
+    booklet_order(slots.len(), signature.get())
+        .into_iter()
+        .enumerate()
+        .map(|(i, [left, right])| {
+            let left = left.and_then(|k| slots[k].take());
+            let right = right.and_then(|k| slots[k].take());
+            let left_size = left.as_ref().map_or(size, |page| page.frame.size());
+            let right_size = right.as_ref().map_or(size, |page| page.frame.size());
+
+            let mut sheet = Frame::hard(Size::new(
+                left_size.x + right_size.x,
+                left_size.y.max(right_size.y),
+            ));
+            if let Some(page) = left {
+                sheet.push_frame(Point::zero(), page.frame);
+            }
+            if let Some(page) = right {
+                sheet.push_frame(Point::with_x(left_size.x), page.frame);
+            }
+
+            Page {
+                frame: sheet,
+                fill: Smart::Auto,
+                numbering: None,
+                supplement: Content::empty(),
+                number: i + 1,
+            }
+        })
+        .collect()
+}
+
+/// Determine which pages end up on the left and right half of each sheet side.
+///
+/// The pages are split into signatures of the given size (rounded up to a
+/// multiple of four). The last signature shrinks to the remaining pages,
+/// padded with blank slots.
+fn booklet_order(count: usize, signature: usize) -> Vec<[Option<usize>; 2]> {
+    let signature = signature.div_ceil(4).max(1) * 4;
+    let mut sides = vec![];
+    let mut start = 0;
+    while start < count {
+        let size = signature.min((count - start).div_ceil(4) * 4);
+        let slot = |k: usize| Some(start + k).filter(|&i| i < count);
+        for k in 0..size / 2 {
+            let (left, right) =
+                if k % 2 == 0 { (size - 1 - k, k) } else { (k, size - 1 - k) };
+            sides.push([slot(left), slot(right)]);
+        }
+        start += size;
+    }
+    sides
+}
+
+#[cfg(test)]
+mod test {
+    use typst_library::layout::{Abs, FrameItem};
+
+    use super::*;
+
+    #[test]
+    fn test_impose_sheets() {
+        // Pages of distinct widths, so that they can be told apart on the
+        // sheets.
+        let page = |width: f64| Page {
+            frame: Frame::hard(Size::new(Abs::pt(width), Abs::pt(20.0))),
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 0,
+        };
+        let pages = vec![page(10.0), page(20.0), page(30.0), page(40.0), page(50.0)];
+        let sheets = impose(pages, NonZeroUsize::new(8).unwrap());
+
+        // Five pages fill a signature of eight slots, so four sheet sides.
+        let layout = sheets
+            .iter()
+            .map(|sheet| {
+                sheet
+                    .frame
+                    .items()
+                    .map(|(pos, item)| match item {
+                        FrameItem::Group(group) => (pos.x, group.frame.width()),
+                        _ => panic!("expected a page frame"),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let pt = Abs::pt;
+        assert_eq!(
+            layout,
+            vec![
+                vec![(pt(10.0), pt(10.0))],
+                vec![(pt(0.0), pt(20.0))],
+                vec![(pt(10.0), pt(30.0))],
+                vec![(pt(0.0), pt(40.0)), (pt(40.0), pt(50.0))],
+            ]
+        );
+        assert_eq!(
+            sheets.iter().map(|sheet| sheet.number).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_booklet_order_single_signature() {
+        let order = |sides: &[[usize; 2]]| {
+            sides.iter().map(|s| s.map(|k| Some(k - 1))).collect::<Vec<_>>()
+        };
+        assert_eq!(booklet_order(8, 8), order(&[[8, 1], [2, 7], [6, 3], [4, 5]]));
+        assert_eq!(booklet_order(4, 100), order(&[[4, 1], [2, 3]]));
+    }
+
+    #[test]
+    fn test_booklet_order_padding_and_signatures() {
+        assert_eq!(booklet_order(3, 4), vec![[None, Some(0)], [Some(1), Some(2)]]);
+        assert_eq!(
+            booklet_order(6, 4),
+            vec![
+                [Some(3), Some(0)],
+                [Some(1), Some(2)],
+                [None, Some(4)],
+                [Some(5), None],
+            ]
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 mod collect;
 mod finalize;
+mod impose;
 mod run;
 
 use comemo::{Tracked, TrackedMut};
@@ -18,6 +19,7 @@ use typst_library::World;
 
 use self::collect::{collect, Item};
 use self::finalize::finalize;
+use self::impose::impose;
 use self::run::{layout_blank_page, layout_page_run, LayoutedPage};
 
 /// Layout content into a document.
@@ -83,8 +85,14 @@ fn layout_document_impl(
         styles,
     )?;
 
-    let pages = layout_pages(&mut engine, &mut children, locator, styles)?;
+    let mut pages = layout_pages(&mut engine, &mut children, locator, styles)?;
+
+    // Introspect before imposing, so that page numbers and positions refer to
+    // the pages in reading order rather than to the sheets.
     let introspector = Introspector::paged(&pages);
+    if let Some(signature) = info.booklet {
+        pages = impose(pages, signature);
+    }
 
     Ok(PagedDocument { pages, info, introspector })
 }
@@ -1,18 +1,21 @@
+use std::collections::HashSet;
+
 use comemo::{Track, Tracked, TrackedMut};
 use typst_library::diag::SourceResult;
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{
-    Content, NativeElement, Resolve, Smart, StyleChain, Styles,
+    Content, NativeElement, Packed, Resolve, Smart, StyleChain, Styles,
 };
 use typst_library::introspection::{
-    Counter, CounterDisplayElem, CounterKey, Introspector, Locator, LocatorLink, TagElem,
+    Counter, CounterDisplayElem, CounterKey, Introspector, Location, Locator,
+    LocatorLink, SplitLocator, Tag, TagElem,
 };
 use typst_library::layout::{
-    Abs, AlignElem, Alignment, Axes, Binding, ColumnsElem, Dir, Frame, HAlignment,
-    Length, OuterVAlignment, PageElem, Paper, Region, Regions, Rel, Sides, Size,
-    VAlignment,
+    Abs, AlignElem, Alignment, Axes, Binding, ColumnsElem, Dir, Fr, Frame, FrameItem,
+    HAlignment, Length, OuterVAlignment, PadElem, PageCornerElem, PageElem, Paper,
+    PlaceElem, Ratio, Region, Regions, Rel, Sides, Size, Spacing, Transform, VAlignment,
 };
-use typst_library::model::Numbering;
+use typst_library::model::{Numbering, SidenoteElem};
 use typst_library::routines::{Pair, Routines};
 use typst_library::text::{LocalName, TextElem};
 use typst_library::visualize::Paint;
@@ -34,9 +37,22 @@ pub struct LayoutedPage {
     pub footer: Option<Frame>,
     pub background: Option<Frame>,
     pub foreground: Option<Frame>,
+    pub corners: Option<Frame>,
     pub fill: Smart<Option<Paint>>,
     pub numbering: Option<Numbering>,
     pub supplement: Content,
+    pub sidenotes: Vec<LayoutedSidenote>,
+}
+
+/// A sidenote laid out for the outer margin of a page.
+#[derive(Clone)]
+pub struct LayoutedSidenote {
+    /// The vertical offset of the note from the top of the page body.
+    pub y: Abs,
+    /// The horizontal distance between the page body and the note.
+    pub gap: Abs,
+    /// The laid out note.
+    pub frame: Frame,
 }
 
 /// Layout a single page suitable  for parity adjustment.
@@ -85,7 +101,8 @@ fn layout_page_run_impl(
     initial: StyleChain,
 ) -> SourceResult<Vec<LayoutedPage>> {
     let link = LocatorLink::new(locator);
-    let mut locator = Locator::link(&link).split();
+    let base = Locator::link(&link);
+    let mut locator = base.relayout().split();
     let mut engine = Engine {
         routines,
         world,
@@ -114,18 +131,21 @@ fn layout_page_run_impl(
     }
 
     // Determine the margins.
-    let default = Rel::<Length>::from((2.5 / 21.0) * min);
+    let default = Spacing::Rel(Rel::<Length>::from((2.5 / 21.0) * min));
     let margin = PageElem::margin_in(styles);
     let two_sided = margin.two_sided.unwrap_or(false);
-    let margin = margin
-        .sides
-        .map(|side| side.and_then(Smart::custom).unwrap_or(default))
-        .resolve(styles)
-        .relative_to(size);
+    let margin = resolve_margins(
+        margin
+            .sides
+            .map(|side| side.and_then(Smart::custom).unwrap_or(default)),
+        size,
+        styles,
+    );
 
     let fill = PageElem::fill_in(styles);
     let foreground = PageElem::foreground_in(styles);
     let background = PageElem::background_in(styles);
+    let corners = corner_content(PageElem::corners_in(styles), styles);
     let header_ascent = PageElem::header_ascent_in(styles).relative_to(margin.top);
     let footer_descent = PageElem::footer_descent_in(styles).relative_to(margin.bottom);
     let numbering = PageElem::numbering_in(styles);
@@ -173,17 +193,149 @@ fn layout_page_run_impl(
 
     // Layout the children.
     let area = size - margin.sum_by_axis();
-    let fragment = layout_flow(
+    let columns = PageElem::columns_in(styles);
+    let gutter = ColumnsElem::gutter_in(styles);
+
+    // Try to fit the content onto a single page by laying it out into a larger
+    // body and scaling it down afterwards.
+    let mut scale = 1.0;
+    if let Some(floor) = PageElem::shrink_in(styles).filter(|_| area.y.is_finite()) {
+        // Lays the children out into pages with bodies that are scaled up by
+        // the inverse of `scale`.
+        let pages = |engine: &mut Engine, scale: f64| {
+            let regions = Regions::repeat(area / scale, area.map(Abs::is_finite));
+            let mut locator = base.relayout().split();
+            layout_flow(
+                engine,
+                children,
+                &mut locator,
+                styles,
+                regions,
+                columns,
+                gutter,
+                true,
+            )
+        };
+
+        let floor = floor.get().clamp(SHRINK_TOLERANCE, 1.0);
+        if pages(&mut engine, 1.0)?.len() > 1 && pages(&mut engine, floor)?.len() == 1 {
+            // Search for the largest scale at which everything still fits.
+            // Each step lays the run out again, so together with the two
+            // checks above, this takes at most nine layouts (for a floor of
+            // 1%). Runs that don't need shrinking or can't be shrunk enough
+            // only cost one or two.
+            let mut low = floor;
+            let mut high = 1.0;
+            while high - low > SHRINK_TOLERANCE {
+                let mid = (low + high) / 2.0;
+                if pages(&mut engine, mid)?.len() == 1 {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            scale = low;
+        }
+    }
+
+    // Try to avoid a widow page by letting the previous pages extend into the
+    // bottom margin. A shrunk run fits onto a single page, so there is no
+    // widow to avoid. If shrinking wasn't possible, the run is laid out at its
+    // normal scale and this applies as usual.
+    let mut extra = Abs::zero();
+    if let Some(min_fill) =
+        PageElem::min_fill_in(styles).filter(|_| area.y.is_finite() && scale == 1.0)
+    {
+        // Lays the children out into pages with bodies that are `extra`
+        // taller than usual and fit their content vertically.
+        let pages = |engine: &mut Engine, extra: Abs| {
+            let pod = Size::new(area.x, area.y + extra);
+            let regions = Regions::repeat(pod, Axes::new(area.x.is_finite(), false));
+            let mut locator = base.relayout().split();
+            layout_flow(
+                engine,
+                children,
+                &mut locator,
+                styles,
+                regions,
+                columns,
+                gutter,
+                true,
+            )
+        };
+
+        let natural = pages(&mut engine, Abs::zero())?;
+        let count = natural.len();
+        let widow = natural
+            .iter()
+            .last()
+            .is_some_and(|frame| frame.height() < min_fill.of(area.y));
+
+        let max = max_extension(margin.bottom, footer.is_some(), footer_descent);
+
+        if count > 1 && widow && pages(&mut engine, max)?.len() < count {
+            // Search for the smallest extension that still saves a page.
+            let mut low = Abs::zero();
+            let mut high = max;
+            while high - low > Abs::pt(WIDOW_TOLERANCE) {
+                let mid = (low + high) / 2.0;
+                if pages(&mut engine, mid)?.len() < count {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            extra = high;
+        }
+    }
+
+    let mut fragment = layout_flow(
         &mut engine,
         children,
         &mut locator,
         styles,
-        Regions::repeat(area, area.map(Abs::is_finite)),
-        PageElem::columns_in(styles),
-        ColumnsElem::gutter_in(styles),
+        Regions::repeat(
+            Size::new(area.x, area.y + extra) / scale,
+            area.map(Abs::is_finite),
+        ),
+        columns,
+        gutter,
         true,
     )?;
 
+    // Extended bodies spill into the bottom margin, but the page keeps its
+    // size.
+    if !extra.is_zero() {
+        for frame in &mut fragment {
+            frame.set_size(area);
+        }
+    }
+
+    // Shrunk bodies are scaled down to the actual body size.
+    if scale != 1.0 {
+        for frame in &mut fragment {
+            let size = frame.size() * scale;
+            frame.transform(Transform::scale(Ratio::new(scale), Ratio::new(scale)));
+            frame.set_size(size);
+        }
+    }
+
+    // Layout the sidenotes, which go into the outside margin. The right
+    // margin is only swapped into the left at finalization, so its size is
+    // the one that counts.
+    let mut sidenotes = Vec::with_capacity(fragment.len());
+    let mut seen = HashSet::new();
+    for inner in &fragment {
+        sidenotes.push(layout_sidenotes(
+            &mut engine,
+            &mut locator,
+            styles,
+            inner,
+            margin,
+            &mut seen,
+        )?);
+    }
+
     // Layouts a single marginal.
     let mut layout_marginal = |content: &Option<Content>, area, align| {
         let Some(content) = content else { return Ok(None) };
@@ -200,7 +352,7 @@ fn layout_page_run_impl(
 
     // Layout marginals.
     let mut layouted = Vec::with_capacity(fragment.len());
-    for inner in fragment {
+    for (inner, sidenotes) in fragment.into_iter().zip(sidenotes) {
         let header_size = Size::new(inner.width(), margin.top - header_ascent);
         let footer_size = Size::new(inner.width(), margin.bottom - footer_descent);
         let full_size = inner.size() + margin.sum_by_axis();
@@ -214,15 +366,180 @@ fn layout_page_run_impl(
             footer: layout_marginal(footer, footer_size, Alignment::TOP)?,
             background: layout_marginal(background, full_size, mid)?,
             foreground: layout_marginal(foreground, full_size, mid)?,
+            corners: layout_marginal(&corners, full_size, mid)?,
             margin,
             binding,
             two_sided,
+            sidenotes,
         });
     }
 
     Ok(layouted)
 }
 
+/// Builds the content anchored to the corners of the page, if any.
+///
+/// Each corner's content is padded by its distances from the page edges and
+/// then placed in the full page area.
+fn corner_content(
+    corners: &[Packed<PageCornerElem>],
+    styles: StyleChain,
+) -> Option<Content> {
+    if corners.is_empty() {
+        return None;
+    }
+
+    Some(Content::sequence(corners.iter().map(|corner| {
+        let dx = Rel::from(corner.dx(styles));
+        let dy = Rel::from(corner.dy(styles));
+        let padded = PadElem::new(corner.body().clone())
+            .with_left(dx)
+            .with_right(dx)
+            .with_top(dy)
+            .with_bottom(dy)
+            .pack();
+        PlaceElem::new(padded)
+            .with_alignment(Smart::Custom(*corner.alignment()))
+            .pack()
+            .spanned(corner.span())
+    })))
+}
+
+/// Lays out the sidenotes anchored in the body of a page.
+///
+/// Each note's first baseline is aligned with the position of its anchor. If
+/// that would make it overlap with the previous note, it is pushed down. Notes
+/// that would run off the bottom of the page are pulled back up, along with
+/// the notes above them.
+///
+/// Notes with a hidden anchor are left out. So are notes whose anchor was
+/// already `seen`, e.g. because it is part of a repeated table header.
+fn layout_sidenotes(
+    engine: &mut Engine,
+    locator: &mut SplitLocator,
+    styles: StyleChain,
+    inner: &Frame,
+    margin: Sides<Abs>,
+    seen: &mut HashSet<Location>,
+) -> SourceResult<Vec<LayoutedSidenote>> {
+    let mut anchors = vec![];
+    find_sidenotes(inner, Transform::identity(), &mut anchors);
+    anchors.sort_by_key(|(y, _)| *y);
+    anchors.retain(|(_, elem)| {
+        elem.hidden() != Some(&true)
+            && elem.location().is_some_and(|loc| seen.insert(loc))
+    });
+
+    let mut notes: Vec<LayoutedSidenote> = Vec::with_capacity(anchors.len());
+    let mut clearances = Vec::with_capacity(anchors.len());
+    for (anchor, elem) in anchors {
+        let gap = elem.gap(styles).resolve(styles);
+        let clearance = elem.clearance(styles).resolve(styles);
+        let pod = Size::new((margin.right - gap).max(Abs::zero()), Abs::inf());
+        let frame = crate::layout_frame(
+            engine,
+            elem.body(),
+            locator.next(&elem.span()),
+            styles,
+            Region::new(pod, Axes::new(true, false)),
+        )?;
+
+        let ascent = frame.baselines().map_or(Abs::zero(), |(first, _)| first);
+        let mut y = anchor - ascent;
+        if let Some(prev) = notes.last() {
+            y.set_max(prev.y + prev.frame.height() + clearance);
+        }
+
+        notes.push(LayoutedSidenote { y, gap, frame });
+        clearances.push(clearance);
+    }
+
+    // Keep the notes within the page.
+    let mut limit = inner.height() + margin.bottom;
+    for (note, clearance) in notes.iter_mut().zip(clearances).rev() {
+        note.y.set_min(limit - note.frame.height());
+        note.y.set_max(-margin.top);
+        limit = note.y - clearance;
+    }
+
+    Ok(notes)
+}
+
+/// Collects the sidenotes in a frame together with the vertical positions of
+/// their anchors.
+fn find_sidenotes(
+    frame: &Frame,
+    ts: Transform,
+    out: &mut Vec<(Abs, Packed<SidenoteElem>)>,
+) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                find_sidenotes(&group.frame, ts, out);
+            }
+            FrameItem::Tag(Tag::Start(elem)) => {
+                if let Some(note) = elem.to_packed::<SidenoteElem>() {
+                    out.push((pos.transform(ts).y, note.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolves the page margins.
+///
+/// The space that is left along an axis after subtracting the fixed margins is
+/// distributed among the fractional margins on that axis and the body, which
+/// takes a share of `1fr`.
+fn resolve_margins(sides: Sides<Spacing>, size: Size, styles: StyleChain) -> Sides<Abs> {
+    let mut margin = sides
+        .map(|side| match side {
+            Spacing::Rel(rel) => rel,
+            Spacing::Fr(_) => Rel::zero(),
+        })
+        .resolve(styles)
+        .relative_to(size);
+
+    let fr = sides.map(|side| match side {
+        Spacing::Rel(_) => Fr::zero(),
+        Spacing::Fr(fr) => fr,
+    });
+
+    let distribute = |first: &mut Abs, second: &mut Abs, a: Fr, b: Fr, extent: Abs| {
+        let total = a + b + Fr::one();
+        let remaining = (extent - *first - *second).max(Abs::zero());
+        *first += a.share(total, remaining);
+        *second += b.share(total, remaining);
+    };
+
+    distribute(&mut margin.left, &mut margin.right, fr.left, fr.right, size.x);
+    distribute(&mut margin.top, &mut margin.bottom, fr.top, fr.bottom, size.y);
+    margin
+}
+
+/// How far the body may extend into the bottom margin to avoid a widow page.
+///
+/// This is at most half of the bottom margin and never reaches into the footer,
+/// which starts `footer_descent` below the body.
+fn max_extension(bottom: Abs, has_footer: bool, footer_descent: Abs) -> Abs {
+    let mut max = bottom / 2.0;
+    if has_footer {
+        max.set_min(footer_descent);
+    }
+    max
+}
+
+/// The precision (in points) up to which the extension of pages for avoiding
+/// a widow page is determined.
+const WIDOW_TOLERANCE: f64 = 0.5;
+
+/// The precision up to which the scale of a shrunk page run is determined.
+const SHRINK_TOLERANCE: f64 = 0.01;
+
 /// Determines the styles used for a page run itself and page-level content like
 /// marginals and footnotes.
 ///
@@ -271,3 +588,16 @@ fn determine_page_styles(children: &[Pair], initial: StyleChain) -> Styles {
         .map(|(_, style)| style)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_extension_stops_at_footer() {
+        let pt = Abs::pt;
+        assert_eq!(max_extension(pt(20.0), false, pt(4.0)), pt(10.0));
+        assert_eq!(max_extension(pt(20.0), true, pt(4.0)), pt(4.0));
+        assert_eq!(max_extension(pt(6.0), true, pt(4.0)), pt(3.0));
+    }
+}
@@ -1,10 +1,12 @@
 use typst_library::diag::{bail, SourceResult};
 use typst_library::engine::Engine;
-use typst_library::foundations::{Packed, Resolve, StyleChain};
+use typst_library::foundations::{NativeElement, Packed, Resolve, StyleChain};
 use typst_library::introspection::Locator;
 use typst_library::layout::{
-    Abs, AlignElem, Axes, Frame, Point, Region, RepeatElem, Size,
+    Abs, AlignElem, Axes, Frame, Point, Region, RepeatElem, RotateElem, Size,
+    WatermarkElem,
 };
+use typst_library::visualize::Path;
 use typst_utils::Numeric;
 
 /// Layout the repeated content.
@@ -58,3 +60,106 @@ pub fn layout_repeat(
 
     Ok(frame)
 }
+
+/// Layout a watermark by tiling its rotated body across the region.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_watermark(
+    elem: &Packed<WatermarkElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    if !region.size.is_finite() {
+        bail!(elem.span(), "watermark with no size restrictions");
+    }
+
+    // Rotate the body with reflow so that the tiles don't overlap.
+    let body = RotateElem::new(elem.body().clone())
+        .with_angle(elem.angle(styles))
+        .with_reflow(true)
+        .pack()
+        .spanned(elem.span());
+
+    let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+    let mut piece = crate::layout_frame(engine, &body, locator, styles, pod)?;
+    piece.fade(elem.opacity(styles).get() as f32);
+
+    let spacing = elem.spacing(styles).resolve(styles);
+    let step = piece.size() + Size::splat(spacing);
+
+    let mut frame = Frame::soft(region.size);
+    if !piece.is_empty() {
+        for pos in watermark_tiles(region.size, step) {
+            frame.push_frame(pos, piece.clone());
+        }
+    }
+
+    frame.clip(Path::rect(region.size));
+    Ok(frame)
+}
+
+/// The positions of the watermark tiles that cover a region of the given size
+/// when each tile takes up `step`.
+fn watermark_tiles(size: Size, step: Size) -> Vec<Point> {
+    if step.x <= Abs::zero() || step.y <= Abs::zero() {
+        return vec![];
+    }
+
+    // Stagger every other row by half a tile. The staggered rows start half a
+    // tile early, so they need one more tile to cover the region.
+    let rows = (size.y / step.y).ceil() as usize;
+    let cols = (size.x / step.x).ceil() as usize + 1;
+    let mut tiles = Vec::with_capacity(rows * cols);
+    for row in 0..rows {
+        let y = step.y * row as f64;
+        let offset = if row % 2 == 0 { Abs::zero() } else { -step.x / 2.0 };
+        for col in 0..cols {
+            tiles.push(Point::new(offset + step.x * col as f64, y));
+        }
+    }
+    tiles
+}
+
+#[cfg(test)]
+mod tests {
+    use typst_library::layout::FrameItem;
+    use typst_library::visualize::{Color, Geometry, Paint};
+    use typst_syntax::Span;
+
+    use super::*;
+
+    #[test]
+    fn test_watermark_tiles_are_staggered() {
+        let pt = |x: f64, y: f64| Point::new(Abs::pt(x), Abs::pt(y));
+        let size = Size::splat(Abs::pt(40.0));
+        assert_eq!(
+            watermark_tiles(size, Size::splat(Abs::pt(20.0))),
+            vec![
+                pt(0.0, 0.0),
+                pt(20.0, 0.0),
+                pt(40.0, 0.0),
+                pt(-10.0, 20.0),
+                pt(10.0, 20.0),
+                pt(30.0, 20.0),
+            ]
+        );
+        assert!(watermark_tiles(size, Size::zero()).is_empty());
+    }
+
+    #[test]
+    fn test_watermark_fades_shapes() {
+        let mut piece = Frame::soft(Size::splat(Abs::pt(10.0)));
+        let shape = Geometry::Rect(piece.size()).filled(Color::BLACK);
+        piece.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+        piece.fade(0.5);
+
+        let Some((_, FrameItem::Shape(shape, _))) = piece.items().next() else {
+            panic!("expected a shape");
+        };
+        let Some(Paint::Solid(color)) = shape.fill else {
+            panic!("expected a solid fill");
+        };
+        assert_eq!(color.alpha(), Some(0.5));
+    }
+}
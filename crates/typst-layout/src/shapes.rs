@@ -1,17 +1,19 @@
 use std::f64::consts::SQRT_2;
 
-use kurbo::ParamCurveExtrema;
+use kurbo::{ParamCurve, ParamCurveArclen, ParamCurveDeriv, ParamCurveExtrema};
 use typst_library::diag::{bail, SourceResult};
 use typst_library::engine::Engine;
-use typst_library::foundations::{Content, Packed, Resolve, Smart, StyleChain};
+use typst_library::foundations::{Content, Packed, Resolve, Smart, StyleChain, Styles};
 use typst_library::introspection::Locator;
 use typst_library::layout::{
-    Abs, Axes, Corner, Corners, Frame, FrameItem, Length, Point, Ratio, Region, Rel,
-    Sides, Size,
+    Abs, Angle, Axes, Corner, Corners, Em, FixAlignment, FixedAlignment, Frame,
+    FrameItem, Length, Point, Ratio, Region, Rel, Sides, Size, Transform,
 };
+use typst_library::text::{Glyph, TextElem, TextItem};
 use typst_library::visualize::{
     CircleElem, EllipseElem, FillRule, FixedStroke, Geometry, LineElem, Paint, Path,
-    PathElem, PathVertex, PolygonElem, RectElem, Shape, SquareElem, Stroke,
+    PathElem, PathItem, PathVertex, PolygonElem, RectElem, Shape, SquareElem, Stroke,
+    StrokeAlign, TextOnPathElem,
 };
 use typst_syntax::Span;
 use typst_utils::{Get, Numeric};
@@ -57,6 +59,38 @@ pub fn layout_path(
     styles: StyleChain,
     region: Region,
 ) -> SourceResult<Frame> {
+    let Some((path, size)) = build_path(elem, styles, region)? else {
+        return Ok(Frame::soft(Size::zero()));
+    };
+
+    // Prepare fill and stroke.
+    let fill = elem.fill(styles);
+    let fill_rule = elem.fill_rule(styles);
+    let stroke = match elem.stroke(styles) {
+        Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
+        Smart::Auto => None,
+        Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
+    };
+
+    let mut frame = Frame::soft(size);
+    let shape = Shape {
+        geometry: Geometry::Path(path),
+        stroke,
+        fill,
+        fill_rule,
+    };
+    frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+    Ok(frame)
+}
+
+/// Build the geometry of a path together with the size of its bounding box.
+///
+/// Returns `None` if the path has no vertices.
+fn build_path(
+    elem: &Packed<PathElem>,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Option<(Path, Size)>> {
     let resolve = |axes: Axes<Rel<Length>>| {
         axes.resolve(styles).zip_map(region.size, Rel::relative_to).to_point()
     };
@@ -66,7 +100,7 @@ pub fn layout_path(
 
     let mut size = Size::zero();
     if points.is_empty() {
-        return Ok(Frame::soft(size));
+        return Ok(None);
     }
 
     // Only create a path if there are more than zero points.
@@ -118,26 +152,200 @@ pub fn layout_path(
         bail!(elem.span(), "cannot create path with infinite length");
     }
 
-    // Prepare fill and stroke.
-    let fill = elem.fill(styles);
-    let fill_rule = elem.fill_rule(styles);
-    let stroke = match elem.stroke(styles) {
-        Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
-        Smart::Auto => None,
-        Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
+    Ok(Some((path, size)))
+}
+
+/// Layout text along a path.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_text_on_path(
+    elem: &Packed<TextOnPathElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    region: Region,
+) -> SourceResult<Frame> {
+    let Some((path, size)) = build_path(&elem.path, styles, region)? else {
+        return Ok(Frame::soft(Size::zero()));
     };
 
+    // Lay out the text on a single line to obtain its glyphs.
+    let body = TextElem::packed(elem.text.clone()).spanned(elem.span());
+    let pod = Region::new(Size::splat(Abs::inf()), Axes::splat(false));
+    let line = crate::layout_frame(engine, &body, locator, styles, pod)?;
+    let mut items = vec![];
+    collect_text(&line, Point::zero(), &mut items);
+
+    let sampler = PathSampler::new(&path);
+    let start = match elem.align(styles).fix(TextElem::dir_in(styles)) {
+        FixedAlignment::Start => Abs::zero(),
+        FixedAlignment::Center => (sampler.length() - line.width()) / 2.0,
+        FixedAlignment::End => sampler.length() - line.width(),
+    } + elem.offset(styles).resolve(styles);
+
     let mut frame = Frame::soft(size);
-    let shape = Shape {
-        geometry: Geometry::Path(path),
-        stroke,
-        fill,
-        fill_rule,
-    };
-    frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+    set_on_path(&mut frame, &sampler, start, line.baseline(), &items);
     Ok(frame)
 }
 
+/// Place each glyph of the text items with the center of its advance on the
+/// path, rotated around that point to follow the path's direction.
+///
+/// The items are positioned as on a straight line, with the given baseline.
+/// Since glyphs are stored in visual order, right-to-left text ends up reading
+/// from the end of the path towards its start, just like on a straight line.
+fn set_on_path(
+    frame: &mut Frame,
+    sampler: &PathSampler,
+    start: Abs,
+    baseline: Abs,
+    items: &[(Point, &TextItem)],
+) {
+    for &(pos, item) in items {
+        let mut x = pos.x;
+        for glyph in &item.glyphs {
+            // The glyph's offset moves it along the path instead of away from
+            // the point where it is rotated. Its vertical offset is already
+            // part of the item's position.
+            let advance = glyph.x_advance.at(item.size);
+            let offset = glyph.x_offset.at(item.size);
+            let (point, angle) = sampler.at(start + x + offset + advance / 2.0);
+            let single = TextItem {
+                font: item.font.clone(),
+                size: item.size,
+                fill: item.fill.clone(),
+                stroke: item.stroke.clone(),
+                lang: item.lang,
+                region: item.region,
+                text: item.text.clone(),
+                glyphs: vec![Glyph { x_offset: Em::zero(), ..glyph.clone() }],
+            };
+
+            let mut piece = Frame::soft(Size::zero());
+            let shift = pos.y - baseline;
+            piece.push(Point::new(-advance / 2.0, shift), FrameItem::Text(single));
+            piece.transform(Transform::rotate(angle));
+            frame.push_frame(point, piece);
+            x += advance;
+        }
+    }
+}
+
+/// Collect the text items in a frame together with their positions.
+fn collect_text<'a>(
+    frame: &'a Frame,
+    offset: Point,
+    items: &mut Vec<(Point, &'a TextItem)>,
+) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => collect_text(&group.frame, offset + *pos, items),
+            FrameItem::Text(text) => items.push((offset + *pos, text)),
+            _ => {}
+        }
+    }
+}
+
+/// The accuracy (in points) with which arc lengths along a path are computed.
+const ARCLEN_ACCURACY: f64 = 1e-3;
+
+/// Finds points and directions along a path by their distance from its start.
+struct PathSampler {
+    /// The segments of the path together with their lengths.
+    segments: Vec<(kurbo::PathSeg, f64)>,
+}
+
+impl PathSampler {
+    /// Split a path into measured segments.
+    fn new(path: &Path) -> Self {
+        let convert =
+            |point: Point| kurbo::Point::new(point.x.to_raw(), point.y.to_raw());
+        let mut segments = vec![];
+        let mut start = kurbo::Point::ZERO;
+        let mut cursor = kurbo::Point::ZERO;
+        for item in &path.0 {
+            let segment = match item {
+                PathItem::MoveTo(to) => {
+                    start = convert(*to);
+                    cursor = start;
+                    continue;
+                }
+                PathItem::LineTo(to) => {
+                    kurbo::PathSeg::Line(kurbo::Line::new(cursor, convert(*to)))
+                }
+                PathItem::CubicTo(a, b, to) => kurbo::PathSeg::Cubic(
+                    kurbo::CubicBez::new(cursor, convert(*a), convert(*b), convert(*to)),
+                ),
+                PathItem::ClosePath if cursor != start => {
+                    kurbo::PathSeg::Line(kurbo::Line::new(cursor, start))
+                }
+                PathItem::ClosePath => continue,
+            };
+            cursor = segment.end();
+            segments.push((segment, segment.arclen(ARCLEN_ACCURACY)));
+        }
+        Self { segments }
+    }
+
+    /// The total length of the path.
+    fn length(&self) -> Abs {
+        Abs::raw(self.segments.iter().map(|(_, len)| len).sum())
+    }
+
+    /// The point at the given distance from the start of the path and the
+    /// direction of the path there.
+    ///
+    /// Beyond its ends, the path is extended in the direction of the ends.
+    fn at(&self, distance: Abs) -> (Point, Angle) {
+        let (Some((first, _)), Some((last, _))) =
+            (self.segments.first(), self.segments.last())
+        else {
+            return (Point::zero(), Angle::zero());
+        };
+
+        let mut remaining = distance.to_raw();
+        let (segment, t, beyond) = if remaining < 0.0 {
+            (first, 0.0, remaining)
+        } else {
+            let mut found = None;
+            for (segment, len) in &self.segments {
+                if remaining <= *len {
+                    found = Some((
+                        segment,
+                        segment.inv_arclen(remaining, ARCLEN_ACCURACY),
+                        0.0,
+                    ));
+                    break;
+                }
+                remaining -= len;
+            }
+            found.unwrap_or((last, 1.0, remaining))
+        };
+
+        let dir = direction(segment, t);
+        let point = segment.eval(t) + dir * beyond;
+        (Point::new(Abs::raw(point.x), Abs::raw(point.y)), Angle::rad(dir.y.atan2(dir.x)))
+    }
+}
+
+/// The normalized direction of a path segment at the parameter `t`.
+fn direction(segment: &kurbo::PathSeg, t: f64) -> kurbo::Vec2 {
+    let deriv = match segment {
+        kurbo::PathSeg::Line(line) => line.p1 - line.p0,
+        kurbo::PathSeg::Quad(quad) => quad.deriv().eval(t).to_vec2(),
+        kurbo::PathSeg::Cubic(cubic) => cubic.deriv().eval(t).to_vec2(),
+    };
+
+    // The derivative vanishes at the ends of curves whose control points
+    // coincide with their end points. Fall back to the chord then.
+    let deriv =
+        if deriv.hypot() > 1e-9 { deriv } else { segment.end() - segment.start() };
+    if deriv.hypot() > 1e-9 {
+        deriv.normalize()
+    } else {
+        kurbo::Vec2::new(1.0, 0.0)
+    }
+}
+
 /// Layout the polygon.
 #[typst_macros::time(span = elem.span())]
 pub fn layout_polygon(
@@ -210,6 +418,7 @@ pub fn layout_rect(
         elem.body(styles),
         elem.fill(styles),
         elem.stroke(styles),
+        elem.stroke_align(styles),
         elem.inset(styles),
         elem.outset(styles),
         elem.radius(styles),
@@ -235,6 +444,7 @@ pub fn layout_square(
         elem.body(styles),
         elem.fill(styles),
         elem.stroke(styles),
+        elem.stroke_align(styles),
         elem.inset(styles),
         elem.outset(styles),
         elem.radius(styles),
@@ -260,6 +470,7 @@ pub fn layout_ellipse(
         elem.body(styles),
         elem.fill(styles),
         elem.stroke(styles).map(|s| Sides::splat(Some(s))),
+        elem.stroke_align(styles),
         elem.inset(styles),
         elem.outset(styles),
         Corners::splat(None),
@@ -285,6 +496,7 @@ pub fn layout_circle(
         elem.body(styles),
         elem.fill(styles),
         elem.stroke(styles).map(|s| Sides::splat(Some(s))),
+        elem.stroke_align(styles),
         elem.inset(styles),
         elem.outset(styles),
         Corners::splat(None),
@@ -328,6 +540,7 @@ fn layout_shape(
     body: &Option<Content>,
     fill: Option<Paint>,
     stroke: Smart<Sides<Option<Option<Stroke<Abs>>>>>,
+    stroke_align: StrokeAlign,
     inset: Sides<Option<Rel<Abs>>>,
     outset: Sides<Option<Rel<Abs>>>,
     radius: Corners<Option<Rel<Abs>>>,
@@ -335,6 +548,9 @@ fn layout_shape(
 ) -> SourceResult<Frame> {
     let mut frame;
     if let Some(child) = body {
+        let local = fill_styles(fill.as_ref());
+        let styles = styles.chain(&local);
+
         let mut inset = inset.unwrap_or_default();
         if kind.is_round() {
             // Apply extra inset to round shapes.
@@ -403,33 +619,81 @@ fn layout_shape(
     };
 
     // Add fill and/or stroke.
-    if fill.is_some() || stroke.iter().any(Option::is_some) {
-        if kind.is_round() {
-            let outset = outset.unwrap_or_default().relative_to(frame.size());
-            let size = frame.size() + outset.sum_by_axis();
-            let pos = Point::new(-outset.left, -outset.top);
-            let shape = Shape {
-                geometry: Geometry::Path(Path::ellipse(size)),
-                fill,
-                stroke: stroke.left,
-                fill_rule: FillRule::default(),
-            };
-            frame.prepend(pos, FrameItem::Shape(shape, span));
-        } else {
-            fill_and_stroke(
-                &mut frame,
-                fill,
-                &stroke,
-                &outset.unwrap_or_default(),
-                &radius.unwrap_or_default(),
-                span,
-            );
-        }
-    }
+    let outset = outset.unwrap_or_default();
+    let radius = radius.unwrap_or_default();
+    paint_shape(&mut frame, kind, fill, &stroke, stroke_align, &outset, &radius, span);
 
     Ok(frame)
 }
 
+/// Prepends the fill and stroke of a shape to the frame.
+///
+/// The fill always covers the shape's (outset) bounds. For a stroke that isn't
+/// centered, only the stroke's path is moved such that the stroke is aligned
+/// as requested.
+#[allow(clippy::too_many_arguments)]
+fn paint_shape(
+    frame: &mut Frame,
+    kind: ShapeKind,
+    fill: Option<Paint>,
+    stroke: &Sides<Option<FixedStroke>>,
+    stroke_align: StrokeAlign,
+    outset: &Sides<Rel<Abs>>,
+    radius: &Corners<Rel<Abs>>,
+    span: Span,
+) {
+    let shift = stroke_align.shift();
+    if shift == 0.0 {
+        add_fill_and_stroke(frame, kind, fill, stroke, outset, radius, span);
+        return;
+    }
+
+    let thickness = |stroke: &Option<FixedStroke>| {
+        stroke.as_ref().map_or(Abs::zero(), |stroke| stroke.thickness)
+    };
+    let thicknesses = if kind.is_round() {
+        Sides::splat(thickness(&stroke.left))
+    } else {
+        stroke.as_ref().map(thickness)
+    };
+    let stroke_outset = outset
+        .zip(thicknesses)
+        .map(|(outset, thickness)| outset + (thickness * shift).into());
+
+    add_fill_and_stroke(frame, kind, None, stroke, &stroke_outset, radius, span);
+    add_fill_and_stroke(frame, kind, fill, &Sides::splat(None), outset, radius, span);
+}
+
+/// Prepends the fill and stroke of a shape of the given kind to the frame.
+fn add_fill_and_stroke(
+    frame: &mut Frame,
+    kind: ShapeKind,
+    fill: Option<Paint>,
+    stroke: &Sides<Option<FixedStroke>>,
+    outset: &Sides<Rel<Abs>>,
+    radius: &Corners<Rel<Abs>>,
+    span: Span,
+) {
+    if fill.is_none() && stroke.iter().all(Option::is_none) {
+        return;
+    }
+
+    if kind.is_round() {
+        let outset = outset.relative_to(frame.size());
+        let size = frame.size() + outset.sum_by_axis();
+        let pos = Point::new(-outset.left, -outset.top);
+        let shape = Shape {
+            geometry: Geometry::Path(Path::ellipse(size)),
+            fill,
+            stroke: stroke.left.clone(),
+            fill_rule: FillRule::default(),
+        };
+        frame.prepend(pos, FrameItem::Shape(shape, span));
+    } else {
+        fill_and_stroke(frame, fill, stroke, outset, radius, span);
+    }
+}
+
 /// Determines the forced size of a quadratic shape based on the region, if any.
 ///
 /// The size is forced if at least one axis is expanded because `expand` is
@@ -509,6 +773,17 @@ pub fn fill_and_stroke(
     );
 }
 
+/// The styles for laying out content on top of a fill.
+///
+/// A solid fill becomes the background against which an automatic text fill
+/// is chosen.
+pub fn fill_styles(fill: Option<&Paint>) -> Styles {
+    match fill {
+        Some(Paint::Solid(color)) => TextElem::set_background(Some(*color)).wrap().into(),
+        _ => Styles::new(),
+    }
+}
+
 /// Create a styled rectangle with shapes.
 /// - use rect primitive for simple rectangles
 /// - stroke sides if possible
@@ -1072,3 +1347,147 @@ fn bezier_arc_control(start: Point, center: Point, end: Point) -> [Point; 2] {
 
     [control_1, control_2]
 }
+
+#[cfg(test)]
+mod test {
+    use typst_library::foundations::Bytes;
+    use typst_library::text::{Font, Lang};
+    use typst_library::visualize::Color;
+
+    use super::*;
+
+    #[test]
+    fn test_stroke_align_keeps_fill_in_place() {
+        let size = Size::new(Abs::pt(20.0), Abs::pt(10.0));
+        let stroke =
+            Sides::splat(Some(FixedStroke::from_pair(Color::BLACK, Abs::pt(4.0))));
+        let shapes = |align| {
+            let mut frame = Frame::soft(size);
+            paint_shape(
+                &mut frame,
+                ShapeKind::Rect,
+                Some(Color::RED.into()),
+                &stroke,
+                align,
+                &Sides::splat(Rel::zero()),
+                &Corners::splat(Rel::zero()),
+                Span::detached(),
+            );
+            frame
+                .items()
+                .map(|(pos, item)| match item {
+                    FrameItem::Shape(shape, _) => (*pos, shape.clone()),
+                    _ => panic!("expected a shape"),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // A centered stroke is drawn together with the fill.
+        let center = shapes(StrokeAlign::Center);
+        assert_eq!(center.len(), 1);
+        assert_eq!(center[0].0, Point::zero());
+        assert_eq!(center[0].1.geometry, Geometry::Rect(size));
+
+        // Otherwise, the fill keeps the shape's bounds and only the stroke's
+        // path moves by half of its thickness.
+        let half = Abs::pt(2.0);
+        for (align, delta) in [(StrokeAlign::Inside, half), (StrokeAlign::Outside, -half)]
+        {
+            let shapes = shapes(align);
+            assert_eq!(shapes.len(), 2);
+
+            let (fill_pos, fill) = &shapes[0];
+            assert_eq!(*fill_pos, Point::zero());
+            assert_eq!(fill.geometry, Geometry::Rect(size));
+            assert!(fill.fill.is_some() && fill.stroke.is_none());
+
+            let (stroke_pos, stroke) = &shapes[1];
+            assert_eq!(*stroke_pos, Point::new(delta, delta));
+            assert_eq!(stroke.geometry, Geometry::Rect(size - Size::splat(delta * 2.0)));
+            assert!(stroke.fill.is_none() && stroke.stroke.is_some());
+        }
+    }
+
+    #[test]
+    fn test_path_sampler_follows_arc() {
+        // A semicircle with a radius of 50pt over the top, from left to right.
+        let pt = |x, y| Point::new(Abs::pt(x), Abs::pt(y));
+        let k = 27.6;
+        let mut path = Path::new();
+        path.move_to(pt(0.0, 50.0));
+        path.cubic_to(pt(0.0, 50.0 - k), pt(50.0 - k, 0.0), pt(50.0, 0.0));
+        path.cubic_to(pt(50.0 + k, 0.0), pt(100.0, 50.0 - k), pt(100.0, 50.0));
+
+        let sampler = PathSampler::new(&path);
+        let length = sampler.length();
+        assert!((length.to_pt() - 50.0 * std::f64::consts::PI).abs() < 0.5);
+
+        let (start, up) = sampler.at(Abs::zero());
+        assert!(start.x.to_pt().abs() < 1e-6 && (start.y.to_pt() - 50.0).abs() < 1e-6);
+        assert!((up.to_deg() + 90.0).abs() < 1.0);
+
+        let (top, flat) = sampler.at(length / 2.0);
+        assert!((top.x.to_pt() - 50.0).abs() < 0.1 && top.y.to_pt().abs() < 0.1);
+        assert!(flat.to_deg().abs() < 1.0);
+
+        let (_, down) = sampler.at(length);
+        assert!((down.to_deg() - 90.0).abs() < 1.0);
+
+        // Beyond the end, the path continues straight down.
+        let (below, _) = sampler.at(length + Abs::pt(10.0));
+        assert!((below.x.to_pt() - 100.0).abs() < 1e-3);
+        assert!((below.y.to_pt() - 60.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_set_on_path_moves_offset_glyphs_along_the_path() {
+        let font = typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .next()
+            .unwrap();
+        let glyph = |x_offset| Glyph {
+            id: 1,
+            x_advance: Em::new(0.5),
+            x_offset: Em::new(x_offset),
+            range: 0..1,
+            span: (Span::detached(), 0),
+        };
+        let item = TextItem {
+            font,
+            size: Abs::pt(10.0),
+            fill: Color::BLACK.into(),
+            stroke: None,
+            lang: Lang::ENGLISH,
+            region: None,
+            text: "ab".into(),
+            glyphs: vec![glyph(0.0), glyph(0.2)],
+        };
+
+        let mut path = Path::new();
+        path.move_to(Point::zero());
+        path.line_to(Point::with_x(Abs::pt(100.0)));
+        let sampler = PathSampler::new(&path);
+
+        let mut frame = Frame::soft(Size::zero());
+        let pos = Point::new(Abs::pt(10.0), Abs::pt(8.0));
+        set_on_path(&mut frame, &sampler, Abs::pt(5.0), Abs::pt(8.0), &[(pos, &item)]);
+
+        // Each glyph is centered on its advance, the second one shifted by its
+        // offset, which is not applied a second time when drawing it.
+        let placed: Vec<_> = frame
+            .items()
+            .map(|(point, item)| {
+                let FrameItem::Group(group) = item else { panic!() };
+                let (_, FrameItem::Text(text)) = group.frame.items().next().unwrap()
+                else {
+                    panic!()
+                };
+                (point.x.to_pt(), point.y.to_pt(), text.glyphs[0].x_offset)
+            })
+            .collect();
+        assert_eq!(placed.len(), 2);
+        assert!((placed[0].0 - 17.5).abs() < 1e-3 && placed[0].1.abs() < 1e-3);
+        assert!((placed[1].0 - 24.5).abs() < 1e-3 && placed[1].1.abs() < 1e-3);
+        assert!(placed.iter().all(|&(_, _, offset)| offset == Em::zero()));
+    }
+}
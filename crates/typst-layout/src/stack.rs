@@ -4,7 +4,7 @@ use typst_library::foundations::{Content, Packed, Resolve, StyleChain, StyledEle
 use typst_library::introspection::{Locator, SplitLocator};
 use typst_library::layout::{
     Abs, AlignElem, Axes, Axis, Dir, FixedAlignment, Fr, Fragment, Frame, HElem, Point,
-    Regions, Size, Spacing, StackChild, StackElem, VElem,
+    Region, Regions, Size, Spacing, StackChild, StackElem, VElem,
 };
 use typst_syntax::Span;
 use typst_utils::{Get, Numeric};
@@ -18,8 +18,16 @@ pub fn layout_stack(
     styles: StyleChain,
     regions: Regions,
 ) -> SourceResult<Fragment> {
-    let mut layouter =
-        StackLayouter::new(elem.span(), elem.dir(styles), locator, styles, regions);
+    let dir = elem.dir(styles);
+    let uniform = if elem.uniform(styles) {
+        let cross = dir.axis().other();
+        Some(measure_largest(engine, elem.children(), &locator, styles, regions, cross)?)
+    } else {
+        None
+    };
+
+    let mut layouter = StackLayouter::new(elem.span(), dir, locator, styles, regions);
+    layouter.uniform = uniform;
 
     let axis = layouter.dir.axis();
 
@@ -61,6 +69,30 @@ pub fn layout_stack(
     layouter.finish()
 }
 
+/// Determine the largest natural size of the stack's blocks along the given
+/// axis.
+fn measure_largest(
+    engine: &mut Engine,
+    children: &[StackChild],
+    locator: &Locator,
+    styles: StyleChain,
+    regions: Regions,
+    axis: Axis,
+) -> SourceResult<Abs> {
+    let pod = Region::new(regions.base(), Axes::splat(false));
+    let mut largest = Abs::zero();
+    for child in children {
+        let StackChild::Block(block) = child else { continue };
+        if block.is::<HElem>() || block.is::<VElem>() {
+            continue;
+        }
+
+        let frame = crate::layout_frame(engine, block, locator.relayout(), styles, pod)?;
+        largest.set_max(frame.size().get(axis));
+    }
+    Ok(largest)
+}
+
 /// Performs stack layout.
 struct StackLayouter<'a> {
     /// The span to raise errors at during layout.
@@ -88,6 +120,9 @@ struct StackLayouter<'a> {
     items: Vec<StackItem>,
     /// Finished frames for previous regions.
     finished: Vec<Frame>,
+    /// The size along the cross axis to lay out all blocks at, if the stack is
+    /// uniform.
+    uniform: Option<Abs>,
 }
 
 /// A prepared item in a stack layout.
@@ -128,6 +163,7 @@ impl<'a> StackLayouter<'a> {
             fr: Fr::zero(),
             items: vec![],
             finished: vec![],
+            uniform: None,
         }
     }
 
@@ -175,12 +211,19 @@ impl<'a> StackLayouter<'a> {
         }
         .resolve(styles);
 
+        let mut pod = self.regions;
+        if let Some(size) = self.uniform {
+            let cross = self.dir.axis().other();
+            pod.size.set(cross, size);
+            pod.expand.set(cross, true);
+        }
+
         let fragment = crate::layout_fragment(
             engine,
             block,
             self.locator.next(&block.span()),
             styles,
-            self.regions,
+            pod,
         )?;
 
         let len = fragment.len();
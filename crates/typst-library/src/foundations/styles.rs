@@ -7,7 +7,7 @@ use comemo::{Track, Tracked};
 use ecow::{eco_vec, EcoString, EcoVec};
 use smallvec::SmallVec;
 use typst_syntax::Span;
-use typst_utils::LazyHash;
+use typst_utils::{hash128, LazyHash};
 
 use crate::diag::{warning, SourceResult, Trace, Tracepoint};
 use crate::engine::Engine;
@@ -289,6 +289,17 @@ impl Style {
         }
     }
 
+    /// Whether this style has the same effect as another one, regardless of
+    /// the rule it stems from.
+    pub fn equivalent(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Property(a), Self::Property(b)) => a.equivalent(b),
+            (Self::Recipe(a), Self::Recipe(b)) => hash128(a) == hash128(b),
+            (Self::Revocation(a), Self::Revocation(b)) => a == b,
+            _ => false,
+        }
+    }
+
     /// Turn this style into prehashed style.
     pub fn wrap(self) -> LazyHash<Style> {
         LazyHash::new(self)
@@ -361,6 +372,15 @@ impl Property {
         self.elem == elem
     }
 
+    /// Whether this property sets the same value for the same field as
+    /// another one. Unlike equality, this disregards the span of the set rule.
+    pub fn equivalent(&self, other: &Self) -> bool {
+        self.is(other.elem, other.id)
+            && self.liftable == other.liftable
+            && self.outside == other.outside
+            && hash128(&self.value) == hash128(&other.value)
+    }
+
     /// Turn this property into prehashed style.
     pub fn wrap(self) -> LazyHash<Style> {
         Style::Property(self).wrap()
@@ -668,6 +688,54 @@ impl<'a> StyleChain<'a> {
         )
     }
 
+    /// Whether this chain contains the same styles as another one.
+    ///
+    /// Unlike `==`, which checks whether both chains are made up of the very
+    /// same links, this compares the individual styles and disregards which
+    /// rules they stem from. This is more expensive, but detects that text
+    /// styled by separate, but identical rules looks the same.
+    ///
+    /// Links shared by both chains are skipped by pointer and styles are
+    /// compared by their cached hash first, so that the uncached comparison
+    /// of values only runs for styles that actually differ.
+    pub fn equivalent(self, other: Self) -> bool {
+        if self == other {
+            return true;
+        }
+
+        let mut a = self.links();
+        let mut b = other.links();
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return true,
+                (Some(x), Some(y)) if ptr::eq(x, y) => {}
+                (Some(x), Some(y)) if x.len() == y.len() => {
+                    if !x.iter().zip(y).all(|(s, t)| Self::same_style(s, t)) {
+                        return false;
+                    }
+                }
+                // The chains are linked differently, so compare them entry by
+                // entry instead.
+                _ => break,
+            }
+        }
+
+        let mut a = self.entries();
+        let mut b = other.entries();
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return true,
+                (Some(x), Some(y)) if Self::same_style(x, y) => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Whether two styles have the same effect, trying the cheap checks first.
+    fn same_style(a: &LazyHash<Style>, b: &LazyHash<Style>) -> bool {
+        ptr::eq(a, b) || a == b || a.equivalent(b)
+    }
+
     /// Iterate over the entries of the chain.
     pub fn entries(self) -> Entries<'a> {
         Entries { inner: [].as_slice().iter(), links: self.links() }
@@ -4,15 +4,16 @@ use std::hash::Hash;
 use std::num::NonZeroUsize;
 use std::sync::RwLock;
 
-use ecow::EcoVec;
+use ecow::{eco_vec, EcoVec};
 use smallvec::SmallVec;
+use typst_syntax::Span;
 use typst_utils::NonZeroExt;
 
-use crate::diag::{bail, StrResult};
+use crate::diag::{bail, error, At, SourceResult, StrResult};
 use crate::foundations::{Content, Label, Repr, Selector};
 use crate::html::{HtmlElement, HtmlNode};
 use crate::introspection::{Location, Tag};
-use crate::layout::{Frame, FrameItem, Page, Point, Position, Transform};
+use crate::layout::{Frame, FrameItem, Page, Point, Position, Size, Transform};
 use crate::model::Numbering;
 
 /// Can be queried for elements and their positions.
@@ -24,6 +25,8 @@ pub struct Introspector {
     page_numberings: Vec<Option<Numbering>>,
     /// The page supplements, indexed by page number minus 1.
     page_supplements: Vec<Content>,
+    /// The page sizes, indexed by page number minus 1.
+    page_sizes: Vec<Size>,
 
     /// All introspectable elements.
     elems: Vec<Pair>,
@@ -35,6 +38,8 @@ pub struct Introspector {
     locations: HashMap<Location, usize>,
     /// Accelerates lookup of elements by label.
     labels: MultiMap<Label, usize>,
+    /// The pages on which elements end.
+    ends: HashMap<Location, NonZeroUsize>,
 
     /// Caches queries done on the introspector. This is important because
     /// even if all top-level queries are distinct, they often have shared
@@ -236,6 +241,35 @@ impl Introspector {
         }
     }
 
+    /// Query for a unique element with the label, failing with an error at
+    /// the given span.
+    ///
+    /// If the label occurs multiple times, the error is accompanied by one
+    /// error for each element that carries the label.
+    pub fn query_label_at(&self, label: Label, span: Span) -> SourceResult<&Content> {
+        let indices = self.labels.get(&label);
+        if indices.len() <= 1 {
+            return self.query_label(label).at(span);
+        }
+
+        let mut errors = eco_vec![error!(
+            span,
+            "label `{}` occurs multiple times in the document",
+            label.repr()
+        )];
+        for &idx in indices {
+            let elem = self.get_by_idx(idx);
+            if !elem.span().is_detached() {
+                errors.push(error!(
+                    elem.span(),
+                    "label `{}` is attached here",
+                    label.repr()
+                ));
+            }
+        }
+        Err(errors)
+    }
+
     /// This is an optimized version of
     /// `query(selector.before(end, true).len()` used by counters and state.
     pub fn query_count_before(&self, selector: &Selector, end: Location) -> usize {
@@ -251,6 +285,14 @@ impl Introspector {
         }
     }
 
+    /// Query for all matching elements on the given page.
+    pub fn query_page(&self, selector: &Selector, page: NonZeroUsize) -> EcoVec<Content> {
+        self.query(selector)
+            .into_iter()
+            .filter(|elem| elem.location().is_some_and(|loc| self.page(loc) == page))
+            .collect()
+    }
+
     /// The total number pages.
     pub fn pages(&self) -> NonZeroUsize {
         NonZeroUsize::new(self.pages).unwrap_or(NonZeroUsize::ONE)
@@ -267,6 +309,21 @@ impl Introspector {
             .unwrap_or(Position { page: NonZeroUsize::ONE, point: Point::zero() })
     }
 
+    /// Find the page on which the element at the given location ends. For
+    /// elements that don't break across pages, this is the same as
+    /// [`page`](Self::page).
+    pub fn end_page(&self, location: Location) -> NonZeroUsize {
+        self.ends
+            .get(&location)
+            .copied()
+            .unwrap_or_else(|| self.page(location))
+    }
+
+    /// The size of the given page, or zero if there is no such page.
+    pub fn page_size(&self, page: NonZeroUsize) -> Size {
+        self.page_sizes.get(page.get() - 1).copied().unwrap_or_default()
+    }
+
     /// Gets the page numbering for the given location, if any.
     pub fn page_numbering(&self, location: Location) -> Option<&Numbering> {
         let page = self.page(location);
@@ -356,11 +413,13 @@ struct IntrospectorBuilder {
     pages: usize,
     page_numberings: Vec<Option<Numbering>>,
     page_supplements: Vec<Content>,
+    page_sizes: Vec<Size>,
     seen: HashSet<Location>,
     insertions: MultiMap<Location, Vec<Pair>>,
     keys: MultiMap<u128, Location>,
     locations: HashMap<Location, usize>,
     labels: MultiMap<Label, usize>,
+    ends: HashMap<Location, NonZeroUsize>,
 }
 
 impl IntrospectorBuilder {
@@ -374,12 +433,14 @@ impl IntrospectorBuilder {
         self.pages = pages.len();
         self.page_numberings.reserve(pages.len());
         self.page_supplements.reserve(pages.len());
+        self.page_sizes.reserve(pages.len());
 
         // Discover all elements.
         let mut elems = Vec::new();
         for (i, page) in pages.iter().enumerate() {
             self.page_numberings.push(page.numbering.clone());
             self.page_supplements.push(page.supplement.clone());
+            self.page_sizes.push(page.frame.size());
             self.discover_in_frame(
                 &mut elems,
                 &page.frame,
@@ -465,6 +526,7 @@ impl IntrospectorBuilder {
             }
             Tag::End(loc, key) => {
                 self.keys.insert(*key, *loc);
+                self.ends.insert(*loc, position.page);
             }
         }
     }
@@ -484,10 +546,12 @@ impl IntrospectorBuilder {
             pages: self.pages,
             page_numberings: self.page_numberings,
             page_supplements: self.page_supplements,
+            page_sizes: self.page_sizes,
             elems,
             keys: self.keys,
             locations: self.locations,
             labels: self.labels,
+            ends: self.ends,
             queries: QueryCache::default(),
         }
     }
@@ -518,3 +582,46 @@ impl IntrospectorBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::{NativeElement, Smart, Value};
+    use crate::introspection::MetadataElem;
+
+    #[test]
+    fn test_end_page() {
+        let page = |tags: Vec<Tag>| {
+            let mut frame = Frame::soft(Size::zero());
+            for tag in tags {
+                frame.push(Point::zero(), FrameItem::Tag(tag));
+            }
+            Page {
+                frame,
+                fill: Smart::Auto,
+                numbering: None,
+                supplement: Content::empty(),
+                number: 1,
+            }
+        };
+
+        let elem = |hash| {
+            let mut elem = MetadataElem::new(Value::None).pack();
+            elem.set_location(Location::new(hash));
+            elem
+        };
+
+        // The first element breaks across three pages, the second one doesn't.
+        let (broken, single) = (Location::new(1), Location::new(2));
+        let introspector = Introspector::paged(&[
+            page(vec![Tag::Start(elem(1))]),
+            page(vec![]),
+            page(vec![Tag::End(broken, 0), Tag::Start(elem(2)), Tag::End(single, 0)]),
+        ]);
+
+        assert_eq!(introspector.page(broken).get(), 1);
+        assert_eq!(introspector.end_page(broken).get(), 3);
+        assert_eq!(introspector.page(single).get(), 3);
+        assert_eq!(introspector.end_page(single).get(), 3);
+    }
+}
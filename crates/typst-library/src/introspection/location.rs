@@ -5,7 +5,9 @@ use ecow::EcoString;
 
 use crate::engine::Engine;
 use crate::foundations::{func, scope, ty, Repr};
-use crate::layout::Position;
+use crate::layout::{
+    Abs, Alignment, Dir, FixedAlignment, HAlignment, Position, VAlignment,
+};
 use crate::model::Numbering;
 
 /// Identifies an element in the document.
@@ -69,15 +71,69 @@ impl Location {
         engine.introspector.page(self)
     }
 
+    /// Returns the page number on which the element at this location ends.
+    ///
+    /// For elements that break across pages, like long tables, this is the
+    /// last page they occupy. Otherwise, it is the same as `page()`. Together,
+    /// the two make it possible to check in a header or footer whether an
+    /// element continues onto the current page.
+    ///
+    /// ```example
+    /// >>> #set page(height: 120pt)
+    /// #set page(footer: context {
+    ///   let table = locate(<long>)
+    ///   let page = here().page()
+    ///   if table.page() < page and page <= table.end-page() [
+    ///     Table continued
+    ///   ]
+    /// })
+    ///
+    /// #table(
+    ///   columns: 2,
+    ///   ..range(16).map(str),
+    /// ) <long>
+    /// ```
+    #[func]
+    pub fn end_page(self, engine: &mut Engine) -> NonZeroUsize {
+        engine.introspector.end_page(self)
+    }
+
     /// Returns a dictionary with the page number and the x, y position for this
     /// location. The page number starts at one and the coordinates are measured
-    /// from the top-left of the page.
+    /// from the top-left of the page by default.
     ///
     /// If you only need the page number, use `page()` instead as it allows
     /// Typst to skip unnecessary work.
+    ///
+    /// ```example
+    /// #set page(height: 60pt)
+    /// #context [
+    ///   From the top: #here().position().y \
+    ///   From the bottom:
+    ///   #here().position(origin: bottom + left).y
+    /// ]
+    /// ```
     #[func]
-    pub fn position(self, engine: &mut Engine) -> Position {
-        engine.introspector.position(self)
+    pub fn position(
+        self,
+        engine: &mut Engine,
+        /// The point on the page from which the coordinates are measured.
+        ///
+        /// With a `bottom` origin, `y` is the distance from the bottom edge of
+        /// the page, growing upwards. Similarly, with a `right` origin, `x` is
+        /// the distance from the right edge of the page. With a `center` or
+        /// `horizon` origin, the coordinates are measured from the center of
+        /// the page. `start` and `end` are treated like `left` and `right`.
+        #[named]
+        #[default(Alignment::Both(HAlignment::Left, VAlignment::Top))]
+        origin: Alignment,
+    ) -> Position {
+        let mut position = engine.introspector.position(self);
+        let size = engine.introspector.page_size(position.page);
+        let origin = origin.fix(Dir::LTR);
+        position.point.x = measure_from(origin.x, position.point.x, size.x);
+        position.point.y = measure_from(origin.y, position.point.y, size.y);
+        position
     }
 
     /// Returns the page numbering pattern of the page at this location. This
@@ -105,6 +161,15 @@ impl Repr for Location {
     }
 }
 
+/// Converts a coordinate measured from the start of a page's axis into one
+/// measured from the given origin on that axis.
+fn measure_from(origin: FixedAlignment, v: Abs, extent: Abs) -> Abs {
+    match origin {
+        FixedAlignment::End => extent - v,
+        _ => v - origin.position(extent),
+    }
+}
+
 /// Makes this element as locatable through the introspector.
 pub trait Locatable {}
 
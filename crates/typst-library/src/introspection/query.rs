@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use comemo::Tracked;
 use typst_syntax::Span;
 
@@ -70,6 +72,26 @@ use crate::introspection::Location;
 /// counter]($counter/#page-counter) at that location and apply the numbering to
 /// the counter.
 ///
+/// # Finding elements on the current page
+/// With the `page` argument, a query only returns elements on a specific page.
+/// Combined with [`here`], this lets page-level content like a header or a
+/// placed sidebar list what appears on its own page.
+///
+/// ```example
+/// #set page(
+///   height: 120pt,
+///   header: context {
+///     let figs = query(figure, page: here().page())
+///     set text(8pt)
+///     [Figures: #figs.len()]
+///   },
+/// )
+///
+/// #figure(rect(height: 20pt), caption: [A])
+/// #figure(rect(height: 20pt), caption: [B])
+/// #figure(rect(height: 20pt), caption: [C])
+/// ```
+///
 /// # A word of caution { #caution }
 /// To resolve all your queries, Typst evaluates and layouts parts of the
 /// document multiple times. However, there is no guarantee that your queries
@@ -156,6 +178,9 @@ pub fn query(
     /// compatibility with Typst 0.10 and lower and shouldn't be used anymore.
     #[default]
     location: Option<Location>,
+    /// If given, only elements on this page are returned.
+    #[named]
+    page: Option<NonZeroUsize>,
 ) -> HintedStrResult<Array> {
     if location.is_none() {
         context.introspect()?;
@@ -166,6 +191,9 @@ pub fn query(
         ));
     }
 
-    let vec = engine.introspector.query(&target.0);
+    let vec = match page {
+        Some(page) => engine.introspector.query_page(&target.0, page),
+        None => engine.introspector.query(&target.0),
+    };
     Ok(vec.into_iter().map(Value::Content).collect())
 }
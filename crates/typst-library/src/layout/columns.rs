@@ -8,10 +8,10 @@ use crate::layout::{BlockElem, Length, Ratio, Rel};
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function lets you separate the interior of any container into
-/// multiple columns. It will currently not balance the height of the columns.
-/// Instead, the columns will take up the height of their container or the
-/// remaining height on the page. Support for balanced columns is planned for
-/// the future.
+/// multiple columns. By default, the columns are filled one after another and
+/// take up the height of their container or the remaining height on the page.
+/// To distribute the content evenly across the columns instead, enable
+/// [balancing]($columns.balance).
 ///
 /// # Page-level columns { #page-level }
 /// If you need to insert columns across your whole document, use the `{page}`
@@ -53,6 +53,20 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the height of the columns.
+    ///
+    /// When enabled, the content is distributed as evenly as possible across
+    /// all columns, regardless of where it would naturally break. Unbreakable
+    /// blocks are kept intact, so the columns may still differ slightly in
+    /// height. Balancing only takes place if all content fits into the
+    /// current region. Otherwise, the columns are filled as usual.
+    ///
+    /// ```example
+    /// #columns(3, balance: true, lorem(30))
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
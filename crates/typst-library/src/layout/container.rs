@@ -1,14 +1,15 @@
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Args, AutoValue, Construct, Content, NativeElement, Packed, Smart,
+    cast, elem, Args, AutoValue, Cast, Construct, Content, NativeElement, Packed, Smart,
     StyleChain, Value,
 };
 use crate::introspection::Locator;
 use crate::layout::{
-    Abs, Corners, Em, Fr, Fragment, Frame, Length, Region, Regions, Rel, Sides, Size,
-    Spacing,
+    Abs, Corners, Em, Fr, FrOrder, Fragment, Frame, Length, Region, Regions, Rel, Sides,
+    Size, Spacing,
 };
+use crate::model::SpacingMode;
 use crate::visualize::{Paint, Stroke};
 
 /// An inline-level container that sizes content.
@@ -117,6 +118,47 @@ pub struct BoxElem {
     #[default(false)]
     pub clip: bool,
 
+    /// Whether to shrink the text in the box until its content fits.
+    ///
+    /// When enabled, the text size is reduced just enough for the content to
+    /// fit into the box's width without wrapping and, if the box has a fixed
+    /// height, into its height. Text is never enlarged and never shrunk below
+    /// the [`fit-min`]($box.fit-min) size. This only has an effect if the box
+    /// has a fixed width.
+    ///
+    /// ```example
+    /// #box(width: 3cm, fit: true, stroke: 0.5pt)[
+    ///   An exceptionally long label
+    /// ]
+    /// ```
+    #[default(false)]
+    pub fit: bool,
+
+    /// The smallest text size to shrink to when [fitting]($box.fit) the
+    /// content. If the content does not fit even at this size, it wraps or
+    /// overflows as usual.
+    #[resolve]
+    #[default(Abs::pt(4.0).into())]
+    pub fit_min: Length,
+
+    /// How to handle text that is too wide for the box.
+    ///
+    /// - `{none}`: The text wraps as usual.
+    /// - `{"clip"}`: The text is laid out on a single line and cut off at the
+    ///   box's edge.
+    /// - `{"ellipsis"}`: The text is laid out on a single line and ends with an
+    ///   ellipsis after the last glyph that still fits. Styling within the body
+    ///   is kept.
+    ///
+    /// This only has an effect if the box has a fixed width.
+    ///
+    /// ```example
+    /// #box(width: 2cm, text-overflow: "ellipsis")[
+    ///   A very *long* name
+    /// ]
+    /// ```
+    pub text_overflow: Option<TextOverflow>,
+
     /// The contents of the box.
     #[positional]
     #[borrowed]
@@ -197,6 +239,21 @@ pub enum InlineItem {
 /// )
 /// ```
 ///
+/// The fill and stroke are applied to each fragment of a broken block
+/// separately. This makes blocks a good basis for callout boxes: An accent bar
+/// on the left side is repeated on every page the callout spans.
+/// ```example
+/// #set page(height: 100pt)
+/// #let note(body) = block(
+///   fill: blue.lighten(90%),
+///   stroke: (left: 3pt + blue),
+///   inset: 8pt,
+///   [*Note:* #body],
+/// )
+///
+/// #note(lorem(30))
+/// ```
+///
 /// Blocks are also useful to force elements that would otherwise be inline to
 /// become block-level, especially when writing show rules.
 /// ```example
@@ -236,6 +293,17 @@ pub struct BlockElem {
     ///   fill: aqua,
     /// )
     /// ```
+    ///
+    /// A [fractional]($fraction) height makes the block take up the space that
+    /// remains in the region once all other content was laid out. This is
+    /// useful for backgrounds that should reach the bottom of the page no
+    /// matter how much content precedes them.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// Intro
+    /// #block(height: 1fr, width: 100%, fill: aqua)[Sidebar]
+    /// ```
     pub height: Sizing,
 
     /// Whether the block can be broken and continue on the next page.
@@ -252,6 +320,63 @@ pub struct BlockElem {
     #[default(true)]
     pub breakable: bool,
 
+    /// Whether to move a breakable block to the next page if it doesn't fit
+    /// into the remaining space on the current one, but would fit wholly onto
+    /// the next.
+    ///
+    /// Unlike with `{breakable: false}`, a block that doesn't fit onto an
+    /// empty page either still breaks normally. The property has no effect on
+    /// unbreakable blocks, which are always moved as a whole.
+    ///
+    /// ```example
+    /// #set page(height: 100pt)
+    /// #lorem(10)
+    /// #block(keep-together: true, lorem(15))
+    /// ```
+    #[default(false)]
+    pub keep_together: bool,
+
+    /// Whether the edges at which a broken block is split stay open.
+    ///
+    /// When a block with a fill or stroke breaks across regions, each of its
+    /// fragments is decorated on its own. By default, every fragment gets a
+    /// complete border. When this is enabled, the border is omitted and the
+    /// corners are not rounded at the edges where the block was split, so that
+    /// the fragments visibly continue one another.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #block(
+    ///   open-breaks: true,
+    ///   stroke: 1pt,
+    ///   radius: 4pt,
+    ///   inset: 6pt,
+    ///   lorem(20),
+    /// )
+    /// ```
+    #[default(false)]
+    pub open_breaks: bool,
+
+    /// What to do when the content of a breakable block does not fit into
+    /// the regions available to it.
+    ///
+    /// A breakable block with a fixed height only has a limited number of
+    /// regions into which its content can flow. By default, additional
+    /// fragments are created for the content that does not fit. With
+    /// `{"clip"}`, that content is dropped instead. With `{"error"}`, an
+    /// error is raised, which is useful to ensure that content designed to
+    /// fit into a fixed area never silently overflows it.
+    ///
+    /// ```example
+    /// #block(
+    ///   height: 2em,
+    ///   overflow: "clip",
+    ///   lorem(20),
+    /// )
+    /// ```
+    #[default(Overflow::Grow)]
+    pub overflow: Overflow,
+
     /// The block's background color. See the
     /// [rectangle's documentation]($rect.fill) for more details.
     pub fill: Option<Paint>,
@@ -315,9 +440,51 @@ pub struct BlockElem {
     pub above: Smart<Spacing>,
 
     /// The spacing between this block and its successor.
+    ///
+    /// Since the gap between two blocks is determined by both the first
+    /// block's `below` and the second block's `above` spacing, the two can be
+    /// combined to space different pairs of elements differently. Here, a list
+    /// is further away from a preceding heading than from a preceding
+    /// paragraph:
+    ///
+    /// ```example
+    /// #show heading: set block(below: 1.5em)
+    /// #show list: set block(above: 0.5em)
+    ///
+    /// = Ingredients
+    /// - Flour
+    /// - Sugar
+    ///
+    /// Optionally, add:
+    /// - Cinnamon
+    /// ```
     #[parse(args.named("below")?.or(spacing))]
     pub below: Smart<Spacing>,
 
+    /// How the spacing [above]($block.above) and [below]($block.below) the
+    /// block is measured.
+    ///
+    /// In `{"exact"}` mode, the spacing is the distance between the baseline
+    /// of the last line before the gap and the baseline of the first line
+    /// after it, regardless of the font sizes on both sides. This keeps a
+    /// consistent rhythm between headings and body text. If the content on
+    /// either side has no baseline, the spacing is measured between the edges
+    /// as usual.
+    ///
+    /// ```example
+    /// #show heading: set block(
+    ///   below: 1.5em,
+    ///   spacing-mode: "exact",
+    /// )
+    ///
+    /// = Introduction
+    /// The baseline of this line is
+    /// exactly 1.5em below the one of
+    /// the heading.
+    /// ```
+    #[default(SpacingMode::Edges)]
+    pub spacing_mode: SpacingMode,
+
     /// Whether to clip the content inside the block.
     ///
     /// Clipping is useful when the block's content is larger than the block itself,
@@ -352,6 +519,81 @@ pub struct BlockElem {
     #[default(false)]
     pub sticky: bool,
 
+    /// How fractional spacing in the block's body is distributed relative to
+    /// the vertically [aligned]($align) blocks around it.
+    ///
+    /// - `{"before-align"}`: The fractional spacing consumes all remaining
+    ///   space in the region, so there is no free space left for aligned
+    ///   blocks to move into. A centered block after `{v(1fr)}` thus ends up
+    ///   at the bottom of the region.
+    /// - `{"after-align"}`: The blocks are first aligned in the remaining
+    ///   space. The fractional spacing then fills the gaps that the alignment
+    ///   leaves between them. A centered block after `{v(1fr)}` thus stays
+    ///   centered.
+    ///
+    /// In both modes, a bottom-aligned block after `{v(1fr)}` ends up at the
+    /// bottom of the region, without being offset twice. To configure this for
+    /// the page itself, use a `{set block(..)}` rule.
+    ///
+    /// ```example
+    /// #let demo(order) = block(
+    ///   fr-order: order,
+    ///   height: 3cm,
+    ///   stroke: 0.5pt,
+    ///   width: 100%,
+    /// )[
+    ///   Top
+    ///   #v(1fr)
+    ///   #align(horizon)[Middle]
+    /// ]
+    /// #grid(
+    ///   columns: (1fr, 1fr),
+    ///   gutter: 8pt,
+    ///   demo("before-align"), demo("after-align"),
+    /// )
+    /// ```
+    #[default(FrOrder::BeforeAlign)]
+    pub fr_order: FrOrder,
+
+    /// A baseline grid to align the block and the surrounding lines to.
+    ///
+    /// When set to a length, the baselines of paragraph lines are moved down
+    /// to the next multiple of this length, measured from the top of the
+    /// page's content area. Blocks are moved down such that their top edge
+    /// lands on the grid. Setting this to `{none}` for an individual block,
+    /// like a figure, places it directly after the preceding content instead.
+    /// The lines following it are aligned to the grid again.
+    ///
+    /// Only content directly on the page is aligned to the grid. The contents
+    /// of nested containers like blocks, boxes, and columns are laid out as
+    /// usual since these don't start at a grid line. Setting this property on
+    /// a block inside of such a container thus has no effect. For a block
+    /// directly on the page, it only determines whether the block itself is
+    /// moved onto the grid, not how its contents are laid out.
+    ///
+    /// ```example
+    /// #set block(grid: 12pt)
+    /// #lorem(8)
+    /// #block(grid: none, fill: aqua, height: 7pt, width: 100%)
+    /// #lorem(8)
+    /// ```
+    #[resolve]
+    pub grid: Option<Length>,
+
+    /// Whether to warn about blocks that fall off the baseline grid.
+    ///
+    /// This is a diagnostic aid for the vertical rhythm of a document. When
+    /// enabled, each block on a [baseline grid]($block.grid) whose bottom edge
+    /// doesn't land on the grid raises a warning. The warning states by how
+    /// much the block deviates from the grid and how much space was absorbed
+    /// before the following content to bring it back onto the grid.
+    ///
+    /// ```typ
+    /// #set block(grid: 12pt, grid-report: true)
+    /// ```
+    #[default(false)]
+    pub grid_report: bool,
+
     /// The contents of the block.
     #[positional]
     #[borrowed]
@@ -425,6 +667,26 @@ cast! {
     v: Content => Self::Content(v),
 }
 
+/// How a box handles text that is too wide for it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum TextOverflow {
+    /// The text is cut off at the box's edge.
+    Clip,
+    /// The text is truncated and ends with an ellipsis.
+    Ellipsis,
+}
+
+/// How a breakable block handles content that exceeds its regions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Overflow {
+    /// Additional fragments are created for the remaining content.
+    Grow,
+    /// An error is raised.
+    Error,
+    /// The remaining content is dropped.
+    Clip,
+}
+
 /// Defines how to size something along an axis.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Sizing {
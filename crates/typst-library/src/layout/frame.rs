@@ -126,6 +126,29 @@ impl Frame {
         self.baseline = Some(baseline);
     }
 
+    /// The positions of the first and last baseline in the frame, if it has
+    /// any.
+    ///
+    /// Frames without their own baseline (like those of blocks) are searched
+    /// for nested frames with baselines, such as the lines of a paragraph.
+    pub fn baselines(&self) -> Option<(Abs, Abs)> {
+        if self.has_baseline() {
+            return Some((self.baseline(), self.baseline()));
+        }
+
+        let mut range: Option<(Abs, Abs)> = None;
+        for (pos, item) in self.items() {
+            let FrameItem::Group(group) = item else { continue };
+            let Some((first, last)) = group.frame.baselines() else { continue };
+            let (first, last) = (pos.y + first, pos.y + last);
+            range = Some(match range {
+                Some((a, b)) => (a.min(first), b.max(last)),
+                None => (first, last),
+            });
+        }
+        range
+    }
+
     /// The distance from the baseline to the top of the frame.
     ///
     /// This is the same as `baseline()`, but more in line with the terminology
@@ -359,6 +382,31 @@ impl Frame {
         });
     }
 
+    /// Make all fills and strokes in the frame more transparent by scaling
+    /// their opacity with the given factor.
+    pub fn fade(&mut self, opacity: f32) {
+        for (_, item) in Arc::make_mut(&mut self.items).iter_mut() {
+            match item {
+                FrameItem::Group(group) => group.frame.fade(opacity),
+                FrameItem::Text(text) => {
+                    text.fill.fade(opacity);
+                    if let Some(stroke) = &mut text.stroke {
+                        stroke.paint.fade(opacity);
+                    }
+                }
+                FrameItem::Shape(shape, _) => {
+                    if let Some(fill) = &mut shape.fill {
+                        fill.fade(opacity);
+                    }
+                    if let Some(stroke) = &mut shape.stroke {
+                        stroke.paint.fade(opacity);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Add a background fill.
     pub fn fill(&mut self, fill: impl Into<Paint>) {
         self.prepend(
@@ -551,6 +599,10 @@ pub struct GroupItem {
     /// The group's logical parent. All elements in this group are logically
     /// ordered immediately after the parent's start location.
     pub parent: Option<Location>,
+    /// Whether the group's contents are purely decorative, like a page's
+    /// header or background. Such content is not part of the document's
+    /// logical reading order.
+    pub artifact: bool,
 }
 
 impl GroupItem {
@@ -562,6 +614,7 @@ impl GroupItem {
             clip_path: None,
             label: None,
             parent: None,
+            artifact: false,
         }
     }
 }
@@ -157,6 +157,11 @@ pub struct GridElem {
     /// If there are more gutters than defined sizes, the last gutter is
     /// repeated.
     ///
+    /// Gutters are separate from the cells' [`inset`]($grid.inset): The inset
+    /// pads the content within each cell, while a gutter is empty space
+    /// between two tracks that does not enlarge the cells around it. Cell
+    /// fills and strokes stop at the gutter, so each cell keeps its own border.
+    ///
     /// This is a shorthand to set `column-gutter` and `row-gutter` to the same
     /// value.
     #[external]
@@ -175,6 +180,29 @@ pub struct GridElem {
     #[borrowed]
     pub row_gutter: TrackSizings,
 
+    /// The maximum widths of automatically sized columns.
+    ///
+    /// An `{auto}` column normally grows as wide as its widest cell as long as
+    /// there is room. With a maximum width, it stops growing at that width and
+    /// the content of its cells wraps instead. Columns with a fixed or
+    /// fractional size are unaffected.
+    ///
+    /// This can be a single width for all columns or an array with a width or
+    /// `{none}` per column. Columns beyond the end of the array have no
+    /// maximum width.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: 2,
+    ///   gutter: 6pt,
+    ///   max-width: (2cm, none),
+    ///   [This text wraps at two centimeters.],
+    ///   [This text does not.],
+    /// )
+    /// ```
+    #[borrowed]
+    pub max_width: ColumnMaxWidths,
+
     /// How to fill the cells.
     ///
     /// This can be a color or a function that returns a color. The function
@@ -345,6 +373,43 @@ cast! {
     values: Array => Self(values.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?),
 }
 
+/// Maximum widths of automatically sized columns.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum ColumnMaxWidths {
+    /// The same maximum width for all columns.
+    Uniform(Option<Rel<Length>>),
+    /// A maximum width per column.
+    Array(Vec<Option<Rel<Length>>>),
+}
+
+impl ColumnMaxWidths {
+    /// The maximum width of the column with the given index, if any.
+    pub fn get(&self, x: usize) -> Option<Rel<Length>> {
+        match self {
+            Self::Uniform(max) => *max,
+            Self::Array(array) => array.get(x).copied().flatten(),
+        }
+    }
+}
+
+impl Default for ColumnMaxWidths {
+    fn default() -> Self {
+        Self::Uniform(None)
+    }
+}
+
+cast! {
+    ColumnMaxWidths,
+    self => match self {
+        Self::Uniform(max) => max.into_value(),
+        Self::Array(array) => array.into_value(),
+    },
+    max: Option<Rel<Length>> => Self::Uniform(max),
+    values: Array => Self::Array(
+        values.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?
+    ),
+}
+
 /// Any child of a grid element.
 #[derive(Debug, PartialEq, Clone, Hash)]
 pub enum GridChild {
@@ -480,6 +545,10 @@ pub struct GridHeader {
 #[elem(name = "footer", title = "Grid Footer")]
 pub struct GridFooter {
     /// Whether this footer should be repeated across pages.
+    ///
+    /// A footer that doesn't repeat only appears once, at the end of the last
+    /// region the table occupies. It is kept together with the last row before
+    /// it where possible, so that it doesn't end up alone on a new page.
     #[default(true)]
     pub repeat: bool,
 
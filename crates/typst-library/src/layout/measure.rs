@@ -7,7 +7,8 @@ use crate::foundations::{
     dict, func, Content, Context, Dict, Resolve, Smart, StyleChain, Styles,
 };
 use crate::introspection::{Locator, LocatorLink};
-use crate::layout::{Abs, Axes, Length, Region, Size};
+use crate::layout::{Abs, Axes, Frame, FrameItem, Length, Region, Size};
+use crate::text::TextItem;
 
 /// Measures the layouted size of content.
 ///
@@ -43,6 +44,21 @@ use crate::layout::{Abs, Axes, Length, Region, Size};
 ///
 /// The measure function returns a dictionary with the entries `width` and
 /// `height`, both of type [`length`].
+///
+/// For aligning content with other elements, the dictionary also contains
+/// the entries `baseline` and `last-baseline` with the distances of the first
+/// and last baseline from the top edge of the content, as well as
+/// `cap-height` and `x-height` with the metrics of the font that sets most of
+/// the content's text at its size. Each of them is `{none}` if the content
+/// has no baseline or no text, respectively.
+///
+/// ```example
+/// #context {
+///   let m = measure[Hello \ World]
+///   [First baseline at #m.baseline,
+///    last one at #m.last-baseline.]
+/// }
+/// ```
 #[func(contextual)]
 pub fn measure(
     /// The engine.
@@ -53,7 +69,17 @@ pub fn measure(
     span: Span,
     /// The width available to layout the content.
     ///
-    /// Setting this to `{auto}` indicates infinite available width.
+    /// Setting this to `{auto}` indicates infinite available width. With a
+    /// fixed width, text wraps just like it would in a container of that
+    /// width, which lets you find out how tall a paragraph becomes:
+    ///
+    /// ```example
+    /// #context {
+    ///   let narrow = measure(lorem(30), width: 4cm)
+    ///   let wide = measure(lorem(30), width: 8cm)
+    ///   [#narrow.height vs. #wide.height]
+    /// }
+    /// ```
     ///
     /// Note that using the `width` and `height` parameters of this function is
     /// different from measuring a sized [`block`] containing the content. In
@@ -111,5 +137,27 @@ pub fn measure(
 
     let frame = (engine.routines.layout_frame)(engine, &content, locator, styles, pod)?;
     let Size { x, y } = frame.size();
-    Ok(dict! { "width" => x, "height" => y })
+    let baselines = frame.baselines();
+    let text = dominant_text(&frame);
+    let metric = |f: fn(&TextItem) -> Abs| text.map(f);
+    Ok(dict! {
+        "width" => x,
+        "height" => y,
+        "baseline" => baselines.map(|(first, _)| first),
+        "last-baseline" => baselines.map(|(_, last)| last),
+        "cap-height" => metric(|text| text.font.metrics().cap_height.at(text.size)),
+        "x-height" => metric(|text| text.font.metrics().x_height.at(text.size)),
+    })
+}
+
+/// Find the text item with the most glyphs in the frame or its descendants.
+fn dominant_text(frame: &Frame) -> Option<&TextItem> {
+    frame
+        .items()
+        .filter_map(|(_, item)| match item {
+            FrameItem::Group(group) => dominant_text(&group.frame),
+            FrameItem::Text(text) => Some(text),
+            _ => None,
+        })
+        .max_by_key(|text| text.glyphs.len())
 }
@@ -27,6 +27,7 @@ mod ratio;
 mod regions;
 mod rel;
 mod repeat;
+mod responsive;
 mod sides;
 mod size;
 mod spacing;
@@ -58,6 +59,7 @@ pub use self::ratio::*;
 pub use self::regions::*;
 pub use self::rel::*;
 pub use self::repeat::*;
+pub use self::responsive::*;
 pub use self::sides::*;
 pub use self::size::*;
 pub use self::spacing::*;
@@ -96,11 +98,13 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<AlignElem>();
     global.define_elem::<PadElem>();
     global.define_elem::<RepeatElem>();
+    global.define_elem::<WatermarkElem>();
     global.define_elem::<MoveElem>();
     global.define_elem::<ScaleElem>();
     global.define_elem::<RotateElem>();
     global.define_elem::<SkewElem>();
     global.define_elem::<HideElem>();
+    global.define_elem::<ResponsiveElem>();
     global.define_func::<measure>();
     global.define_func::<layout>();
 }
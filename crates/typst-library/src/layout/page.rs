@@ -9,13 +9,13 @@ use typst_utils::{singleton, NonZeroExt, Scalar};
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Args, AutoValue, Cast, Construct, Content, Context, Dict, Fold, Func,
-    NativeElement, Set, Smart, StyleChain, Value,
+    cast, dict, elem, scope, Args, AutoValue, Cast, Construct, Content, Context, Dict,
+    Fold, Func, NativeElement, Packed, Set, Smart, StyleChain, Value,
 };
 use crate::introspection::Introspector;
 use crate::layout::{
     Abs, Alignment, FlushElem, Frame, HAlignment, Length, OuterVAlignment, Ratio, Rel,
-    Sides, SpecificAlignment,
+    Sides, Spacing, SpecificAlignment,
 };
 use crate::model::{DocumentInfo, Numbering};
 use crate::text::LocalName;
@@ -40,7 +40,7 @@ use crate::visualize::{Color, Paint};
 ///
 /// There you go, US friends!
 /// ```
-#[elem(Construct)]
+#[elem(scope, Construct)]
 pub struct PageElem {
     /// A standard paper size to set width and height.
     ///
@@ -132,6 +132,15 @@ pub struct PageElem {
     /// The values for `left` and `right` are mutually exclusive with
     /// the values for `inside` and `outside`.
     ///
+    /// Margins can also be [fractions]($fraction). After the fixed margins
+    /// are subtracted, the remaining space along an axis is distributed
+    /// among the fractional margins and the text block, where the text block
+    /// takes a share of `{1fr}`. For instance, with `(left: 2cm, right: 1fr)`
+    /// the text block and the right margin split the width left over after
+    /// the left margin equally. This leaves room for marginal notes. On pages
+    /// with an `{auto}` size along an axis, fractional margins on that axis
+    /// are zero.
+    ///
     /// ```example
     /// #set page(
     ///  width: 3cm,
@@ -182,6 +191,70 @@ pub struct PageElem {
     #[ghost]
     pub columns: NonZeroUsize,
 
+    /// How full the last page of a page run must at least be.
+    ///
+    /// A last page with just a few lines on it (a _widow page_) looks
+    /// unfinished. When this is set and the last page before a page break or
+    /// the end of the document would be filled less than the given ratio of
+    /// its body height, Typst tries to pull its content back onto the previous
+    /// pages. For this, the previous pages are allowed to extend their body
+    /// into the bottom margin by at most half of its size, but not past the
+    /// top of the footer. If that is not enough, the pages are laid out as
+    /// usual.
+    ///
+    /// ```typ
+    /// #set page(min-fill: 20%)
+    /// ```
+    #[ghost]
+    pub min_fill: Option<Ratio>,
+
+    /// Whether to scale the content of a page run down so that it fits onto a
+    /// single page, and if so, how much it may at most be scaled down.
+    ///
+    /// When this is set to a ratio and the content would not fit onto a single
+    /// page, Typst lays it out into a proportionally larger page body and then
+    /// uniformly scales the result down to the page's actual body size. The
+    /// ratio is the smallest permissible scale. If the content doesn't fit onto
+    /// one page even at that scale, it is laid out as usual.
+    ///
+    /// Finding the right scale requires laying the content out several times,
+    /// so this is best used for short runs like title pages or posters. As a
+    /// shrunk run ends up on a single page, [`min-fill`]($page.min-fill) only
+    /// applies if shrinking wasn't possible.
+    ///
+    /// ```typ
+    /// #set page(shrink: 80%)
+    /// ```
+    #[ghost]
+    pub shrink: Option<Ratio>,
+
+    /// Areas of the page body that paragraphs flow around.
+    ///
+    /// Each exclusion is a dictionary with the keys `x`, `y`, `width`, and
+    /// `height`, which are relative to the top-left corner of the page body,
+    /// i.e. the area within the margins. Lines of text next to an exclusion
+    /// are shortened so that they end before it. If the exclusion touches the
+    /// left edge of the body, the lines start after it instead. Below the
+    /// exclusion, text resumes at full width.
+    ///
+    /// Exclusions only reserve space. To show something in the reserved area,
+    /// [place] it there. They apply to the paragraphs directly in the page's
+    /// body on single-column pages, but not to paragraphs within containers.
+    /// Paragraphs with shortened lines always use the `{"simple"}`
+    /// [line breaking]($par.linebreaks) algorithm.
+    ///
+    /// ```example
+    /// #set page(
+    ///   height: 120pt,
+    ///   exclude: ((x: 65%, y: 0pt, width: 35%, height: 40pt),),
+    /// )
+    ///
+    /// #place(top + right, rect(width: 30%, height: 35pt)[Stamp])
+    /// #lorem(40)
+    /// ```
+    #[ghost]
+    pub exclude: Vec<Exclusion>,
+
     /// The page's background fill.
     ///
     /// Setting this to something non-transparent instructs the printer to color
@@ -211,6 +284,11 @@ pub struct PageElem {
     /// If an explicit `footer` (or `header` for top-aligned numbering) is
     /// given, the numbering is ignored.
     ///
+    /// When exporting to PDF, the numbering is also written as page labels,
+    /// so that PDF viewers show the same page numbers in their navigation.
+    /// A change of numbering, e.g. from roman numerals in the front matter to
+    /// arabic numbers in the body, starts a new range of labels.
+    ///
     /// ```example
     /// #set page(
     ///   height: 100pt,
@@ -360,6 +438,24 @@ pub struct PageElem {
     #[ghost]
     pub foreground: Option<Content>,
 
+    /// Content anchored to the corners of the page.
+    ///
+    /// Each [corner]($page.corner) is placed at a fixed distance from the
+    /// page's edges, independently of its margins. Like the header and footer,
+    /// it is laid out anew for every page, so it appears on all pages.
+    ///
+    /// ```example
+    /// #set page(height: 80pt, corners: (
+    ///   page.corner(top + right, dx: 8pt, dy: 8pt)[*Logo*],
+    ///   page.corner(bottom + left, dx: 8pt, dy: 8pt, context counter(page).display()),
+    /// ))
+    ///
+    /// This page has a logo and a folio.
+    /// ```
+    #[borrowed]
+    #[ghost]
+    pub corners: Vec<Packed<PageCornerElem>>,
+
     /// The contents of the page(s).
     ///
     /// Multiple pages will be created if the content does not fit on a single
@@ -370,6 +466,12 @@ pub struct PageElem {
     pub body: Content,
 }
 
+#[scope]
+impl PageElem {
+    #[elem]
+    type PageCornerElem;
+}
+
 impl Construct for PageElem {
     fn construct(engine: &mut Engine, args: &mut Args) -> SourceResult<Content> {
         // The page constructor is special: It doesn't create a page element.
@@ -395,6 +497,37 @@ impl LocalName for PageElem {
     const KEY: &'static str = "page";
 }
 
+/// Content anchored to a corner of the page.
+///
+/// Corners are used with the [`corners`]($page.corners) property of pages.
+///
+/// ```example
+/// #set page(height: 60pt, corners: (
+///   page.corner(top + right, dx: 6pt, dy: 6pt, circle(radius: 6pt)),
+/// ))
+/// ```
+#[elem(name = "corner", title = "Page Corner")]
+pub struct PageCornerElem {
+    /// The corner or edge of the page to anchor the content to.
+    #[required]
+    pub alignment: Alignment,
+
+    /// The horizontal distance between the content and the page's edge.
+    pub dx: Length,
+
+    /// The vertical distance between the content and the page's edge.
+    pub dy: Length,
+
+    /// The anchored content.
+    #[required]
+    pub body: Content,
+}
+
+cast! {
+    PageCornerElem,
+    v: Content => v.unpack::<Self>().map_err(|_| "expected a page corner")?,
+}
+
 /// A manual page break.
 ///
 /// Must not be used inside any containers.
@@ -502,11 +635,45 @@ impl Page {
     }
 }
 
+/// A rectangular area of the page body that paragraphs flow around.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Exclusion {
+    /// The horizontal offset from the left edge of the page body.
+    pub x: Rel<Length>,
+    /// The vertical offset from the top edge of the page body.
+    pub y: Rel<Length>,
+    /// The width of the area.
+    pub width: Rel<Length>,
+    /// The height of the area.
+    pub height: Rel<Length>,
+}
+
+cast! {
+    Exclusion,
+    self => dict![
+        "x" => self.x,
+        "y" => self.y,
+        "width" => self.width,
+        "height" => self.height,
+    ].into_value(),
+    mut dict: Dict => {
+        let mut take = |key| dict.take(key).ok().map(Value::cast).transpose();
+        let ret = Self {
+            x: take("x")?.unwrap_or_default(),
+            y: take("y")?.unwrap_or_default(),
+            width: take("width")?.unwrap_or_default(),
+            height: take("height")?.unwrap_or_default(),
+        };
+        dict.finish(&["x", "y", "width", "height"])?;
+        ret
+    },
+}
+
 /// Specification of the page's margins.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Margin {
     /// The margins for each side.
-    pub sides: Sides<Option<Smart<Rel<Length>>>>,
+    pub sides: Sides<Option<Smart<Spacing>>>,
     /// Whether to swap `left` and `right` to make them `inside` and `outside`
     /// (when to swap depends on the binding).
     pub two_sided: Option<bool>,
@@ -514,7 +681,7 @@ pub struct Margin {
 
 impl Margin {
     /// Create an instance with four equal components.
-    pub fn splat(value: Option<Smart<Rel<Length>>>) -> Self {
+    pub fn splat(value: Option<Smart<Spacing>>) -> Self {
         Self { sides: Sides::splat(value), two_sided: None }
     }
 }
@@ -548,7 +715,7 @@ cast! {
         }
 
         let mut dict = Dict::new();
-        let mut handle = |key: &str, component: Option<Smart<Rel<Length>>>| {
+        let mut handle = |key: &str, component: Option<Smart<Spacing>>| {
             if let Some(c) = component {
                 dict.insert(key.into(), c.into_value());
             }
@@ -567,7 +734,7 @@ cast! {
         Value::Dict(dict)
     },
     _: AutoValue => Self::splat(Some(Smart::Auto)),
-    v: Rel<Length> => Self::splat(Some(Smart::Custom(v))),
+    v: Spacing => Self::splat(Some(Smart::Custom(v))),
     mut dict: Dict => {
         let mut take = |key| dict.take(key).ok().map(Value::cast).transpose();
 
@@ -40,6 +40,28 @@ use crate::layout::{Alignment, Em, Length, Rel};
 /// )
 /// ```
 ///
+/// # Absolute page coordinates { #absolute-coordinates }
+/// To hit exact positions on the page, e.g. when filling in a pre-printed
+/// form, place content in the page's foreground. The offsets are then measured
+/// from the corner of the page given by the alignment. With a `bottom + left`
+/// alignment, for instance, a negative `dy` moves the content up from the
+/// bottom edge of the page.
+///
+/// ```example
+/// #set page(
+///   width: 60mm,
+///   height: 30mm,
+///   foreground: {
+///     place(top + left, dx: 20mm, dy: 5mm)[A]
+///     place(bottom + left, dx: 20mm, dy: -5mm)[B]
+///   },
+/// )
+/// ```
+///
+/// To find out where content ended up, you can use the
+/// [`position`]($location.position) method of its location, which can also
+/// measure from a chosen corner of the page.
+///
 /// # Effect on the position of other elements { #effect-on-other-elements }
 /// Overlaid elements don't take space in the flow of content, but a `place`
 /// call inserts an invisible block-level element in the flow. This can
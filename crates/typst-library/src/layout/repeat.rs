@@ -1,7 +1,7 @@
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{elem, Content, NativeElement, Packed, Show, StyleChain};
-use crate::layout::{BlockElem, Length};
+use crate::layout::{Angle, BlockElem, Em, Length, Ratio};
 
 /// Repeats content to the available space.
 ///
@@ -47,3 +47,59 @@ impl Show for Packed<RepeatElem> {
             .spanned(self.span()))
     }
 }
+
+/// Tiles rotated content across the available space.
+///
+/// This is mostly useful as a page [background]($page.background) to put a
+/// watermark onto every page. The body is rotated by the given angle and then
+/// repeated in staggered rows until the whole area is covered. The body's fills
+/// and strokes are drawn with the given opacity.
+///
+/// Errors if there no bounds on the available space, as it would create
+/// infinite content.
+///
+/// # Example
+/// ```example
+/// #set page(background: watermark(
+///   text(14pt, weight: "bold")[CONFIDENTIAL],
+/// ))
+///
+/// = Typst's secret plans
+/// In the year 2023, we plan to take
+/// over the world (of typesetting).
+/// ```
+#[elem(Show)]
+pub struct WatermarkElem {
+    /// The content to tile.
+    #[required]
+    pub body: Content,
+
+    /// The angle by which each instance of the body is rotated.
+    ///
+    /// ```example
+    /// #box(width: 100%, height: 60pt, watermark(angle: 0deg)[Draft])
+    /// ```
+    #[default(Angle::deg(-45.0))]
+    pub angle: Angle,
+
+    /// The gap between neighbouring instances of the body, both horizontally
+    /// and vertically.
+    #[default(Em::new(2.0).into())]
+    pub spacing: Length,
+
+    /// The opacity of the body.
+    ///
+    /// The opacity is applied to all fills and strokes in the body, including
+    /// gradients and patterns. Images are drawn as is. At `{100%}`, the body
+    /// is drawn just like outside of a watermark.
+    #[default(Ratio::new(0.3))]
+    pub opacity: Ratio,
+}
+
+impl Show for Packed<WatermarkElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_watermark)
+            .pack()
+            .spanned(self.span()))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::diag::{bail, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    array, cast, elem, Array, Content, NativeElement, Packed, Resolve, Show, StyleChain,
+};
+use crate::layout::{BlockElem, Length};
+
+/// Chooses between alternative layouts depending on the available width.
+///
+/// Each alternative is either an array of a minimum width and content or just
+/// content. The first alternative whose minimum width fits into the width
+/// available in the current region is displayed. Plain content always fits,
+/// so it is useful as the last alternative. If no alternative fits, the last
+/// one is displayed anyway.
+///
+/// This makes it possible to collapse, for instance, a two-column figure into
+/// a single column when it ends up in a narrow container.
+///
+/// ```example
+/// #let pair = (
+///   rect(width: 100%)[A],
+///   rect(width: 100%)[B],
+/// )
+///
+/// #let adaptive = responsive(
+///   (5cm, grid(columns: 2, gutter: 4pt, ..pair)),
+///   stack(spacing: 4pt, ..pair),
+/// )
+///
+/// #adaptive
+/// #block(width: 3cm, adaptive)
+/// ```
+///
+/// Like with [`layout`], the content is forced into a [block]-level container.
+#[elem(Show)]
+pub struct ResponsiveElem {
+    /// The alternatives to choose from, ordered from the widest to the
+    /// narrowest.
+    #[variadic]
+    pub alternatives: Vec<ResponsiveAlternative>,
+}
+
+impl Show for Packed<ResponsiveElem> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::multi_layouter(
+            self.clone(),
+            |elem, engine, locator, styles, regions| {
+                let width = regions.size.x;
+                let alternatives = elem.alternatives();
+                let body = alternatives
+                    .iter()
+                    .find(|alt| alt.min.map_or(true, |min| min.resolve(styles) <= width))
+                    .or(alternatives.last())
+                    .map(|alt| alt.body.clone())
+                    .unwrap_or_default();
+                (engine.routines.layout_fragment)(engine, &body, locator, styles, regions)
+            },
+        )
+        .pack()
+        .spanned(self.span()))
+    }
+}
+
+/// An alternative layout for [`responsive`]($responsive).
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ResponsiveAlternative {
+    /// The minimum width for which this alternative is chosen.
+    pub min: Option<Length>,
+    /// The content to display.
+    pub body: Content,
+}
+
+cast! {
+    ResponsiveAlternative,
+    self => match self.min {
+        Some(min) => array![min, self.body].into_value(),
+        None => self.body.into_value(),
+    },
+    body: Content => Self { min: None, body },
+    array: Array => match array.as_slice() {
+        [min, body] => Self {
+            min: Some(min.clone().cast()?),
+            body: body.clone().cast()?,
+        },
+        _ => bail!("alternative must be content or an array of a width and content"),
+    },
+}
@@ -1,6 +1,6 @@
 use typst_utils::Numeric;
 
-use crate::foundations::{cast, elem, Content};
+use crate::foundations::{cast, elem, Cast, Content, Fold, Smart};
 use crate::layout::{Abs, Em, Fr, Length, Ratio, Rel};
 
 /// Inserts horizontal spacing into a paragraph.
@@ -78,6 +78,10 @@ impl HElem {
 /// the remaining space on the page is distributed among all fractional spacings
 /// according to their relative fractions.
 ///
+/// How fractional spacing interacts with [aligned]($align) blocks is
+/// configurable through the [`fr-order`]($block.fr-order) property of the
+/// surrounding block.
+///
 /// # Example
 /// ```example
 /// #grid(
@@ -113,6 +117,71 @@ pub struct VElem {
     /// ```
     pub weak: bool,
 
+    /// Whether the spacing is a good or a bad place to break the region.
+    ///
+    /// - `{"prefer"}`: If the content following the spacing doesn't fit into
+    ///   the current region, the region ends at this spacing rather than at the
+    ///   last possible point further down. The most recent preferred spacing
+    ///   wins. To not waste too much space, this only happens if breaking
+    ///   there leaves at most half of the region empty.
+    /// - `{"avoid"}`: The content before the spacing is kept in the same
+    ///   region as the content after it, just like a
+    ///   [sticky]($block.sticky) block.
+    /// - `{auto}`: The spacing is treated like any other potential break.
+    ///
+    /// ```example
+    /// #set page(height: 100pt)
+    /// First section.
+    /// #v(1em, penalty: "prefer")
+    /// Second section with a longer
+    /// paragraph that would otherwise break.
+    /// ```
+    pub penalty: Smart<Penalty>,
+
+    /// How the spacing combines with directly preceding strong spacing.
+    ///
+    /// - `{"sum"}`: Both spacings are added up.
+    /// - `{"max"}`: Only the larger of the two spacings remains, similar to
+    ///   collapsing margins.
+    ///
+    /// This only affects strong spacing. From adjacent [weak]($v.weak)
+    /// spacings, the largest one always wins.
+    ///
+    /// ```example
+    /// First
+    /// #v(1em)
+    /// #v(1em)
+    /// Second
+    ///
+    /// #set v(collapse: "max")
+    /// First
+    /// #v(1em)
+    /// #v(1em)
+    /// Second
+    /// ```
+    #[default(SpacingCollapse::Sum)]
+    pub collapse: SpacingCollapse,
+
+    /// Whether the spacing is dropped at the very start and end of a flow.
+    ///
+    /// Unlike [weak]($v.weak) spacing, which collapses at the edges of every
+    /// region, this only affects spacing before the first and after the last
+    /// content of a whole flow, like a page run or the body of a block.
+    /// Spacing at the boundaries of the regions in between is kept. This is
+    /// useful when content that brings its own spacing, e.g. from a template,
+    /// ends up at the very start of a document.
+    ///
+    /// ```example
+    /// #set v(trim: true)
+    /// #block(stroke: 0.5pt)[
+    ///   #v(1em)
+    ///   No gap above or below.
+    ///   #v(1em)
+    /// ]
+    /// ```
+    #[default(false)]
+    pub trim: bool,
+
     /// Whether the spacing collapses if not immediately preceded by a
     /// paragraph.
     #[internal]
@@ -125,6 +194,34 @@ cast! {
     v: Content => v.unpack::<Self>().map_err(|_| "expected `v` element")?,
 }
 
+/// How favorable a vertical spacing is as a region break.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Penalty {
+    /// The spacing is a preferred break.
+    Prefer,
+    /// Breaking at the spacing should be avoided.
+    Avoid,
+}
+
+/// When fractional vertical spacing is distributed relative to the alignment
+/// of the surrounding blocks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum FrOrder {
+    /// Fractional spacing takes all remaining space before blocks are aligned.
+    BeforeAlign,
+    /// Blocks are aligned first and fractional spacing fills the gaps.
+    AfterAlign,
+}
+
+/// How adjacent strong vertical spacings are combined.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum SpacingCollapse {
+    /// The spacings are added up.
+    Sum,
+    /// The larger spacing wins.
+    Max,
+}
+
 /// Kinds of spacing.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Spacing {
@@ -150,6 +247,15 @@ impl Spacing {
     }
 }
 
+impl Fold for Spacing {
+    fn fold(self, outer: Self) -> Self {
+        match (self, outer) {
+            (Self::Rel(inner), Self::Rel(outer)) => Self::Rel(inner.fold(outer)),
+            (inner, _) => inner,
+        }
+    }
+}
+
 impl From<Abs> for Spacing {
     fn from(abs: Abs) -> Self {
         Self::Rel(abs.into())
@@ -42,6 +42,29 @@ pub struct StackElem {
     /// Spacing to insert between items where no explicit spacing was provided.
     pub spacing: Option<Spacing>,
 
+    /// Whether all items take the size of the largest item along the cross
+    /// axis.
+    ///
+    /// The items are first measured at their natural size. Then, each of them
+    /// is laid out at the largest of those sizes along the axis perpendicular
+    /// to the stacking direction: In a vertical stack, all items get the width
+    /// of the widest item. In a horizontal stack, they get the height of the
+    /// tallest item. This is useful for columns of buttons or rows of labels
+    /// that should line up regardless of their content.
+    ///
+    /// ```example
+    /// #let button = rect.with(inset: 6pt, radius: 3pt)
+    /// #stack(
+    ///   spacing: 4pt,
+    ///   uniform: true,
+    ///   button[OK],
+    ///   button[Cancel],
+    ///   button[Help],
+    /// )
+    /// ```
+    #[default(false)]
+    pub uniform: bool,
+
     /// The children to stack along the axis.
     #[variadic]
     pub children: Vec<StackChild>,
@@ -51,6 +51,10 @@ impl Show for Packed<MoveElem> {
 /// Rotates an element by a given angle. The layout will act as if the element
 /// was not rotated unless you specify `{reflow: true}`.
 ///
+/// The body is laid out first and then rotated as a whole. To rotate multiple
+/// elements as one unit, keeping their arrangement relative to each other,
+/// group them into a single body, for example with a [stack] or a [box].
+///
 /// # Example
 /// ```example
 /// #stack(
@@ -11,8 +11,8 @@ use crate::foundations::{
 };
 use crate::introspection::{Count, Counter, CounterUpdate, Locatable};
 use crate::layout::{
-    AlignElem, Alignment, BlockElem, InlineElem, OuterHAlignment, SpecificAlignment,
-    VAlignment,
+    AlignElem, Alignment, BlockElem, Em, InlineElem, Length, OuterHAlignment,
+    SpecificAlignment, VAlignment,
 };
 use crate::math::{MathSize, MathVariant};
 use crate::model::{Numbering, Outlinable, ParLine, Refable, Supplement};
@@ -81,6 +81,72 @@ pub struct EquationElem {
     #[default(SpecificAlignment::Both(OuterHAlignment::End, VAlignment::Horizon))]
     pub number_align: SpecificAlignment<OuterHAlignment, VAlignment>,
 
+    /// Whether trailing punctuation of a centered block-level equation hangs
+    /// outside of it.
+    ///
+    /// When enabled, punctuation like a period or comma at the end of a
+    /// centered equation is not taken into account for centering it. The
+    /// expression itself then stays centered and the punctuation hangs into
+    /// the space to the right.
+    ///
+    /// ```example
+    /// #set math.equation(hang-punctuation: true)
+    /// $ a^2 + b^2 = c^2 . $
+    /// $ a^2 + b^2 = c^2 $
+    /// ```
+    #[default(false)]
+    pub hang_punctuation: bool,
+
+    /// Whether a block-level equation that is too wide for the available space
+    /// is automatically broken into multiple lines.
+    ///
+    /// Breaks are placed before relations like `=` where possible and before
+    /// binary operators like `+` otherwise. If the first line contains a
+    /// relation, the continuation lines are aligned to it. Otherwise, they start
+    /// where the first line starts and are indented. Space for the
+    /// [number]($math.equation.numbering) is kept free. Equations that already
+    /// contain [line breaks]($linebreak) or alignment points are laid out as
+    /// written, so you can always place the breaks manually.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #set math.equation(wrap: true)
+    /// $ (a + b)^4 = a^4 + 4a^3 b + 6a^2 b^2 + 4a b^3 + b^4 $
+    /// ```
+    #[default(false)]
+    pub wrap: bool,
+
+    /// How far the continuation lines of an automatically
+    /// [wrapped]($math.equation.wrap) equation are indented if they aren't
+    /// aligned to a relation.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #set math.equation(wrap: true, wrap-indent: 1em)
+    /// $ a + b + c + d + e + f + g + h + i + j + k $
+    /// ```
+    #[resolve]
+    #[default(Em::new(2.0).into())]
+    pub wrap_indent: Length,
+
+    /// Whether the number of a block-level equation moves to its own line when
+    /// it would otherwise overlap the equation.
+    ///
+    /// The number is then placed below the equation, or above it if it is
+    /// [aligned]($math.equation.number-align) to the top.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #set math.equation(
+    ///   numbering: "(1)",
+    ///   number-break: true,
+    /// )
+    ///
+    /// $ a + b + c + d + e + f + g $
+    /// ```
+    #[default(false)]
+    pub number_break: bool,
+
     /// A supplement for the equation.
     ///
     /// For references to equations, this is added before the referenced number.
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use ecow::EcoString;
 
 use crate::diag::{bail, HintedStrResult, SourceResult};
@@ -6,6 +8,7 @@ use crate::foundations::{
     cast, elem, Args, Array, Construct, Content, Datetime, Fields, Smart, StyleChain,
     Styles, Value,
 };
+use crate::layout::{Abs, Length};
 
 /// The root element of a document and its metadata.
 ///
@@ -58,6 +61,43 @@ pub struct DocumentElem {
     /// something other than `{auto}`.
     #[ghost]
     pub date: Smart<Option<Datetime>>,
+
+    /// How far above their targets internal links land.
+    ///
+    /// When a link to a heading, figure, or other element in the document is
+    /// clicked, e.g. in the outline, the PDF viewer scrolls such that this
+    /// much space remains between the top of the viewer and the target. This
+    /// prevents the target from being cut off at the top.
+    ///
+    /// ```typ
+    /// #set document(link-offset: 1cm)
+    /// ```
+    #[ghost]
+    #[resolve]
+    #[default(Abs::pt(10.0).into())]
+    pub link_offset: Length,
+
+    /// Imposes the pages onto sheets for printing a saddle-stitched booklet.
+    ///
+    /// When set, every two pages are placed side by side onto a sheet that is
+    /// twice as wide as a page, in the order that yields the document's
+    /// reading order once the printed sheets are stacked, folded and stapled.
+    /// The value is the signature size, i.e. how many pages are folded
+    /// together into one bundle. It is rounded up to a multiple of four and
+    /// the last signature is padded with blank pages if necessary.
+    ///
+    /// For instance, an eight-page document with a signature of eight pages
+    /// results in the sheet sides 8 & 1, 2 & 7, 6 & 3, and 4 & 5.
+    ///
+    /// Imposition happens after layout, so page numbers and counters still
+    /// follow the reading order. For the same reason, links within the
+    /// document don't point to the right sheet.
+    ///
+    /// ```typ
+    /// #set document(booklet: 16)
+    /// ```
+    #[ghost]
+    pub booklet: Option<NonZeroUsize>,
 }
 
 impl Construct for DocumentElem {
@@ -89,7 +129,7 @@ cast! {
 }
 
 /// Details about the document.
-#[derive(Debug, Default, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone, PartialEq, Hash)]
 pub struct DocumentInfo {
     /// The document's title.
     pub title: Option<EcoString>,
@@ -101,6 +141,24 @@ pub struct DocumentInfo {
     pub keywords: Vec<EcoString>,
     /// The document's creation date.
     pub date: Smart<Option<Datetime>>,
+    /// How far above their targets internal links land.
+    pub link_offset: Abs,
+    /// The signature size with which the pages are imposed as a booklet.
+    pub booklet: Option<NonZeroUsize>,
+}
+
+impl Default for DocumentInfo {
+    fn default() -> Self {
+        Self {
+            title: None,
+            author: vec![],
+            description: None,
+            keywords: vec![],
+            date: Smart::Auto,
+            link_offset: DocumentElem::link_offset_in(StyleChain::default()),
+            booklet: None,
+        }
+    }
 }
 
 impl DocumentInfo {
@@ -127,5 +185,11 @@ impl DocumentInfo {
         if has(<DocumentElem as Fields>::Enum::Date) {
             self.date = DocumentElem::date_in(chain);
         }
+        if has(<DocumentElem as Fields>::Enum::LinkOffset) {
+            self.link_offset = DocumentElem::link_offset_in(chain);
+        }
+        if has(<DocumentElem as Fields>::Enum::Booklet) {
+            self.booklet = DocumentElem::booklet_in(chain);
+        }
     }
 }
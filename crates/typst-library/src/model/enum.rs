@@ -10,6 +10,7 @@ use crate::foundations::{
     Styles, TargetElem,
 };
 use crate::html::{attr, tag, HtmlElem};
+use crate::introspection::Locatable;
 use crate::layout::{Alignment, BlockElem, Em, HAlignment, Length, VAlignment, VElem};
 use crate::model::{ListItemLike, ListLike, Numbering, NumberingPattern, ParElem};
 
@@ -69,7 +70,7 @@ use crate::model::{ListItemLike, ListLike, Numbering, NumberingPattern, ParElem}
 /// Enumeration items can contain multiple paragraphs and other block-level
 /// content. All content that is indented more than an item's marker becomes
 /// part of that item.
-#[elem(scope, title = "Numbered List", Show)]
+#[elem(scope, title = "Numbered List", Locatable, Show)]
 pub struct EnumElem {
     /// Defines the default [spacing]($enum.spacing) of the enumeration. If it
     /// is `{false}`, the items are spaced apart with
@@ -145,14 +146,44 @@ pub struct EnumElem {
     pub full: bool,
 
     /// The indentation of each item.
+    ///
+    /// This is the space before the number. The space between the number and
+    /// the body is controlled separately through the
+    /// [`body-indent`]($enum.body-indent) property.
     #[resolve]
     pub indent: Length,
 
     /// The space between the numbering and the body of each item.
+    ///
+    /// All lines of an item's body, including wrapped ones, start at this
+    /// distance from the number.
+    ///
+    /// ```example
+    /// #set enum(indent: 0pt, body-indent: 2em)
+    /// + Numbers are flush with the
+    ///   surrounding text, while the
+    ///   bodies are indented.
+    /// ```
     #[resolve]
     #[default(Em::new(0.5).into())]
     pub body_indent: Length,
 
+    /// Whether the numbers hang into the margin before the list.
+    ///
+    /// If this is enabled, the number and the [body indent]($enum.body-indent)
+    /// are placed before the start edge of the text, so that the bodies of
+    /// the items are flush with the surrounding paragraphs. The
+    /// [`indent`]($enum.indent) still moves the whole item inwards.
+    ///
+    /// ```example
+    /// #set enum(hanging: true)
+    /// Paragraphs and items
+    /// + start at the same
+    /// + edge of the text.
+    /// ```
+    #[default(false)]
+    pub hanging: bool,
+
     /// The spacing between the items of the enumeration.
     ///
     /// If set to `{auto}`, uses paragraph [`leading`]($par.leading) for tight
@@ -3,21 +3,23 @@ use std::num::NonZeroUsize;
 use std::str::FromStr;
 
 use ecow::EcoString;
+use smallvec::smallvec;
 use typst_utils::NonZeroExt;
 
-use crate::diag::{bail, SourceResult};
+use crate::diag::{bail, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, select_where, Content, Element, NativeElement, Packed, Selector,
-    Show, ShowSet, Smart, StyleChain, Styles, Synthesize, TargetElem,
+    cast, elem, scope, select_where, Content, Element, NativeElement, Packed, Repr,
+    Selector, Show, ShowSet, Smart, StyleChain, Styles, Synthesize, TargetElem,
 };
 use crate::html::{tag, HtmlElem};
 use crate::introspection::{
     Count, Counter, CounterKey, CounterUpdate, Locatable, Location,
 };
 use crate::layout::{
-    AlignElem, Alignment, BlockBody, BlockElem, Em, HAlignment, Length, OuterVAlignment,
-    PlaceElem, PlacementScope, VAlignment, VElem,
+    AlignElem, Alignment, BlockBody, BlockElem, Celled, Em, Fr, GridCell, GridChild,
+    GridElem, GridItem, HAlignment, Length, PlaceElem, PlacementScope, Sizing,
+    TrackSizings, VAlignment, VElem,
 };
 use crate::model::{Numbering, NumberingPattern, Outlinable, Refable, Supplement};
 use crate::text::{Lang, Region, TextElem};
@@ -63,15 +65,20 @@ use crate::visualize::ImageElem;
 /// float to the top or bottom of the page, you can use the
 /// [`placement`]($figure.placement) argument.
 ///
-/// If your figure is too large and its contents are breakable across pages
-/// (e.g. if it contains a large table), then you can make the figure itself
-/// breakable across pages as well with this show rule:
+/// A figure and its caption are kept together: If a figure doesn't fit into
+/// the remaining space on the page, it moves to the next page as a whole. Only
+/// if it is taller than a full page, it breaks across pages (provided that its
+/// contents are breakable, like a large table). In that case, a caption below
+/// the figure ends up with its final part, while a caption at the
+/// [top]($figure.caption.position) stays with its first part. If you never
+/// want your figure to break, you can make it unbreakable with this show rule:
 /// ```typ
-/// #show figure: set block(breakable: true)
+/// #show figure: set block(breakable: false)
 /// ```
 ///
 /// See the [block]($block.breakable) documentation for more information about
-/// breakable and non-breakable blocks.
+/// breakable and non-breakable blocks and the
+/// [`keep-together`]($block.keep-together) property.
 ///
 /// # Caption customization
 /// You can modify the appearance of the figure's caption with its associated
@@ -117,6 +124,10 @@ pub struct FigureElem {
     /// - `{top}`: The figure floats to the top of the page.
     /// - `{bottom}`: The figure floats to the bottom of the page.
     ///
+    /// A floating figure moves together with its caption. It floats within
+    /// the page (or column) it was specified on, even if it is written in the
+    /// middle of the content.
+    ///
     /// The gap between the main flow content and the floating figure is
     /// controlled by the [`clearance`]($place.clearance) argument on the
     /// `place` function.
@@ -217,7 +228,8 @@ pub struct FigureElem {
     #[borrowed]
     pub numbering: Option<Numbering>,
 
-    /// The vertical gap between the body and caption.
+    /// The gap between the body and caption. For captions on the left or
+    /// right, this is the horizontal distance between the two.
     #[default(Em::new(0.65).into())]
     pub gap: Length,
 
@@ -333,18 +345,27 @@ impl Show for Packed<FigureElem> {
 
         // Build the caption, if any.
         if let Some(caption) = self.caption(styles) {
-            let (first, second) = match caption.position(styles) {
-                OuterVAlignment::Top => (caption.pack(), realized),
-                OuterVAlignment::Bottom => (realized, caption.pack()),
-            };
-            let mut seq = Vec::with_capacity(3);
-            seq.push(first);
-            if !target.is_html() {
-                let v = VElem::new(self.gap(styles).into()).with_weak(true);
-                seq.push(v.pack().spanned(self.span()))
+            let position = caption.position(styles);
+            if position.is_side() && !target.is_html() {
+                realized = self.side_caption(realized, caption, position, styles);
+            } else {
+                let (first, second) = match position {
+                    CaptionPosition::Top | CaptionPosition::Left => {
+                        (caption.pack(), realized)
+                    }
+                    CaptionPosition::Bottom | CaptionPosition::Right => {
+                        (realized, caption.pack())
+                    }
+                };
+                let mut seq = Vec::with_capacity(3);
+                seq.push(first);
+                if !target.is_html() {
+                    let v = VElem::new(self.gap(styles).into()).with_weak(true);
+                    seq.push(v.pack().spanned(self.span()))
+                }
+                seq.push(second);
+                realized = Content::sequence(seq)
             }
-            seq.push(second);
-            realized = Content::sequence(seq)
         }
 
         if target.is_html() {
@@ -380,12 +401,45 @@ impl Show for Packed<FigureElem> {
     }
 }
 
+impl Packed<FigureElem> {
+    /// Lays the caption out next to the body, vertically centered. The caption
+    /// takes up the remaining width and wraps within it.
+    fn side_caption(
+        &self,
+        body: Content,
+        caption: Packed<FigureCaption>,
+        position: CaptionPosition,
+        styles: StyleChain,
+    ) -> Content {
+        let cell = |content: Content| {
+            GridChild::Item(GridItem::Cell(
+                Packed::new(GridCell::new(content)).spanned(self.span()),
+            ))
+        };
+
+        let caption = caption.pack();
+        let (children, columns) = if position == CaptionPosition::Left {
+            ([cell(caption), cell(body)], [Sizing::Fr(Fr::one()), Sizing::Auto])
+        } else {
+            ([cell(body), cell(caption)], [Sizing::Auto, Sizing::Fr(Fr::one())])
+        };
+
+        GridElem::new(children.into())
+            .with_columns(TrackSizings(columns.into_iter().collect()))
+            .with_column_gutter(TrackSizings(smallvec![self.gap(styles).into()]))
+            .with_align(Celled::Value(Smart::Custom(Alignment::HORIZON)))
+            .pack()
+            .spanned(self.span())
+    }
+}
+
 impl ShowSet for Packed<FigureElem> {
     fn show_set(&self, _: StyleChain) -> Styles {
-        // Still allows breakable figures with
-        // `show figure: set block(breakable: true)`.
+        // Figures only break if they don't fit into a full region. This still
+        // allows unbreakable figures with
+        // `show figure: set block(breakable: false)`.
         let mut map = Styles::new();
-        map.set(BlockElem::set_breakable(false));
+        map.set(BlockElem::set_keep_together(true));
         map.set(AlignElem::set_alignment(Alignment::CENTER));
         map
     }
@@ -486,7 +540,13 @@ impl Outlinable for Packed<FigureElem> {
 /// ```
 #[elem(name = "caption", Synthesize, Show)]
 pub struct FigureCaption {
-    /// The caption's position in the figure. Either `{top}` or `{bottom}`.
+    /// The caption's position in the figure. Either `{top}`, `{bottom}`,
+    /// `{left}`, or `{right}`.
+    ///
+    /// Captions on the left or right are vertically centered next to the body
+    /// and take up the remaining width, wrapping within it. The figure's
+    /// [`gap`]($figure.gap) is used as the horizontal distance between the
+    /// body and the caption.
     ///
     /// ```example
     /// #show figure.where(
@@ -510,9 +570,17 @@ pub struct FigureCaption {
     ///     [I'm down here too!]
     ///   )
     /// )
+    ///
+    /// #figure(
+    ///   rect[Hey],
+    ///   caption: figure.caption(
+    ///     position: right,
+    ///     [I'm on the side],
+    ///   )
+    /// )
     /// ```
-    #[default(OuterVAlignment::Bottom)]
-    pub position: OuterVAlignment,
+    #[default(CaptionPosition::Bottom)]
+    pub position: CaptionPosition,
 
     /// The separator which will appear between the number and body.
     ///
@@ -640,6 +708,53 @@ cast! {
     v: Content => v.unpack::<Self>().unwrap_or_else(Self::new),
 }
 
+/// Where a caption is placed relative to the body of its figure.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CaptionPosition {
+    /// Above the body.
+    Top,
+    /// Below the body.
+    Bottom,
+    /// To the left of the body.
+    Left,
+    /// To the right of the body.
+    Right,
+}
+
+impl CaptionPosition {
+    /// Whether the caption is placed beside the body.
+    pub fn is_side(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+}
+
+impl TryFrom<Alignment> for CaptionPosition {
+    type Error = EcoString;
+
+    fn try_from(value: Alignment) -> StrResult<Self> {
+        match value {
+            Alignment::V(VAlignment::Top) => Ok(Self::Top),
+            Alignment::V(VAlignment::Bottom) => Ok(Self::Bottom),
+            Alignment::H(HAlignment::Left) => Ok(Self::Left),
+            Alignment::H(HAlignment::Right) => Ok(Self::Right),
+            v => {
+                bail!("expected `top`, `bottom`, `left`, or `right`, found {}", v.repr())
+            }
+        }
+    }
+}
+
+cast! {
+    CaptionPosition,
+    self => match self {
+        Self::Top => Alignment::TOP,
+        Self::Bottom => Alignment::BOTTOM,
+        Self::Left => Alignment::LEFT,
+        Self::Right => Alignment::RIGHT,
+    }.into_value(),
+    align: Alignment => Self::try_from(align)?,
+}
+
 /// The `kind` parameter of a [`FigureElem`].
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum FigureKind {
@@ -237,6 +237,23 @@ pub struct FootnoteEntry {
     )]
     pub separator: Content,
 
+    /// The separator above the remainder of a footnote that was broken across
+    /// regions. If set to `{auto}`, the regular
+    /// [`separator`]($footnote.entry.separator) is used.
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #set footnote.entry(
+    ///   continuation: line(length: 100%, stroke: 0.5pt)
+    /// )
+    ///
+    /// #v(1fr)
+    /// A long footnote #footnote[
+    ///   #lorem(40)
+    /// ]
+    /// ```
+    pub continuation: Smart<Content>,
+
     /// The amount of clearance between the document body and the separator.
     ///
     /// ```example
@@ -275,6 +292,21 @@ pub struct FootnoteEntry {
     /// ```
     #[default(Em::new(1.0).into())]
     pub indent: Length,
+
+    /// The minimum amount of body content that must precede a footnote's
+    /// marker in a region for the entry to be placed in the same region.
+    ///
+    /// If a footnote is referenced with less body content above it, e.g. in
+    /// the first line of a page, its entry is deferred to the next region.
+    /// By default, footnote entries are always placed in the region of their
+    /// marker if they fit.
+    ///
+    /// ```typ
+    /// #set footnote.entry(min-body: 3em)
+    /// ```
+    #[default(Abs::zero().into())]
+    #[resolve]
+    pub min_body: Length,
 }
 
 impl Show for Packed<FootnoteEntry> {
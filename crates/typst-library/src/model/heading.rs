@@ -1,12 +1,14 @@
 use std::num::NonZeroUsize;
 
+use comemo::Tracked;
+use typst_syntax::Span;
 use typst_utils::NonZeroExt;
 
-use crate::diag::SourceResult;
+use crate::diag::{At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Content, NativeElement, Packed, Resolve, Show, ShowSet, Smart, StyleChain,
-    Styles, Synthesize, TargetElem,
+    elem, func, scope, Content, Context, NativeElement, Packed, Resolve, Show, ShowSet,
+    Smart, StyleChain, Styles, Synthesize, TargetElem,
 };
 use crate::html::{tag, HtmlElem};
 use crate::introspection::{
@@ -48,7 +50,9 @@ use crate::text::{FontWeight, LocalName, SpaceElem, TextElem, TextSize};
 /// one or multiple equals signs, followed by a space. The number of equals
 /// signs determines the heading's logical nesting depth. The `{offset}` field
 /// can be set to configure the starting depth.
-#[elem(Locatable, Synthesize, Count, Show, ShowSet, LocalName, Refable, Outlinable)]
+#[elem(
+    scope, Locatable, Synthesize, Count, Show, ShowSet, LocalName, Refable, Outlinable
+)]
 pub struct HeadingElem {
     /// The absolute nesting depth of the heading, starting from one. If set
     /// to `{auto}`, it is computed from `{offset + depth}`.
@@ -184,6 +188,61 @@ pub struct HeadingElem {
     pub body: Content,
 }
 
+#[scope]
+impl HeadingElem {
+    /// Retrieves the title of the heading that is in effect on the current
+    /// page. This is useful for running heads.
+    ///
+    /// This is the last heading of the given level that starts on or before
+    /// the current page. A heading of a higher level (that is, with a smaller
+    /// level number) ends the effect of the headings before it, so a section
+    /// from an earlier chapter does not carry over into the next chapter. If
+    /// no heading is in effect, the result is empty.
+    ///
+    /// ```example
+    /// >>> #set page(width: 120pt, height: 80pt)
+    /// #set page(header: context {
+    ///   heading.running(level: 1)
+    ///   h(1fr)
+    ///   heading.running(level: 2)
+    /// })
+    ///
+    /// = Chapter
+    /// == Section
+    /// ```
+    #[func(contextual)]
+    pub fn running(
+        /// The engine.
+        engine: &mut Engine,
+        /// The callsite context.
+        context: Tracked<Context>,
+        /// The callsite span.
+        span: Span,
+        /// The level of the heading to retrieve.
+        #[named]
+        #[default(NonZeroUsize::ONE)]
+        level: NonZeroUsize,
+    ) -> SourceResult<Content> {
+        let page = engine.introspector.page(context.location().at(span)?);
+        let mut current = None;
+        for elem in engine.introspector.query(&Self::elem().select()) {
+            if engine.introspector.page(elem.location().unwrap()) > page {
+                break;
+            }
+
+            let heading = elem.to_packed::<Self>().unwrap();
+            let depth = heading.resolve_level(StyleChain::default());
+            if depth < level {
+                current = None;
+            } else if depth == level {
+                current = Some(heading.body().clone());
+            }
+        }
+
+        Ok(current.unwrap_or_default())
+    }
+}
+
 impl HeadingElem {
     pub fn resolve_level(&self, styles: StyleChain) -> NonZeroUsize {
         self.level(styles).unwrap_or_else(|| {
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use ecow::EcoString;
+
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, scope, Content, NativeElement, Packed, Show, StyleChain};
+use crate::introspection::{Counter, CounterKey, Locatable, Location};
+use crate::model::{Numbering, NumberingPattern, ParbreakElem, StrongElem};
+use crate::text::TextElem;
+
+/// Marks a term for the index.
+///
+/// The marker does not produce any visible content. Instead, it registers its
+/// term together with its location in the document, so that
+/// [`make-index`]($make-index) can list the pages on which the term occurs.
+///
+/// # Example
+/// ```example
+/// Typst is a markup-based
+/// typesetting system.
+/// #index("Typst")
+/// #index("Markup")
+///
+/// #make-index()
+/// ```
+#[elem(scope, Show, Locatable)]
+pub struct IndexElem {
+    /// The term under which this location is listed in the index.
+    #[required]
+    pub term: EcoString,
+}
+
+#[scope]
+impl IndexElem {
+    #[elem]
+    type IndexEntry;
+}
+
+impl Show for Packed<IndexElem> {
+    fn show(&self, _: &mut Engine, _styles: StyleChain) -> SourceResult<Content> {
+        Ok(Content::empty())
+    }
+}
+
+/// An alphabetical index of all terms marked with [`index`]($index).
+///
+/// The terms are grouped by their first letter. Each term is listed once,
+/// followed by the pages on which it was marked, displayed with the page
+/// numbering in effect there. Runs of consecutive pages with the same
+/// numbering are collapsed into a range.
+///
+/// ```example
+/// #index("Apple")
+/// #index("Banana")
+/// #index("Avocado")
+///
+/// #make-index()
+/// ```
+#[elem(Show, Locatable)]
+pub struct MakeIndexElem {
+    /// The separator between the first and the last page of a range.
+    ///
+    /// ```example
+    /// #set make-index(range-separator: [ to ])
+    ///
+    /// #index("Lemon")
+    /// #pagebreak()
+    /// #index("Lemon")
+    ///
+    /// #make-index()
+    /// ```
+    #[default(TextElem::packed("–"))]
+    #[borrowed]
+    pub range_separator: Content,
+}
+
+impl Show for Packed<MakeIndexElem> {
+    #[typst_macros::time(name = "make-index", span = self.span())]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        // Collect the pages of each term.
+        let mut terms: BTreeMap<(EcoString, EcoString), Vec<IndexPage>> = BTreeMap::new();
+        for elem in engine.introspector.query(&IndexElem::elem().select()) {
+            let location = elem.location().unwrap();
+            let term = elem.to_packed::<IndexElem>().unwrap().term().clone();
+            let page = IndexPage {
+                index: engine.introspector.page(location).get(),
+                number: Counter::new(CounterKey::Page).at_loc(engine, location)?.first(),
+                numbering: engine
+                    .introspector
+                    .page_numbering(location)
+                    .cloned()
+                    .unwrap_or_else(|| NumberingPattern::from_str("1").unwrap().into()),
+                location,
+            };
+            terms.entry((term.to_lowercase(), term)).or_default().push(page);
+        }
+
+        let separator = self.range_separator(styles);
+        let mut seq = vec![ParbreakElem::shared().clone()];
+        let mut group: Option<EcoString> = None;
+        for ((key, term), mut pages) in terms {
+            let letter: Option<EcoString> =
+                key.chars().next().map(|c| c.to_uppercase().collect());
+            if group != letter {
+                if let Some(letter) = &letter {
+                    seq.push(StrongElem::new(TextElem::packed(letter.clone())).pack());
+                    seq.push(ParbreakElem::shared().clone());
+                }
+                group = letter;
+            }
+
+            pages.sort_by_key(|page| (page.index, page.number));
+            pages.dedup_by(|b, a| a.number == b.number && a.numbering == b.numbering);
+
+            let mut parts = vec![];
+            for run in collapse_pages(&pages) {
+                if !parts.is_empty() {
+                    parts.push(TextElem::packed(", "));
+                }
+                let (first, last) = (&run[0], &run[run.len() - 1]);
+                parts.push(first.display(engine, styles)?);
+                if run.len() > 1 {
+                    parts.push(separator.clone());
+                    parts.push(last.display(engine, styles)?);
+                }
+            }
+
+            let pages = Content::sequence(parts);
+            seq.push(IndexEntry::new(term, pages).pack().spanned(self.span()));
+            seq.push(ParbreakElem::shared().clone());
+        }
+
+        Ok(Content::sequence(seq))
+    }
+}
+
+/// A page on which a term was marked.
+struct IndexPage {
+    /// The physical page index.
+    index: usize,
+    /// The page number at the marker.
+    number: usize,
+    /// The page numbering at the marker.
+    numbering: Numbering,
+    /// The location of the marker.
+    location: Location,
+}
+
+impl IndexPage {
+    /// Display the page number with its page's numbering.
+    fn display(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Counter::new(CounterKey::Page).display_at_loc(
+            engine,
+            self.location,
+            styles,
+            &self.numbering,
+        )
+    }
+}
+
+/// Splits sorted and de-duplicated pages into runs of consecutive page
+/// numbers with the same numbering.
+fn collapse_pages(pages: &[IndexPage]) -> impl Iterator<Item = &[IndexPage]> {
+    pages.chunk_by(|a, b| b.number == a.number + 1 && a.numbering == b.numbering)
+}
+
+/// Represents a term in the index.
+///
+/// This element is created by [`make-index`]($make-index) for each distinct
+/// term. It can be customized with show rules.
+///
+/// ```example
+/// #show index.entry: it => [#it.term (#it.pages)]
+///
+/// #index("Kerning")
+/// #make-index()
+/// ```
+#[elem(name = "entry", title = "Index Entry", Show)]
+pub struct IndexEntry {
+    /// The indexed term.
+    #[required]
+    pub term: EcoString,
+
+    /// The pages on which the term occurs, displayed with their page
+    /// numbering, sorted and with runs of consecutive pages collapsed into
+    /// ranges.
+    #[required]
+    pub pages: Content,
+}
+
+impl Show for Packed<IndexEntry> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(Content::sequence([
+            TextElem::packed(self.term().clone()),
+            TextElem::packed(", "),
+            self.pages().clone(),
+        ]))
+    }
+}
@@ -3,7 +3,7 @@ use std::ops::Deref;
 use ecow::{eco_format, EcoString};
 use smallvec::SmallVec;
 
-use crate::diag::{bail, warning, At, SourceResult, StrResult};
+use crate::diag::{bail, warning, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, Content, Label, NativeElement, Packed, Repr, Show, Smart, StyleChain,
@@ -123,7 +123,7 @@ impl Show for Packed<LinkElem> {
             let linked = match self.dest() {
                 LinkTarget::Dest(dest) => body.linked(dest.clone()),
                 LinkTarget::Label(label) => {
-                    let elem = engine.introspector.query_label(*label).at(self.span())?;
+                    let elem = engine.introspector.query_label_at(*label, self.span())?;
                     let dest = Destination::Location(elem.location().unwrap());
                     body.clone().linked(dest)
                 }
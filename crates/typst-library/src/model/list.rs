@@ -7,6 +7,7 @@ use crate::foundations::{
     Smart, StyleChain, Styles, TargetElem, Value,
 };
 use crate::html::{tag, HtmlElem};
+use crate::introspection::Locatable;
 use crate::layout::{BlockElem, Em, Length, VElem};
 use crate::model::ParElem;
 use crate::text::TextElem;
@@ -42,7 +43,7 @@ use crate::text::TextElem;
 /// followed by a space to create a list item. A list item can contain multiple
 /// paragraphs and other block-level content. All content that is indented
 /// more than an item's marker becomes part of that item.
-#[elem(scope, title = "Bullet List", Show)]
+#[elem(scope, title = "Bullet List", Locatable, Show)]
 pub struct ListElem {
     /// Defines the default [spacing]($list.spacing) of the list. If it is
     /// `{false}`, the items are spaced apart with
@@ -98,19 +99,54 @@ pub struct ListElem {
     pub marker: ListMarker,
 
     /// The indent of each item.
+    ///
+    /// This is the space before the marker. The space between the marker and
+    /// the body is controlled separately through the
+    /// [`body-indent`]($list.body-indent) property.
     #[resolve]
     pub indent: Length,
 
     /// The spacing between the marker and the body of each item.
+    ///
+    /// All lines of an item's body, including wrapped ones, start at this
+    /// distance from the marker.
+    ///
+    /// ```example
+    /// #set list(indent: 0pt, body-indent: 2em)
+    /// - Markers are flush with the
+    ///   surrounding text, while the
+    ///   bodies are indented.
+    /// ```
     #[resolve]
     #[default(Em::new(0.5).into())]
     pub body_indent: Length,
 
+    /// Whether the markers hang into the margin before the list.
+    ///
+    /// If this is enabled, the marker and the [body indent]($list.body-indent)
+    /// are placed before the start edge of the text, so that the bodies of
+    /// the items are flush with the surrounding paragraphs. The
+    /// [`indent`]($list.indent) still moves the whole item inwards.
+    ///
+    /// ```example
+    /// #set list(hanging: true)
+    /// Paragraphs and items
+    /// - start at the same
+    /// - edge of the text.
+    /// ```
+    #[default(false)]
+    pub hanging: bool,
+
     /// The spacing between the items of the list.
     ///
     /// If set to `{auto}`, uses paragraph [`leading`]($par.leading) for tight
     /// lists and paragraph [`spacing`]($par.spacing) for wide (non-tight)
     /// lists.
+    ///
+    /// The spacing around the list collapses with the spacing of surrounding
+    /// paragraphs, so a wide list is separated from them by a single
+    /// paragraph spacing. To change it, use a show-set rule for the
+    /// [block]($block.spacing), as in `[#show list: set block(spacing: 1em)]`.
     pub spacing: Smart<Length>,
 
     /// The bullet list's children.
@@ -9,6 +9,7 @@ mod enum_;
 mod figure;
 mod footnote;
 mod heading;
+mod index;
 mod link;
 mod list;
 #[path = "numbering.rs"]
@@ -17,6 +18,7 @@ mod outline;
 mod par;
 mod quote;
 mod reference;
+mod sidenote;
 mod strong;
 mod table;
 mod terms;
@@ -29,6 +31,7 @@ pub use self::enum_::*;
 pub use self::figure::*;
 pub use self::footnote::*;
 pub use self::heading::*;
+pub use self::index::*;
 pub use self::link::*;
 pub use self::list::*;
 pub use self::numbering_::*;
@@ -36,6 +39,7 @@ pub use self::outline::*;
 pub use self::par::*;
 pub use self::quote::*;
 pub use self::reference::*;
+pub use self::sidenote::*;
 pub use self::strong::*;
 pub use self::table::*;
 pub use self::terms::*;
@@ -59,7 +63,10 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<OutlineElem>();
     global.define_elem::<HeadingElem>();
     global.define_elem::<FigureElem>();
+    global.define_elem::<IndexElem>();
+    global.define_elem::<MakeIndexElem>();
     global.define_elem::<FootnoteElem>();
+    global.define_elem::<SidenoteElem>();
     global.define_elem::<QuoteElem>();
     global.define_elem::<CiteElem>();
     global.define_elem::<BibliographyElem>();
@@ -174,6 +174,9 @@ pub struct OutlineElem {
     /// Content to fill the space between the title and the page number. Can be
     /// set to `{none}` to disable filling.
     ///
+    /// The fill is [repeated]($repeat) as often as needed if it is wrapped in
+    /// `repeat`, so `{repeat[-]}` results in a dashed leader.
+    ///
     /// ```example
     /// #outline(fill: line(length: 100%))
     ///
@@ -5,11 +5,11 @@ use typst_utils::singleton;
 use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, scope, Args, Cast, Construct, Content, NativeElement, Packed, Set, Smart,
-    StyleVec, Unlabellable,
+    elem, scope, Args, Cast, Construct, Content, NativeElement, Packed, Regex, Set,
+    Smart, StyleVec, Unlabellable,
 };
 use crate::introspection::{Count, CounterUpdate, Locatable};
-use crate::layout::{Em, HAlignment, Length, OuterHAlignment};
+use crate::layout::{Abs, Em, HAlignment, Length, OuterHAlignment, Ratio};
 use crate::model::Numbering;
 
 /// Arranges text, spacing and inline-level elements into a paragraph.
@@ -37,7 +37,7 @@ use crate::model::Numbering;
 /// let $a$ be the smallest of the
 /// three integers. Then, we ...
 /// ```
-#[elem(scope, title = "Paragraph", Debug, Construct)]
+#[elem(scope, title = "Paragraph", Debug, Construct, Locatable)]
 pub struct ParElem {
     /// The spacing between lines.
     ///
@@ -57,6 +57,22 @@ pub struct ParElem {
     #[default(Em::new(0.65).into())]
     pub leading: Length,
 
+    /// How the [leading]($par.leading) is measured.
+    ///
+    /// With `{"edges"}`, the leading is the gap between the edges of two lines.
+    /// With `{"exact"}`, it is instead the exact distance between their
+    /// baselines, regardless of the fonts' ascent and descent.
+    ///
+    /// ```example
+    /// #set par(spacing-mode: "exact", leading: 1.5em)
+    /// Lines are \
+    /// #text(font: "DejaVu Sans Mono")[exactly] \
+    /// evenly spaced.
+    /// ```
+    #[ghost]
+    #[default(SpacingMode::Edges)]
+    pub spacing_mode: SpacingMode,
+
     /// The spacing between paragraphs.
     ///
     /// Just like leading, this defines the spacing between the bottom edge of a
@@ -72,6 +88,30 @@ pub struct ParElem {
     #[default(Em::new(1.2).into())]
     pub spacing: Length,
 
+    /// The spacing after short paragraphs, relative to the regular
+    /// [spacing]($par.spacing).
+    ///
+    /// By default, all paragraphs are followed by the same spacing. For
+    /// compact layouts, this can be set to reduce the spacing after
+    /// paragraphs with few lines: The spacing after a single-line paragraph
+    /// is scaled by the given ratio. For a paragraph with `n` lines, the
+    /// reduction is `n` times smaller, so that long paragraphs are followed by
+    /// almost the regular spacing. The reduced spacing also takes precedence
+    /// over the spacing of a directly following paragraph.
+    ///
+    /// ```example
+    /// #set par(short-spacing: 40%)
+    /// Short.
+    ///
+    /// Also short.
+    ///
+    /// #lorem(20)
+    ///
+    /// After a longer paragraph.
+    /// ```
+    #[ghost]
+    pub short_spacing: Option<Ratio>,
+
     /// Whether to justify text in its line.
     ///
     /// Hyphenation will be enabled for justified paragraphs if the
@@ -85,6 +125,68 @@ pub struct ParElem {
     #[default(false)]
     pub justify: bool,
 
+    /// The narrowest measure in which text is still justified.
+    ///
+    /// In very narrow columns, justification tends to open up large gaps
+    /// between words. If the available width for a justified paragraph is
+    /// smaller than this value, the paragraph is set ragged instead. By
+    /// default, justified text is always justified.
+    ///
+    /// ```example
+    /// #set par(justify: true, justify-min-width: 4cm)
+    /// #block(width: 3cm)[
+    ///   This narrow column is set ragged to avoid huge gaps.
+    /// ]
+    /// #block(width: 6cm)[
+    ///   This wider column is justified just like normal.
+    /// ]
+    /// ```
+    #[ghost]
+    #[resolve]
+    pub justify_min_width: Length,
+
+    /// How much the spaces of justified lines may be stretched before the
+    /// paragraph falls back to ragged text.
+    ///
+    /// The limit is given relative to the natural stretchability of a line:
+    /// At `{100%}`, spaces may grow by half their natural width. If more than
+    /// half of the justified lines of a paragraph would need to be stretched
+    /// beyond this limit, the whole paragraph is set ragged instead. When set
+    /// to `{none}`, the default, justified paragraphs never fall back.
+    #[ghost]
+    pub justify_limit: Option<Ratio>,
+
+    /// How much the spaces of justified lines may be stretched.
+    ///
+    /// Like the [`justify-limit`]($par.justify-limit), this is relative to the
+    /// natural stretchability of a line. Line breaks that would stretch the
+    /// spaces of a line beyond this ratio are avoided as long as the
+    /// paragraph can be broken differently. When set to `{none}`, the
+    /// default, loose lines are only discouraged by their usual cost.
+    #[ghost]
+    pub justify_stretch: Option<Ratio>,
+
+    /// How much the spaces of justified lines may be shrunk.
+    ///
+    /// This is relative to the natural shrinkability of a line: At `{100%}`,
+    /// the default, spaces may shrink as far as they can. Line breaks that
+    /// would shrink the spaces of a line beyond this ratio are only chosen if
+    /// the text does not fit otherwise. Lowering this ratio trades tight lines
+    /// for loose ones.
+    ///
+    /// ```example
+    /// #set par(justify: true)
+    /// #let body = [
+    ///   This paragraph is justified with spaces that may only shrink a bit.
+    /// ]
+    ///
+    /// #block(width: 4.5cm, body)
+    /// #block(width: 4.5cm, par(justify-shrink: 20%, body))
+    /// ```
+    #[ghost]
+    #[default(Ratio::one())]
+    pub justify_shrink: Ratio,
+
     /// How to determine line breaks.
     ///
     /// When this property is set to `{auto}`, its default value, optimized line
@@ -109,6 +211,58 @@ pub struct ParElem {
     #[ghost]
     pub linebreaks: Smart<Linebreaks>,
 
+    /// Patterns within which lines are never broken.
+    ///
+    /// Line break opportunities that fall strictly inside a match of any of
+    /// these regular expressions are discarded. This lets you keep things
+    /// like a number and its unit together throughout the document without
+    /// inserting non-breaking spaces by hand. Breaks at the start and end of
+    /// a match are unaffected.
+    ///
+    /// ```example
+    /// #set page(width: 80pt)
+    /// #set par(unbreakable: (regex("\d+ (kg|km|m)"),))
+    /// The parcel weighs 5 kg in total.
+    /// ```
+    #[ghost]
+    pub unbreakable: Vec<Regex>,
+
+    /// Whether to break words that don't fit into a line on their own.
+    ///
+    /// Normally, a word that is wider than the line, like a long URL or the
+    /// name of a chemical compound, overflows into the margin. When this is
+    /// enabled, such words are broken between arbitrary characters as a last
+    /// resort. Words that fit are never broken like this.
+    ///
+    /// ```example
+    /// #set page(width: 80pt)
+    /// #set par(emergency-breaks: true)
+    /// See https://example.com/a/very/long/path.
+    /// ```
+    #[ghost]
+    #[default(false)]
+    pub emergency_breaks: bool,
+
+    /// How full a line must at least be before it may end with a hyphenated
+    /// word.
+    ///
+    /// When a line would end with a hyphenated word although it is filled less
+    /// than the given ratio of its width, the hyphenation opportunity is
+    /// ignored and the whole word moves to the next line instead. This avoids
+    /// hyphenating when there is plenty of room anyway.
+    ///
+    /// ```example
+    /// #set page(width: 120pt)
+    /// #set text(hyphenate: true)
+    /// #set par(linebreaks: "simple")
+    /// A characteristically \
+    /// #set par(hyphenation-fill: 90%)
+    /// A characteristically
+    /// ```
+    #[ghost]
+    #[default(Ratio::zero())]
+    pub hyphenation_fill: Ratio,
+
     /// The indent the first line of a paragraph should have.
     ///
     /// Only the first line of a consecutive paragraph will be indented (not
@@ -126,6 +280,13 @@ pub struct ParElem {
     #[resolve]
     pub hanging_indent: Length,
 
+    /// How much the first lines of the paragraph are shortened from the left
+    /// and the right. Set by flow layout to make text flow around page
+    /// exclusions.
+    #[internal]
+    #[ghost]
+    pub insets: Vec<(Abs, Abs)>,
+
     /// The contents of the paragraph.
     #[external]
     #[required]
@@ -177,6 +338,17 @@ pub enum Linebreaks {
     Optimized,
 }
 
+/// How the leading between the lines of a paragraph or the spacing around a
+/// block is measured.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum SpacingMode {
+    /// The spacing is the gap between the bottom edge of one line and the top
+    /// edge of the next.
+    Edges,
+    /// The spacing is the distance between the baselines of two lines.
+    Exact,
+}
+
 /// A paragraph break.
 ///
 /// This starts a new paragraph. Especially useful when used within code like
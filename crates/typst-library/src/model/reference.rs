@@ -1,18 +1,19 @@
 use comemo::Track;
 use ecow::eco_format;
+use typst_utils::SliceExt;
 
 use crate::diag::{bail, At, Hint, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Cast, Content, Context, Func, IntoValue, Label, NativeElement, Packed,
-    Show, Smart, StyleChain, Synthesize,
+    cast, elem, Args, Cast, Construct, Content, Context, Func, IntoValue, Label,
+    NativeElement, Packed, Show, Smart, StyleChain, Synthesize,
 };
-use crate::introspection::{Counter, CounterKey, Locatable};
+use crate::introspection::{Counter, CounterKey, Locatable, Location};
 use crate::math::EquationElem;
 use crate::model::{
     BibliographyElem, CiteElem, Destination, Figurable, FootnoteElem, Numbering,
 };
-use crate::text::TextElem;
+use crate::text::{localized_plural, localized_str, Lang, SpaceElem, TextElem};
 
 /// A reference to a label or bibliography.
 ///
@@ -161,6 +162,61 @@ pub struct RefElem {
     #[default(RefForm::Normal)]
     pub form: RefForm,
 
+    /// The separator between the supplement and the number.
+    ///
+    /// Defaults to a non-breaking space, which keeps the supplement on the
+    /// same line as the number. Like the supplement, this can be a function
+    /// that receives the referenced element, so that different kinds of
+    /// elements can use different separators.
+    ///
+    /// ```example
+    /// #set figure(supplement: [Fig.])
+    /// #set ref(separator: sym.space.nobreak.narrow)
+    /// #figure(rect(), caption: [A box]) <box>
+    ///
+    /// See @box.
+    /// ```
+    #[borrowed]
+    #[default(Supplement::Content(TextElem::packed("\u{a0}")))]
+    pub separator: Supplement,
+
+    /// Whether to collapse adjacent references to elements of the same kind.
+    ///
+    /// When enabled, references that are only separated by spaces and share
+    /// the same supplement are combined into one: The supplement is shown
+    /// once, followed by all numbers. For more than one number, the
+    /// [plural supplement]($ref.plural) is used. Each number still links to its
+    /// element.
+    ///
+    /// ```example
+    /// #set ref(group: true)
+    /// #figure(rect(), caption: [A]) <a>
+    /// #figure(circle(), caption: [B]) <b>
+    ///
+    /// See @a @b.
+    /// ```
+    #[default(false)]
+    pub group: bool,
+
+    /// The supplement for a collapsed [group]($ref.group) of references.
+    ///
+    /// When `{auto}`, this is the localized plural of the supplement if it is
+    /// the default name of the referenced element, like "Figures" for
+    /// "Figure". For other supplements, it is the supplement itself.
+    ///
+    /// Like the supplement, this can be a function that receives the first
+    /// referenced element of the group.
+    ///
+    /// ```example
+    /// #set ref(group: true, plural: [Figs.])
+    /// #figure(rect(), caption: [A]) <a>
+    /// #figure(circle(), caption: [B]) <b>
+    ///
+    /// See @a @b.
+    /// ```
+    #[borrowed]
+    pub plural: Smart<Option<Supplement>>,
+
     /// A synthesized citation.
     #[synthesized]
     pub citation: Option<Packed<CiteElem>>,
@@ -198,12 +254,12 @@ impl Show for Packed<RefElem> {
     #[typst_macros::time(name = "ref", span = self.span())]
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let target = *self.target();
-        let elem = engine.introspector.query_label(target);
         let span = self.span();
+        let elem = engine.introspector.query_label_at(target, span);
 
         let form = self.form(styles);
         if form == RefForm::Page {
-            let elem = elem.at(span)?;
+            let elem = elem?;
             let elem = elem.clone();
 
             let loc = elem.location().unwrap();
@@ -237,7 +293,7 @@ impl Show for Packed<RefElem> {
             return Ok(to_citation(self, engine, styles)?.pack().spanned(span));
         }
 
-        let elem = elem.at(span)?;
+        let elem = elem?;
 
         if let Some(footnote) = elem.to_packed::<FootnoteElem>() {
             return Ok(footnote.into_ref(target).pack().spanned(span));
@@ -299,20 +355,140 @@ fn show_reference(
     let loc = elem.location().unwrap();
     let numbers = counter.display_at_loc(engine, loc, styles, &numbering.trimmed())?;
 
+    let separator =
+        reference.separator(styles).resolve(engine, styles, [elem.clone()])?;
     let supplement = match reference.supplement(styles).as_ref() {
         Smart::Auto => supplement,
         Smart::Custom(None) => Content::empty(),
-        Smart::Custom(Some(supplement)) => supplement.resolve(engine, styles, [elem])?,
+        Smart::Custom(Some(supplement)) => {
+            supplement.resolve(engine, styles, [elem.clone()])?
+        }
     };
 
+    if reference.group(styles) {
+        let plural = match reference.plural(styles).as_ref() {
+            Smart::Auto => localized_plural(
+                TextElem::lang_in(styles),
+                TextElem::region_in(styles),
+                &supplement.plain_text(),
+            )
+            .map_or_else(|| supplement.clone(), TextElem::packed),
+            Smart::Custom(None) => Content::empty(),
+            Smart::Custom(Some(plural)) => plural.resolve(engine, styles, [elem])?,
+        };
+        return Ok(RefPart::new(supplement, plural, separator, numbers, loc)
+            .pack()
+            .spanned(reference.span()));
+    }
+
     let mut content = numbers;
     if !supplement.is_empty() {
-        content = supplement + TextElem::packed("\u{a0}") + content;
+        content = supplement + separator + content;
     }
 
     Ok(content.linked(Destination::Location(loc)))
 }
 
+/// A shown reference that may still be collapsed with adjacent ones.
+///
+/// References produce this instead of their final content if
+/// [`group`]($ref.group) is enabled. Adjacent parts are then collected into a
+/// [`RefGroup`] during show rule application.
+#[elem(Construct)]
+pub struct RefPart {
+    /// The resolved supplement.
+    #[required]
+    #[internal]
+    pub supplement: Content,
+
+    /// The resolved supplement for a group of references.
+    #[required]
+    #[internal]
+    pub plural: Content,
+
+    /// The resolved separator between supplement and number.
+    #[required]
+    #[internal]
+    pub separator: Content,
+
+    /// The displayed number.
+    #[required]
+    #[internal]
+    pub number: Content,
+
+    /// The location of the referenced element.
+    #[required]
+    #[internal]
+    pub target: Location,
+}
+
+impl Construct for RefPart {
+    fn construct(_: &mut Engine, args: &mut Args) -> SourceResult<Content> {
+        bail!(args.span, "cannot be constructed manually");
+    }
+}
+
+/// A group of references.
+///
+/// This is automatically created from adjacent references during show rule
+/// application.
+#[elem(Construct, Show)]
+pub struct RefGroup {
+    /// The references.
+    #[required]
+    #[internal]
+    pub children: Vec<Packed<RefPart>>,
+}
+
+impl Construct for RefGroup {
+    fn construct(_: &mut Engine, args: &mut Args) -> SourceResult<Content> {
+        bail!(args.span, "cannot be constructed manually");
+    }
+}
+
+impl Show for Packed<RefGroup> {
+    #[typst_macros::time(name = "ref", span = self.span())]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let lang = TextElem::lang_in(styles);
+        let and = localized_str(lang, TextElem::region_in(styles), "and");
+        let mut seq = vec![];
+
+        // Combine runs of references with the same supplement.
+        for (supplement, run) in
+            self.children.group_by_key(|part| part.supplement.clone())
+        {
+            if !seq.is_empty() {
+                seq.push(SpaceElem::shared().clone());
+            }
+
+            let link = |part: &Packed<RefPart>| Destination::Location(part.target);
+
+            let mut first = run[0].number.clone();
+            let supplement =
+                if run.len() > 1 { run[0].plural.clone() } else { supplement };
+            if !supplement.is_empty() {
+                first = supplement + run[0].separator.clone() + first;
+            }
+            seq.push(first.linked(link(&run[0])));
+
+            for (i, part) in run.iter().enumerate().skip(1) {
+                let last = i + 1 == run.len();
+                // Only English puts a serial comma before the conjunction.
+                let separator = match (last, run.len()) {
+                    (true, 2) => eco_format!(" {and} "),
+                    (true, _) if lang == Lang::ENGLISH => eco_format!(", {and} "),
+                    (true, _) => eco_format!(" {and} "),
+                    (false, _) => ", ".into(),
+                };
+                seq.push(TextElem::packed(separator));
+                seq.push(part.number.clone().linked(link(part)));
+            }
+        }
+
+        Ok(Content::sequence(seq))
+    }
+}
+
 /// Turn a reference into a citation.
 fn to_citation(
     reference: &Packed<RefElem>,
@@ -0,0 +1,67 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, Packed, Resolve, Show, StyleChain, Synthesize};
+use crate::introspection::Locatable;
+use crate::layout::{Em, HideElem, Length};
+
+/// A note in the outer page margin.
+///
+/// The note is placed next to the line in which it is referenced, with its
+/// first baseline aligned to the baseline of that line. When notes cluster
+/// too closely, later notes are pushed down so that they do not overlap.
+///
+/// Notes are placed in the right margin. On two-sided pages (with
+/// [`inside` and `outside` margins]($page.margin)), they are placed in the
+/// outside margin instead. To make room for them, you can use a wider or a
+/// [fractional]($fraction) margin.
+///
+/// # Example
+/// ```example
+/// #set page(margin: (right: 1fr))
+///
+/// Typst is a new markup-based
+/// typesetting system.
+/// #sidenote[Like LaTeX.]
+/// ```
+///
+/// The sidenote itself does not produce any content in the text. Notes are
+/// laid out with the styles that are active at the page level, so you may
+/// want to style their body directly, e.g. with `[#sidenote(text(8pt)[..])]`.
+#[elem(Locatable, Synthesize, Show)]
+pub struct SidenoteElem {
+    /// The horizontal distance between the text block and the note.
+    #[default(Em::new(1.0).into())]
+    pub gap: Length,
+
+    /// The minimum vertical distance between two notes.
+    #[default(Em::new(0.5).into())]
+    pub clearance: Length,
+
+    /// The content to put into the margin.
+    #[required]
+    pub body: Content,
+
+    /// Whether the note's anchor is hidden, in which case the note is left
+    /// out, too.
+    #[internal]
+    #[synthesized]
+    pub hidden: bool,
+}
+
+impl Synthesize for Packed<SidenoteElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        // The note is laid out with the page's styles, so we resolve relative
+        // lengths with the styles at the anchor.
+        let elem = self.as_mut();
+        elem.push_gap(elem.gap(styles).resolve(styles).into());
+        elem.push_clearance(elem.clearance(styles).resolve(styles).into());
+        elem.push_hidden(HideElem::hidden_in(styles));
+        Ok(())
+    }
+}
+
+impl Show for Packed<SidenoteElem> {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(Content::empty())
+    }
+}
@@ -8,10 +8,11 @@ use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, scope, Content, NativeElement, Packed, Show, Smart, StyleChain,
 };
+use crate::introspection::Locatable;
 use crate::layout::{
-    show_grid_cell, Abs, Alignment, BlockElem, Celled, GridCell, GridFooter, GridHLine,
-    GridHeader, GridVLine, Length, OuterHAlignment, OuterVAlignment, Rel, Sides,
-    TrackSizings,
+    show_grid_cell, Abs, Alignment, BlockElem, Celled, ColumnMaxWidths, GridCell,
+    GridFooter, GridHLine, GridHeader, GridVLine, Length, OuterHAlignment,
+    OuterVAlignment, Rel, Sides, TrackSizings,
 };
 use crate::model::Figurable;
 use crate::text::LocalName;
@@ -117,7 +118,7 @@ use crate::visualize::{Paint, Stroke};
 ///   [Robert], b, a, b,
 /// )
 /// ```
-#[elem(scope, Show, LocalName, Figurable)]
+#[elem(scope, Locatable, Show, LocalName, Figurable)]
 pub struct TableElem {
     /// The column sizes. See the [grid documentation]($grid) for more
     /// information on track sizing.
@@ -150,6 +151,20 @@ pub struct TableElem {
     #[borrowed]
     pub row_gutter: TrackSizings,
 
+    /// The maximum widths of automatically sized columns. See the
+    /// [grid documentation]($grid.max-width) for more information.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   max-width: 3cm,
+    ///   [Name], [Description],
+    ///   [Typst], [A markup-based typesetting system.],
+    /// )
+    /// ```
+    #[borrowed]
+    pub max_width: ColumnMaxWidths,
+
     /// How to fill the cells.
     ///
     /// This can be a color or a function that returns a color. The function
@@ -233,6 +248,44 @@ pub struct TableElem {
     #[default(Celled::Value(Sides::splat(Some(Abs::pt(5.0).into()))))]
     pub inset: Celled<Sides<Option<Rel<Length>>>>,
 
+    /// Content to display in cells that were omitted.
+    ///
+    /// When fewer cells than there are positions in the table are given, e.g.
+    /// because the last row is incomplete or cells were placed at explicit
+    /// positions, the remaining positions are filled with cells showing this
+    /// content. Cells that were explicitly given empty content stay empty.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 3,
+    ///   empty: [---],
+    ///   [Name], [Born], [Died],
+    ///   [Ada Lovelace], [1815], [1852],
+    ///   [Grace Hopper], [1906],
+    /// )
+    /// ```
+    pub empty: Option<Content>,
+
+    /// Content to display below each part of a row that is broken across
+    /// pages or columns.
+    ///
+    /// When a breakable row does not fit into the remaining space, its cells
+    /// are split and continue in the next region. With this option, space is
+    /// reserved at the end of each unfinished part of the row to show a note
+    /// that the row continues. The last part of the row does not display the
+    /// note.
+    ///
+    /// ```example
+    /// #set page(height: 4cm)
+    /// #table(
+    ///   columns: 2,
+    ///   continued: align(end, emph[(continued)]),
+    ///   [Term], [Definition],
+    ///   [Typst], lorem(30),
+    /// )
+    /// ```
+    pub continued: Option<Content>,
+
     /// The contents of the table cells, plus any extra table lines specified
     /// with the [`table.hline`]($table.hline) and
     /// [`table.vline`]($table.vline) elements.
@@ -450,6 +503,10 @@ pub struct TableHeader {
 #[elem(name = "footer", title = "Table Footer")]
 pub struct TableFooter {
     /// Whether this footer should be repeated across pages.
+    ///
+    /// A footer that doesn't repeat only appears once, at the end of the last
+    /// region the table occupies. It is kept together with the last row before
+    /// it where possible, so that it doesn't end up alone on a new page.
     #[default(true)]
     pub repeat: bool,
 
@@ -656,7 +713,7 @@ pub struct TableVLine {
 ///   [Vikram], [49], [Perseverance],
 /// )
 /// ```
-#[elem(name = "cell", title = "Table Cell", Show)]
+#[elem(name = "cell", title = "Table Cell", Locatable, Show)]
 pub struct TableCell {
     /// The cell's body.
     #[required]
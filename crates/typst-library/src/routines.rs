@@ -17,13 +17,13 @@ use crate::introspection::{Introspector, Locator, SplitLocator};
 use crate::layout::{
     Abs, BoxElem, ColumnsElem, Fragment, Frame, GridElem, InlineItem, MoveElem, PadElem,
     PagedDocument, Region, Regions, Rel, RepeatElem, RotateElem, ScaleElem, Size,
-    SkewElem, StackElem,
+    SkewElem, StackElem, WatermarkElem,
 };
 use crate::math::EquationElem;
 use crate::model::{DocumentInfo, EnumElem, ListElem, TableElem};
 use crate::visualize::{
     CircleElem, EllipseElem, ImageElem, LineElem, PathElem, PolygonElem, RectElem,
-    SquareElem,
+    SquareElem, TextOnPathElem,
 };
 use crate::World;
 
@@ -232,6 +232,15 @@ routines! {
         regions: Regions,
     ) -> SourceResult<Fragment>
 
+    /// Lays out a [`WatermarkElem`].
+    fn layout_watermark(
+        elem: &Packed<WatermarkElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
     /// Lays out a [`LineElem`].
     fn layout_line(
         elem: &Packed<LineElem>,
@@ -250,6 +259,15 @@ routines! {
         region: Region,
     ) -> SourceResult<Frame>
 
+    /// Lays out a [`TextOnPathElem`].
+    fn layout_text_on_path(
+        elem: &Packed<TextOnPathElem>,
+        engine: &mut Engine,
+        locator: Locator,
+        styles: StyleChain,
+        region: Region,
+    ) -> SourceResult<Frame>
+
     /// Lays out a [`PolygonElem`].
     fn layout_polygon(
         elem: &Packed<PolygonElem>,
@@ -0,0 +1,85 @@
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{elem, Cast, Content, NativeElement, Packed, Show, StyleChain};
+use crate::text::{StrikeElem, TextElem, UnderlineElem};
+use crate::visualize::Color;
+
+/// Marks content as inserted in a tracked change.
+///
+/// While changes are tracked, insertions are underlined and shown in green.
+/// Once [accepted]($text.changes), they are displayed like normal content.
+/// When rejected, they are dropped.
+///
+/// # Example
+/// ```example
+/// The #delete[quick] #insert[slow]
+/// brown fox.
+///
+/// #set text(changes: "accept")
+/// The #delete[quick] #insert[slow]
+/// brown fox.
+/// ```
+#[elem(Show)]
+pub struct InsertElem {
+    /// The inserted content.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<InsertElem> {
+    #[typst_macros::time(name = "insert", span = self.span())]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Ok(match TextElem::changes_in(styles) {
+            ChangeMode::Track => UnderlineElem::new(self.body().clone())
+                .pack()
+                .spanned(self.span())
+                .styled(TextElem::set_fill(Color::GREEN.into())),
+            ChangeMode::Accept => self.body().clone(),
+            ChangeMode::Reject => Content::empty(),
+        })
+    }
+}
+
+/// Marks content as deleted in a tracked change.
+///
+/// While changes are tracked, deletions are struck through and shown in red.
+/// Once [accepted]($text.changes), they are dropped. When rejected, they are
+/// displayed like normal content.
+///
+/// # Example
+/// ```example
+/// Typst is #delete[not] great.
+/// ```
+#[elem(Show)]
+pub struct DeleteElem {
+    /// The deleted content.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for Packed<DeleteElem> {
+    #[typst_macros::time(name = "delete", span = self.span())]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Ok(match TextElem::changes_in(styles) {
+            ChangeMode::Track => StrikeElem::new(self.body().clone())
+                .pack()
+                .spanned(self.span())
+                .styled(TextElem::set_fill(Color::RED.into())),
+            ChangeMode::Accept => Content::empty(),
+            ChangeMode::Reject => self.body().clone(),
+        })
+    }
+}
+
+/// How tracked insertions and deletions are displayed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ChangeMode {
+    /// Show insertions and deletions with distinct styling.
+    Track,
+    /// Apply the changes: Show insertions as normal content and drop
+    /// deletions.
+    Accept,
+    /// Discard the changes: Drop insertions and show deletions as normal
+    /// content.
+    Reject,
+}
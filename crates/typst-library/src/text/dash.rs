@@ -0,0 +1,119 @@
+use ecow::{eco_format, EcoString};
+
+use crate::diag::SourceResult;
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Cast, Content, Packed, PlainText, Show, StyleChain, Synthesize,
+};
+use crate::text::TextElem;
+
+/// A dash for parenthetical breaks that follows the configured convention.
+///
+/// Conventions for such dashes differ: American typography usually sets an
+/// unspaced em dash, while British typography prefers a spaced en dash. With
+/// this function's properties, the same markup can be typeset in either way.
+///
+/// # Example
+/// ```example
+/// Typst#dash()unlike others#dash()is fast.
+///
+/// #set dash(form: "en", spacing: "word")
+/// Typst#dash()unlike others#dash()is fast.
+/// ```
+///
+/// Note that the `---` shorthand always produces a plain em dash. To apply
+/// the configured convention to it, too, you can use a show rule such as
+/// `{show "—": dash()}`.
+#[elem(Synthesize, Show, PlainText)]
+pub struct DashElem {
+    /// Which dash to use.
+    ///
+    /// ```example
+    /// #set dash(form: "en")
+    /// Dashes#dash()in a different form.
+    /// ```
+    #[default(DashForm::Em)]
+    pub form: DashForm,
+
+    /// The space to insert on both sides of the dash, if any.
+    ///
+    /// The dash never starts a new line. Instead, a line can break after the
+    /// space following it.
+    ///
+    /// ```example
+    /// #set dash(spacing: "hair")
+    /// Dashes#dash()with hair spaces.
+    /// ```
+    pub spacing: Option<DashSpacing>,
+}
+
+impl Synthesize for Packed<DashElem> {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        let elem = self.as_mut();
+        elem.push_form(elem.form(styles));
+        elem.push_spacing(elem.spacing(styles));
+        Ok(())
+    }
+}
+
+impl Show for Packed<DashElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let dash = self.form(styles).char();
+        let text = match self.spacing(styles) {
+            // The word joiner prevents a break between the space and the dash.
+            Some(spacing) => {
+                let space = spacing.char();
+                eco_format!("{space}\u{2060}{dash}{space}")
+            }
+            None => dash.into(),
+        };
+        Ok(TextElem::packed(text).spanned(self.span()))
+    }
+}
+
+impl PlainText for Packed<DashElem> {
+    fn plain_text(&self, text: &mut EcoString) {
+        text.push(self.form.unwrap_or(DashForm::Em).char());
+    }
+}
+
+/// Which dash to use for a [`dash`]($dash).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum DashForm {
+    /// An em dash (—).
+    Em,
+    /// An en dash (–).
+    En,
+}
+
+impl DashForm {
+    /// The dash character.
+    pub fn char(self) -> char {
+        match self {
+            Self::Em => '\u{2014}',
+            Self::En => '\u{2013}',
+        }
+    }
+}
+
+/// The space around a [`dash`]($dash).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum DashSpacing {
+    /// A hair space, the narrowest space.
+    Hair,
+    /// A thin space.
+    Thin,
+    /// A normal word space.
+    Word,
+}
+
+impl DashSpacing {
+    /// The space character.
+    pub fn char(self) -> char {
+        match self {
+            Self::Hair => '\u{200A}',
+            Self::Thin => '\u{2009}',
+            Self::Word => ' ',
+        }
+    }
+}
@@ -191,6 +191,16 @@ impl Show for Packed<OverlineElem> {
 /// ```example
 /// This is #strike[not] relevant.
 /// ```
+///
+/// # Combining decorations
+/// Decorations stack: When you nest several of them, each one is drawn as a
+/// separate line with its own stroke and offset.
+///
+/// ```example
+/// #underline(stroke: blue)[
+///   #strike(stroke: red)[Both lines]
+/// ]
+/// ```
 #[elem(title = "Strikethrough", Show)]
 pub struct StrikeElem {
     /// How to [stroke] the line.
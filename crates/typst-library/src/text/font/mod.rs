@@ -14,7 +14,7 @@ use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use ttf_parser::GlyphId;
+use ttf_parser::{GlyphId, Tag, Variation};
 
 use self::book::find_name;
 use crate::foundations::{Bytes, Cast};
@@ -39,6 +39,8 @@ struct Repr {
     ttf: ttf_parser::Face<'static>,
     /// The underlying rustybuzz face.
     rusty: rustybuzz::Face<'static>,
+    /// The coordinates this instance of a variable font is set to.
+    variations: Vec<Variation>,
     // NOTE: `ttf` and `rusty` reference `data`, so it's important for `data`
     // to be dropped after them or they will be left dangling while they're
     // dropped. Fields are dropped in declaration order, so `data` needs to be
@@ -66,7 +68,53 @@ impl Font {
         let metrics = FontMetrics::from_ttf(&ttf);
         let info = FontInfo::from_ttf(&ttf)?;
 
-        Some(Self(Arc::new(Repr { data, index, info, metrics, ttf, rusty })))
+        Some(Self(Arc::new(Repr {
+            data,
+            index,
+            info,
+            metrics,
+            ttf,
+            rusty,
+            variations: vec![],
+        })))
+    }
+
+    /// Create an instance of this font at the given variation coordinates.
+    ///
+    /// Coordinates are clamped to the ranges of the font's variation axes and
+    /// coordinates for axes the font doesn't have or that match the axis'
+    /// default are ignored. If no coordinates remain, the font itself is
+    /// returned. Instances are cached, so requesting the same coordinates
+    /// again yields the same font.
+    pub fn with_variations(&self, coords: &[(Tag, f32)]) -> Self {
+        let axes = self.0.ttf.variation_axes();
+        let mut effective: Vec<(Tag, u32)> = vec![];
+        for &(tag, value) in coords {
+            let Some(axis) = axes.into_iter().find(|axis| axis.tag == tag) else {
+                continue;
+            };
+            let value = value.clamp(axis.min_value, axis.max_value);
+            effective.retain(|&(t, _)| t != tag);
+            if value != axis.def_value {
+                effective.push((tag, value.to_bits()));
+            }
+        }
+
+        if effective.is_empty() {
+            return self.clone();
+        }
+
+        instantiate(self.clone(), effective)
+    }
+
+    /// Whether the font has a variation axis with the given tag.
+    pub fn has_axis(&self, tag: Tag) -> bool {
+        self.0.ttf.variation_axes().into_iter().any(|axis| axis.tag == tag)
+    }
+
+    /// The variation coordinates this font instance is set to.
+    pub fn variations(&self) -> &[Variation] {
+        &self.0.variations
     }
 
     /// Parse all fonts in the given data.
@@ -175,10 +223,43 @@ impl Font {
     }
 }
 
+/// Build an instance of a font at already clamped, non-default coordinates.
+///
+/// The coordinates are given as bit patterns so that they can be hashed.
+#[comemo::memoize]
+fn instantiate(font: Font, coords: Vec<(Tag, u32)>) -> Font {
+    let variations: Vec<Variation> = coords
+        .into_iter()
+        .map(|(tag, bits)| Variation { tag, value: f32::from_bits(bits) })
+        .collect();
+
+    let mut ttf = font.0.ttf.clone();
+    for variation in &variations {
+        ttf.set_variation(variation.tag, variation.value);
+    }
+
+    let mut rusty = font.0.rusty.clone();
+    rusty.set_variations(&variations);
+
+    Font(Arc::new(Repr {
+        data: font.0.data.clone(),
+        index: font.0.index,
+        info: font.0.info.clone(),
+        metrics: font.0.metrics,
+        ttf,
+        rusty,
+        variations,
+    }))
+}
+
 impl Hash for Font {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.data.hash(state);
         self.0.index.hash(state);
+        for variation in &self.0.variations {
+            variation.tag.hash(state);
+            variation.value.to_bits().hash(state);
+        }
     }
 }
 
@@ -192,7 +273,15 @@ impl Eq for Font {}
 
 impl PartialEq for Font {
     fn eq(&self, other: &Self) -> bool {
-        self.0.data == other.0.data && self.0.index == other.0.index
+        self.0.data == other.0.data
+            && self.0.index == other.0.index
+            && self.0.variations.len() == other.0.variations.len()
+            && self
+                .0
+                .variations
+                .iter()
+                .zip(&other.0.variations)
+                .all(|(a, b)| a.tag == b.tag && a.value.to_bits() == b.value.to_bits())
     }
 }
 
@@ -308,3 +397,28 @@ pub enum TextEdgeBounds<'a> {
     /// Use the dimension of the given frame for the bounds.
     Frame(&'a Frame),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_variations_reuses_instances() {
+        let font = typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .next()
+            .unwrap();
+
+        // Coordinates for axes the font doesn't have yield the font itself.
+        let bogus = Tag::from_bytes(b"zzzz");
+        assert!(!font.has_axis(bogus));
+        let same = font.with_variations(&[(bogus, 1.0)]);
+        assert!(Arc::ptr_eq(&font.0, &same.0));
+
+        // Coordinates that match an axis' default yield the font itself, too.
+        if let Some(axis) = font.ttf().variation_axes().into_iter().next() {
+            let same = font.with_variations(&[(axis.tag, axis.def_value)]);
+            assert!(Arc::ptr_eq(&font.0, &same.0));
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::foundations::elem;
+use crate::layout::Length;
+
+/// Inserts a manual kern between two glyphs.
+///
+/// A kern moves the following glyph closer to (negative amount) or further
+/// away from (positive amount) the preceding one. Unlike [horizontal
+/// spacing]($h), a kern never creates a line break opportunity, so the glyphs
+/// on both sides stay on the same line.
+///
+/// The glyphs around a manual kern are shaped separately. This means that the
+/// font's own kerning for that particular pair does not apply and the manual
+/// kern fully determines the distance between them. To control font kerning
+/// globally, use the [`kerning`]($text.kerning) property of the text function.
+///
+/// # Example
+/// ```example
+/// #set text(size: 20pt)
+/// AV \
+/// A#kern(-0.1em)V \
+/// #text(kerning: false)[AV]
+/// ```
+#[elem]
+pub struct KernElem {
+    /// How much to move the following glyph. Negative values tighten the
+    /// pair, positive values loosen it.
+    #[required]
+    pub amount: Length,
+}
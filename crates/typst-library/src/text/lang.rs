@@ -267,6 +267,26 @@ pub fn localized_str(lang: Lang, region: Option<Region>, key: &str) -> &'static
     english_bundle.get(key).unwrap()
 }
 
+/// Retrieves the localized plural of a localized element name, if it is known.
+///
+/// The plural is looked up in the same bundle that the singular is found in,
+/// so that it matches the singular's language even if that one fell back to
+/// English.
+#[comemo::memoize]
+pub fn localized_plural(
+    lang: Lang,
+    region: Option<Region>,
+    singular: &str,
+) -> Option<&'static str> {
+    [(lang, region), (lang, None), (Lang::ENGLISH, None)]
+        .into_iter()
+        .find_map(|(lang, region)| {
+            let bundle = parse_language_bundle(lang, region).unwrap();
+            let (key, _) = bundle.iter().find(|(_, name)| **name == singular)?;
+            bundle.get(eco_format!("{key}-plural").as_str()).copied()
+        })
+}
+
 /// Parses the translation file for a given language and region.
 /// Only returns an error if the language file is malformed.
 #[comemo::memoize]
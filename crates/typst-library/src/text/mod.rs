@@ -1,9 +1,12 @@
 //! Text handling.
 
 mod case;
+mod changes;
+mod dash;
 mod deco;
 mod font;
 mod item;
+mod kern;
 mod lang;
 mod linebreak;
 #[path = "lorem.rs"]
@@ -16,9 +19,12 @@ mod smartquote;
 mod space;
 
 pub use self::case::*;
+pub use self::changes::*;
+pub use self::dash::*;
 pub use self::deco::*;
 pub use self::font::*;
 pub use self::item::*;
+pub use self::kern::*;
 pub use self::lang::*;
 pub use self::linebreak::*;
 pub use self::lorem_::*;
@@ -28,7 +34,7 @@ pub use self::smallcaps_::*;
 pub use self::smartquote::*;
 pub use self::space::*;
 
-use std::fmt::{self, Debug, Formatter};
+use std::fmt::{self, Debug, Display, Formatter};
 use std::sync::LazyLock;
 
 use ecow::{eco_format, EcoString};
@@ -39,15 +45,17 @@ use rustybuzz::Feature;
 use smallvec::SmallVec;
 use ttf_parser::Tag;
 use typst_syntax::Spanned;
+use typst_utils::Scalar;
+use unicode_normalization::UnicodeNormalization;
 
 use crate::diag::{bail, warning, HintedStrResult, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, category, dict, elem, Args, Array, Cast, Category, Construct, Content, Dict,
-    Fold, IntoValue, NativeElement, Never, NoneValue, Packed, PlainText, Repr, Resolve,
-    Scope, Set, Smart, StyleChain,
+    Fields, Fold, IntoValue, NativeElement, Never, NoneValue, Packed, PlainText, Repr,
+    Resolve, Scope, Set, Smart, StyleChain,
 };
-use crate::layout::{Abs, Axis, Dir, Em, Length, Ratio, Rel};
+use crate::layout::{Abs, Axis, Dir, Em, Length, PageElem, Ratio, Rel};
 use crate::model::ParElem;
 use crate::visualize::{Color, Paint, RelativeTo, Stroke};
 use crate::World;
@@ -63,13 +71,17 @@ pub(super) fn define(global: &mut Scope) {
     global.category(TEXT);
     global.define_elem::<TextElem>();
     global.define_elem::<LinebreakElem>();
+    global.define_elem::<KernElem>();
     global.define_elem::<SmartQuoteElem>();
+    global.define_elem::<DashElem>();
     global.define_elem::<SubElem>();
     global.define_elem::<SuperElem>();
     global.define_elem::<UnderlineElem>();
     global.define_elem::<OverlineElem>();
     global.define_elem::<StrikeElem>();
     global.define_elem::<HighlightElem>();
+    global.define_elem::<InsertElem>();
+    global.define_elem::<DeleteElem>();
     global.define_elem::<SmallcapsElem>();
     global.define_elem::<RawElem>();
     global.define_func::<lower>();
@@ -239,26 +251,37 @@ pub struct TextElem {
 
     /// The glyph fill paint.
     ///
+    /// When set to `{auto}`, the text is filled black or white, depending on
+    /// which of the two contrasts better with the background. The background
+    /// is the solid color fill of the innermost [block], [box], or shape
+    /// around the text or, if there is none, the fill of the [page]. Without
+    /// any solid fill, a white background is assumed.
+    ///
     /// ```example
     /// #set text(fill: red)
     /// This text is red.
+    ///
+    /// #set text(fill: auto)
+    /// #box(fill: navy, inset: 2pt)[White]
+    /// #box(fill: yellow, inset: 2pt)[Black]
     /// ```
     #[parse({
-        let paint: Option<Spanned<Paint>> = args.named_or_find("fill")?;
-        if let Some(paint) = &paint {
-            if paint.v.relative() == Smart::Custom(RelativeTo::Self_) {
+        let paint: Option<Spanned<Smart<Paint>>> = args.named_or_find("fill")?;
+        if let Some(Spanned { v: Smart::Custom(v), span }) = &paint {
+            if v.relative() == Smart::Custom(RelativeTo::Self_) {
                 bail!(
-                    paint.span,
+                    *span,
                     "gradients and patterns on text must be relative to the parent";
                     hint: "make sure to set `relative: auto` on your text fill"
                 );
             }
         }
-        paint.map(|paint| paint.v)
+        paint.map(|paint| TextFill(paint.v))
     })]
     #[default(Color::BLACK.into())]
+    #[resolve]
     #[ghost]
-    pub fill: Paint,
+    pub fill: TextFill,
 
     /// How to stroke the text.
     ///
@@ -382,6 +405,10 @@ pub struct TextElem {
     ///   language.
     /// - And all other things which are language-aware.
     ///
+    /// The language can also change in the middle of a paragraph. Text is then
+    /// shaped in separate runs at language boundaries and each word is
+    /// hyphenated with the patterns of its own language.
+    ///
     /// ```example
     /// #set text(lang: "de")
     /// #outline()
@@ -432,6 +459,10 @@ pub struct TextElem {
     /// The dominant direction for text and inline objects. Possible values are:
     ///
     /// - `{auto}`: Automatically infer the direction from the `lang` property.
+    ///   If the language isn't set either, each paragraph instead follows the
+    ///   first character with a strong direction in it. A paragraph starting
+    ///   with Arabic is then laid out right-to-left and one starting with Latin
+    ///   left-to-right, which is useful for multilingual documents.
     /// - `{ltr}`: Layout text from left to right.
     /// - `{rtl}`: Layout text from right to left.
     ///
@@ -492,6 +523,8 @@ pub struct TextElem {
     /// - `runt`: ending a paragraph with a line with a single word
     /// - `widow`: leaving a single line of paragraph on the next page
     /// - `orphan`: leaving single line of paragraph on the previous page
+    /// - `balance`: ending a paragraph with a last line that is much shorter
+    ///   than the line before it
     ///
     /// Hyphenation is generally avoided by placing the whole word on the next
     /// line, so a higher hyphenation cost can result in awkward justification
@@ -511,6 +544,13 @@ pub struct TextElem {
     /// default of `{100%}`, prevents them. More nuanced cost specification for
     /// these modifications is planned for the future.)
     ///
+    /// Unbalanced final lines are allowed by default, i.e. the `balance` cost
+    /// is `{0%}`. With a positive cost, text layout prefers breaks that make
+    /// the last line of a paragraph about as long as the second-to-last one,
+    /// for instance by moving a word down. Like the hyphenation cost, this
+    /// only applies when the [`linebreaks`]($par.linebreaks) are set to
+    /// "optimized".
+    ///
     /// ```example
     /// #set text(hyphenate: true, size: 11.4pt)
     /// #set par(justify: true)
@@ -652,6 +692,31 @@ pub struct TextElem {
     #[ghost]
     pub slashed_zero: bool,
 
+    /// How to distinguish the zero glyph from the letter O.
+    ///
+    /// - `{auto}`: Use the font's default zero.
+    /// - `{"slashed"}`: Draw a slash through the zero. This enables the
+    ///   OpenType `zero` font feature.
+    /// - `{"dotted"}`: Draw a dot in the center of the zero. Since there is
+    ///   no standard font feature for this, Typst looks for a stylistic set
+    ///   that replaces the zero with a glyph named like `zero.dotted`.
+    ///
+    /// Stylistic sets with a fitting glyph, like `zero.slash`, are also used
+    /// for slashed zeros in fonts without the `zero` feature. They only apply
+    /// to the zeros. If the font has no way to provide the form, the slash or
+    /// dot is drawn over the plain zero instead.
+    ///
+    /// Unlike [`slashed-zero`]($text.slashed-zero), which only enables the
+    /// font feature, this also works with fonts that lack it.
+    ///
+    /// ```example
+    /// #set text(font: "DejaVu Sans Mono")
+    /// O0 #text(zero: "slashed")[O0]
+    /// #text(zero: "dotted")[O0]
+    /// ```
+    #[ghost]
+    pub zero: Smart<ZeroForm>,
+
     /// Whether to turn numbers into fractions. Setting this to `{true}`
     /// enables the OpenType `frac` font feature.
     ///
@@ -667,6 +732,60 @@ pub struct TextElem {
     #[ghost]
     pub fractions: bool,
 
+    /// Whether to raise the suffixes of ordinal numbers.
+    ///
+    /// When enabled, suffixes like the "st" in "1st" are set in
+    /// [superscript]($super). This only applies to languages that write
+    /// ordinals with such suffixes, which currently are English ("1st",
+    /// "2nd", "3rd", "4th") and French ("1er", "1re", "2e").
+    ///
+    /// Only suffixes that agree with their number are raised, so "2st" stays
+    /// as it is. [Raw text]($raw) is never affected. Text show rules are
+    /// applied first and still see the whole ordinal.
+    ///
+    /// ```example
+    /// 1st, 2nd, 3rd \
+    /// #text(ordinals: true)[1st, 2nd, 3rd]
+    /// ```
+    #[default(false)]
+    #[ghost]
+    pub ordinals: bool,
+
+    /// Whether to insert the spaces that the language's typography requires
+    /// around punctuation.
+    ///
+    /// This currently only applies to French, in which a narrow no-break space
+    /// is inserted before high punctuation (`;`, `:`, `!`, and `?`) and inside
+    /// of guillemets (`«` and `»`). This also applies to punctuation and
+    /// guillemets directly next to inline math. Where there already is a
+    /// space, no additional one is inserted. [Raw text]($raw) is never
+    /// affected.
+    ///
+    /// ```example
+    /// #set text(lang: "fr", punctuation-spacing: true)
+    /// Voici le texte: « valeur »!
+    /// ```
+    #[default(false)]
+    #[ghost]
+    pub punctuation_spacing: bool,
+
+    /// How tracked changes are displayed.
+    ///
+    /// This controls the display of all [insertions]($insert) and
+    /// [deletions]($delete). With `{"track"}`, they are shown with distinct
+    /// styling. With `{"accept"}`, insertions are shown as normal content and
+    /// deletions are dropped, while `{"reject"}` does the opposite.
+    ///
+    /// ```example
+    /// #let draft = [Typst is #delete[not] #insert[very] great.]
+    /// #draft \
+    /// #text(changes: "accept", draft) \
+    /// #text(changes: "reject", draft)
+    /// ```
+    #[default(ChangeMode::Track)]
+    #[ghost]
+    pub changes: ChangeMode,
+
     /// Raw OpenType features to apply.
     ///
     /// - If given an array of strings, sets the features identified by the
@@ -683,6 +802,77 @@ pub struct TextElem {
     #[ghost]
     pub features: FontFeatures,
 
+    /// Coordinates on the variation axes of variable fonts.
+    ///
+    /// Variable fonts contain a continuous range of designs along axes like
+    /// weight (`wght`), width (`wdth`), or optical size (`opsz`). This property
+    /// maps four-character axis tags to coordinates on these axes. Coordinates
+    /// outside of an axis' range are clamped to it with a warning and axes a
+    /// font doesn't have are ignored.
+    ///
+    /// Setting the `wght` or `wdth` axis also selects the closest font from
+    /// the family as if the [`weight`]($text.weight) or
+    /// [`stretch`]($text.stretch) had been set accordingly.
+    ///
+    /// ```typ
+    /// #set text(variations: (wght: 650, wdth: 75))
+    /// ```
+    #[fold]
+    #[ghost]
+    pub variations: FontVariations,
+
+    /// The optical size to use for variable fonts with an `opsz` axis.
+    ///
+    /// Fonts with an optical size axis adapt their design to the size they
+    /// are set at: Small text gets sturdier strokes and more generous spacing
+    /// while large display text gets finer details. When set to `{auto}`, the
+    /// current font size is used as the optical size. A length overrides
+    /// this, for example to get display-style shapes at a smaller size.
+    /// Explicitly setting the `opsz` axis with
+    /// [`variations`]($text.variations) takes precedence over this property.
+    ///
+    /// ```typ
+    /// #set text(optical-size: 48pt)
+    /// ```
+    #[resolve]
+    #[ghost]
+    pub optical_size: Smart<Length>,
+
+    /// Explicit glyphs to use for specific characters.
+    ///
+    /// Maps single characters to glyphs, either by their glyph ID or by their
+    /// name in the font. The glyphs are substituted after shaping and thus
+    /// take precedence over all font features. This is useful to force a
+    /// specific alternate that is not accessible through a feature. If the
+    /// font doesn't contain the glyph, the character is shaped as usual and a
+    /// warning is emitted.
+    ///
+    /// ```typ
+    /// #set text(substitutions: ("&": "ampersand.swsh"))
+    /// ```
+    #[fold]
+    #[ghost]
+    pub substitutions: GlyphSubstitutions,
+
+    /// The Unicode normalization form to apply to the text before shaping.
+    ///
+    /// Many characters can be encoded in multiple ways. For example, "é" can
+    /// be a single precomposed codepoint or an "e" followed by a combining
+    /// acute accent. Fonts don't necessarily render both encodings the same
+    /// way, so text combined from different sources may look inconsistent.
+    /// Normalizing the text ensures a consistent encoding.
+    ///
+    /// - `{none}`: The text is shaped as is.
+    /// - `{"nfc"}`: Characters are composed where possible.
+    /// - `{"nfd"}`: Characters are decomposed.
+    ///
+    /// ```example
+    /// #set text(normalization: "nfc")
+    /// \u{e9} and e\u{301}
+    /// ```
+    #[ghost]
+    pub normalization: Option<Normalization>,
+
     /// Content in which all text is styled according to the other arguments.
     #[external]
     #[required]
@@ -727,6 +917,12 @@ pub struct TextElem {
     #[default(false)]
     #[ghost]
     pub smallcaps: bool,
+
+    /// The solid color behind the text, if known. Used to pick a contrasting
+    /// [fill]($text.fill) when it is `{auto}`.
+    #[internal]
+    #[ghost]
+    pub background: Option<Color>,
 }
 
 impl TextElem {
@@ -842,6 +1038,16 @@ pub fn variant(styles: StyleChain) -> FontVariant {
         TextElem::stretch_in(styles),
     );
 
+    // Pick the closest instance from the family for explicit weight and width
+    // variation coordinates.
+    let variations = TextElem::variations_in(styles);
+    if let Some(wght) = variations.get(b"wght") {
+        variant.weight = FontWeight::from_number(wght.clamp(100.0, 900.0).round() as u16);
+    }
+    if let Some(wdth) = variations.get(b"wdth") {
+        variant.stretch = FontStretch::from_ratio(Ratio::new(wdth / 100.0));
+    }
+
     let WeightDelta(delta) = TextElem::delta_in(styles);
     variant.weight = variant
         .weight
@@ -886,6 +1092,54 @@ cast! {
     v: Length => Self(v),
 }
 
+/// The fill of text, which can be chosen automatically to contrast with the
+/// background.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct TextFill(pub Smart<Paint>);
+
+impl Resolve for TextFill {
+    type Output = Paint;
+
+    fn resolve(self, styles: StyleChain) -> Self::Output {
+        match self.0 {
+            Smart::Custom(paint) => paint,
+            Smart::Auto => {
+                let background = TextElem::background_in(styles)
+                    .or_else(|| match PageElem::fill_in(styles) {
+                        Smart::Custom(Some(Paint::Solid(color))) => Some(color),
+                        _ => None,
+                    })
+                    .unwrap_or(Color::WHITE);
+                let luminance = background.relative_luminance();
+                // Compare the WCAG contrast ratios against white and black.
+                if 1.05 / (luminance + 0.05) > (luminance + 0.05) / 0.05 {
+                    Color::WHITE.into()
+                } else {
+                    Color::BLACK.into()
+                }
+            }
+        }
+    }
+}
+
+impl From<Paint> for TextFill {
+    fn from(paint: Paint) -> Self {
+        Self(Smart::Custom(paint))
+    }
+}
+
+impl From<Color> for TextFill {
+    fn from(color: Color) -> Self {
+        Self(Smart::Custom(color.into()))
+    }
+}
+
+cast! {
+    TextFill,
+    self => self.0.into_value(),
+    v: Smart<Paint> => Self(v),
+}
+
 /// Specifies the top edge of text.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum TopEdge {
@@ -991,6 +1245,22 @@ cast! {
     },
 }
 
+impl TextDir {
+    /// Whether neither the text direction nor the language are set in the
+    /// style chain, so that paragraphs infer their direction from their
+    /// content.
+    pub fn is_inferred_in(styles: StyleChain) -> bool {
+        let elem = TextElem::elem();
+        let dir = <TextElem as Fields>::Enum::Dir as u8;
+        let lang = <TextElem as Fields>::Enum::Lang as u8;
+        styles.get(elem, dir, None, TextDir::default).0.is_auto()
+            && !styles
+                .entries()
+                .filter_map(|style| style.property())
+                .any(|property| property.is(elem, lang))
+    }
+}
+
 impl Resolve for TextDir {
     type Output = Dir;
 
@@ -1084,6 +1354,34 @@ pub enum NumberWidth {
     Tabular,
 }
 
+/// How the zero glyph is distinguished from the letter O.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ZeroForm {
+    /// A zero with a slash through it.
+    Slashed,
+    /// A zero with a dot in its center.
+    Dotted,
+}
+
+/// A Unicode normalization form.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Normalization {
+    /// Canonical decomposition, followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+}
+
+impl Normalization {
+    /// Apply the normalization form to a string.
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Self::Nfc => text.nfc().collect(),
+            Self::Nfd => text.nfd().collect(),
+        }
+    }
+}
+
 /// OpenType font features settings.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct FontFeatures(pub Vec<(Tag, u32)>);
@@ -1122,6 +1420,140 @@ impl Fold for FontFeatures {
     }
 }
 
+/// Coordinates on the variation axes of variable fonts.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FontVariations(pub Vec<(Tag, Scalar)>);
+
+impl FontVariations {
+    /// The coordinate for the given axis, if any.
+    pub fn get(&self, tag: &[u8; 4]) -> Option<f64> {
+        let tag = Tag::from_bytes(tag);
+        self.0.iter().rev().find(|(t, _)| *t == tag).map(|(_, v)| v.get())
+    }
+}
+
+cast! {
+    FontVariations,
+    self => self.0
+        .into_iter()
+        .map(|(tag, value)| {
+            let bytes = tag.to_bytes();
+            let key = std::str::from_utf8(&bytes).unwrap_or_default();
+            (key.into(), value.get().into_value())
+        })
+        .collect::<Dict>()
+        .into_value(),
+    values: Dict => Self(values
+        .into_iter()
+        .map(|(k, v)| {
+            if k.len() != 4 || !k.as_str().is_ascii() {
+                bail!("variation axis tag must be four ASCII characters");
+            }
+            let value = v.cast::<f64>()?;
+            Ok((Tag::from_bytes_lossy(k.as_bytes()), Scalar::new(value)))
+        })
+        .collect::<HintedStrResult<_>>()?),
+}
+
+impl Fold for FontVariations {
+    fn fold(self, outer: Self) -> Self {
+        Self(self.0.fold(outer.0))
+    }
+}
+
+/// Explicit glyphs to use for specific characters.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct GlyphSubstitutions(pub Vec<(char, GlyphRef)>);
+
+impl GlyphSubstitutions {
+    /// The glyph to use for the given character, if any.
+    pub fn get(&self, c: char) -> Option<&GlyphRef> {
+        self.0.iter().rev().find(|(k, _)| *k == c).map(|(_, glyph)| glyph)
+    }
+}
+
+cast! {
+    GlyphSubstitutions,
+    self => self.0
+        .into_iter()
+        .map(|(c, glyph)| (c.into(), glyph.into_value()))
+        .collect::<Dict>()
+        .into_value(),
+    values: Dict => Self(values
+        .into_iter()
+        .map(|(k, v)| {
+            let mut chars = k.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                bail!("substituted text must be a single character");
+            };
+            Ok((c, v.cast::<GlyphRef>()?))
+        })
+        .collect::<HintedStrResult<_>>()?),
+}
+
+impl Fold for GlyphSubstitutions {
+    fn fold(self, outer: Self) -> Self {
+        Self(self.0.fold(outer.0))
+    }
+}
+
+/// A reference to a glyph in a font.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GlyphRef {
+    /// A glyph ID.
+    Id(u16),
+    /// A glyph name from the font's `post` or `CFF` table.
+    Name(EcoString),
+}
+
+impl GlyphRef {
+    /// Look up the glyph's ID in the given font, if the font contains it.
+    pub fn resolve(&self, font: &Font) -> Option<u16> {
+        let ttf = font.ttf();
+        match self {
+            Self::Id(id) => (*id < ttf.number_of_glyphs()).then_some(*id),
+            Self::Name(name) => ttf.glyph_index_by_name(name).map(|id| id.0),
+        }
+    }
+}
+
+cast! {
+    GlyphRef,
+    self => match self {
+        Self::Id(id) => id.into_value(),
+        Self::Name(name) => name.into_value(),
+    },
+    v: u16 => Self::Id(v),
+    v: EcoString => Self::Name(v),
+}
+
+impl Display for GlyphRef {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "with ID {id}"),
+            Self::Name(name) => write!(f, "`{name}`"),
+        }
+    }
+}
+
+/// Collect the explicitly set variation axis coordinates.
+///
+/// Each axis appears at most once, with the innermost coordinate winning.
+pub fn variations(styles: StyleChain) -> Vec<(Tag, f32)> {
+    let mut coords: Vec<(Tag, f32)> = vec![];
+    for (tag, value) in TextElem::variations_in(styles).0 {
+        coords.retain(|(t, _)| *t != tag);
+        coords.push((tag, value.get() as f32));
+    }
+    coords
+}
+
+/// The size to use for the optical size axis of variable fonts that have
+/// one, unless it is set explicitly through the variations.
+pub fn optical_size(styles: StyleChain) -> Abs {
+    TextElem::optical_size_in(styles).unwrap_or_else(|| TextElem::size_in(styles))
+}
+
 /// Collect the OpenType features to apply.
 pub fn features(styles: StyleChain) -> Vec<Feature> {
     let mut tags = vec![];
@@ -1173,7 +1605,9 @@ pub fn features(styles: StyleChain) -> Vec<Feature> {
         Smart::Custom(NumberWidth::Tabular) => feat(b"tnum", 1),
     }
 
-    if TextElem::slashed_zero_in(styles) {
+    if TextElem::slashed_zero_in(styles)
+        || TextElem::zero_in(styles) == Smart::Custom(ZeroForm::Slashed)
+    {
         feat(b"zero", 1);
     }
 
@@ -1218,6 +1652,7 @@ pub struct Costs {
     runt: Option<Ratio>,
     widow: Option<Ratio>,
     orphan: Option<Ratio>,
+    balance: Option<Ratio>,
 }
 
 impl Costs {
@@ -1240,6 +1675,11 @@ impl Costs {
     pub fn orphan(&self) -> Ratio {
         self.orphan.unwrap_or(Ratio::one())
     }
+
+    #[must_use]
+    pub fn balance(&self) -> Ratio {
+        self.balance.unwrap_or(Ratio::zero())
+    }
 }
 
 impl Fold for Costs {
@@ -1250,6 +1690,7 @@ impl Fold for Costs {
             runt: self.runt.or(outer.runt),
             widow: self.widow.or(outer.widow),
             orphan: self.orphan.or(outer.orphan),
+            balance: self.balance.or(outer.balance),
         }
     }
 }
@@ -1261,6 +1702,7 @@ cast! {
         "runt" => self.runt(),
         "widow" => self.widow(),
         "orphan" => self.orphan(),
+        "balance" => self.balance(),
     ].into_value(),
     mut v: Dict => {
         let ret = Self {
@@ -1268,8 +1710,9 @@ cast! {
             runt: v.take("runt").ok().map(|v| v.cast()).transpose()?,
             widow: v.take("widow").ok().map(|v| v.cast()).transpose()?,
             orphan: v.take("orphan").ok().map(|v| v.cast()).transpose()?,
+            balance: v.take("balance").ok().map(|v| v.cast()).transpose()?,
         };
-        v.finish(&["hyphenation", "runt", "widow", "orphan"])?;
+        v.finish(&["hyphenation", "runt", "widow", "orphan", "balance"])?;
         ret
     },
 }
@@ -1318,3 +1761,25 @@ fn check_font_list(engine: &mut Engine, list: &Spanned<FontList>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::Styles;
+
+    #[test]
+    fn test_auto_fill_contrasts_with_background() {
+        #[track_caller]
+        fn test(background: Option<Color>, expected: Color) {
+            let styles = Styles::from(TextElem::set_background(background).wrap());
+            let fill = TextFill(Smart::Auto).resolve(StyleChain::new(&styles));
+            assert_eq!(fill, expected.into());
+        }
+
+        test(None, Color::BLACK);
+        test(Some(Color::NAVY), Color::WHITE);
+        test(Some(Color::BLACK), Color::WHITE);
+        test(Some(Color::YELLOW), Color::BLACK);
+        test(Some(Color::SILVER), Color::BLACK);
+    }
+}
@@ -17,12 +17,12 @@ use crate::foundations::{
     PlainText, Show, ShowSet, Smart, StyleChain, Styles, Synthesize, TargetElem, Value,
 };
 use crate::html::{tag, HtmlElem};
-use crate::layout::{BlockBody, BlockElem, Em, HAlignment};
+use crate::layout::{BlockBody, BlockElem, Em, HAlignment, Sides};
 use crate::model::{Figurable, ParElem};
 use crate::text::{
     FontFamily, FontList, Hyphenate, LinebreakElem, LocalName, TextElem, TextSize,
 };
-use crate::visualize::Color;
+use crate::visualize::{Color, Paint};
 use crate::World;
 
 // Shorthand for highlighter closures.
@@ -268,6 +268,53 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// Whether to remove blank lines at the start and end of the text.
+    ///
+    /// Blank lines between other lines are kept. Raw blocks written with
+    /// backticks are already trimmed like this, so this is mostly useful for
+    /// text passed to the `raw` function, e.g. when it was read from a file.
+    ///
+    /// ```example
+    /// #raw(block: true, trim: true, "\n\nfn main() {}\n\n")
+    /// ```
+    #[default(false)]
+    pub trim: bool,
+
+    /// Whether to remove the leading whitespace that all lines share.
+    ///
+    /// Blank lines do not count towards the shared indentation. This works
+    /// like Python's `textwrap.dedent` and is useful for raw text that is
+    /// indented to match its surroundings.
+    ///
+    /// ```example
+    /// #raw(block: true, dedent: true, "    if x:\n        y()")
+    /// ```
+    #[default(false)]
+    pub dedent: bool,
+
+    /// Lines to highlight with a background band that spans the width of the
+    /// block.
+    ///
+    /// Lines are numbered from one, just like the [`number`]($raw.line.number)
+    /// of a raw line, so this works together with custom line numbering. Only
+    /// applies to raw blocks. Highlighted lines still wrap and can break
+    /// across pages like any other line.
+    ///
+    /// ````example
+    /// #set raw(highlight-lines: (2,))
+    /// ```rust
+    /// fn main() {
+    ///     println!("Hello!");
+    /// }
+    /// ```
+    /// ````
+    pub highlight_lines: Vec<i64>,
+
+    /// The background of lines listed in
+    /// [`highlight-lines`]($raw.highlight-lines).
+    #[default(Color::from_u8(0xff, 0xf2, 0xb3, 0xff).into())]
+    pub highlight_fill: Paint,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -441,17 +488,43 @@ impl Show for Packed<RawElem> {
     #[typst_macros::time(name = "raw", span = self.span())]
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         let lines = self.lines().map(|v| v.as_slice()).unwrap_or_default();
+        let block = self.block(styles);
+
+        // Highlighting only applies to raw blocks in paged export.
+        let highlighted = if block && !TargetElem::target_in(styles).is_html() {
+            self.highlight_lines(styles)
+        } else {
+            vec![]
+        };
+        let highlight = |i: usize| highlighted.contains(&(i as i64 + 1));
 
         let mut seq = EcoVec::with_capacity((2 * lines.len()).saturating_sub(1));
         for (i, line) in lines.iter().enumerate() {
-            if i != 0 {
+            if highlight(i) {
+                seq.push(self.highlight_line(line, styles));
+                continue;
+            }
+
+            // Highlighted lines are blocks, so the lines around them already
+            // end up on their own.
+            if i != 0 && !highlight(i - 1) {
                 seq.push(LinebreakElem::shared().clone());
             }
 
-            seq.push(line.clone().pack());
+            let mut body = line.clone().pack();
+            if !highlighted.is_empty() && line.text().is_empty() {
+                // Keep empty lines next to highlighted ones from vanishing.
+                body += TextElem::packed("\u{200B}");
+            }
+            seq.push(body);
         }
 
         let mut realized = Content::sequence(seq);
+        if !highlighted.is_empty() {
+            // Space the paragraphs between highlighted lines like lines.
+            let leading = ParElem::leading_in(styles);
+            realized = realized.styled(ParElem::set_spacing(leading.into()));
+        }
 
         if TargetElem::target_in(styles).is_html() {
             return Ok(HtmlElem::new(tag::pre)
@@ -460,7 +533,7 @@ impl Show for Packed<RawElem> {
                 .spanned(self.span()));
         }
 
-        if self.block(styles) {
+        if block {
             // Align the text before inserting it into the block.
             realized = realized.aligned(self.align(styles).into());
             realized = BlockElem::new()
@@ -473,12 +546,41 @@ impl Show for Packed<RawElem> {
     }
 }
 
+impl Packed<RawElem> {
+    /// Puts a band behind a line.
+    ///
+    /// The line becomes a block of its own, which spans the width of the raw
+    /// block and can still wrap and break across pages. The band extends by
+    /// half the leading above and below the line so that adjacent bands touch.
+    fn highlight_line(&self, line: &Packed<RawLine>, styles: StyleChain) -> Content {
+        let mut body = line.clone().pack();
+
+        // Give empty lines the same height as the others.
+        if line.text().is_empty() {
+            body += TextElem::packed("\u{200B}");
+        }
+
+        let leading = ParElem::leading_in(styles);
+        let half = leading / 2.0;
+        BlockElem::new()
+            .with_body(Some(BlockBody::Content(body)))
+            .with_fill(Some(self.highlight_fill(styles)))
+            .with_outset(Sides::new(None, Some(half.into()), None, Some(half.into())))
+            .with_above(Smart::Custom(leading.into()))
+            .with_below(Smart::Custom(leading.into()))
+            .pack()
+            .spanned(self.span())
+    }
+}
+
 impl ShowSet for Packed<RawElem> {
     fn show_set(&self, styles: StyleChain) -> Styles {
         let mut out = Styles::new();
         out.set(TextElem::set_overhang(false));
         out.set(TextElem::set_lang(Lang::ENGLISH));
         out.set(TextElem::set_hyphenate(Hyphenate(Smart::Custom(false))));
+        out.set(TextElem::set_ordinals(false));
+        out.set(TextElem::set_punctuation_spacing(false));
         out.set(TextElem::set_size(TextSize(Em::new(0.8).into())));
         out.set(TextElem::set_font(FontList(vec![FontFamily::new("DejaVu Sans Mono")])));
         if self.block(styles) {
@@ -679,21 +781,51 @@ fn preprocess(
     styles: StyleChain,
     span: Span,
 ) -> EcoVec<(EcoString, Span)> {
-    if let RawContent::Lines(lines) = text {
-        if lines.iter().all(|(s, _)| !s.contains('\t')) {
-            return lines.clone();
+    let mut lines = match text {
+        RawContent::Lines(lines) if lines.iter().all(|(s, _)| !s.contains('\t')) => {
+            lines.clone()
         }
+        _ => {
+            let mut text = text.get();
+            if text.contains('\t') {
+                let tab_size = RawElem::tab_size_in(styles);
+                text = align_tabs(&text, tab_size);
+            }
+            split_newlines(&text)
+                .into_iter()
+                .map(|line| (line.into(), span))
+                .collect()
+        }
+    };
+
+    let is_blank = |line: &str| line.trim().is_empty();
+
+    if RawElem::trim_in(styles) {
+        let start = lines.iter().position(|(s, _)| !is_blank(s)).unwrap_or(lines.len());
+        let end = lines.iter().rposition(|(s, _)| !is_blank(s)).map_or(start, |i| i + 1);
+        lines = lines[start..end].iter().cloned().collect();
     }
 
-    let mut text = text.get();
-    if text.contains('\t') {
-        let tab_size = RawElem::tab_size_in(styles);
-        text = align_tabs(&text, tab_size);
+    if RawElem::dedent_in(styles) {
+        let indent = lines
+            .iter()
+            .filter(|(s, _)| !is_blank(s))
+            .map(|(s, _)| s.len() - s.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        if indent > 0 {
+            for (line, _) in lines.make_mut() {
+                *line = if is_blank(line) {
+                    EcoString::new()
+                } else {
+                    line.get(indent..).unwrap_or(line.trim_start()).into()
+                };
+            }
+        }
     }
-    split_newlines(&text)
-        .into_iter()
-        .map(|line| (line.into(), span))
-        .collect()
+
+    lines
 }
 
 /// Style a piece of text with a syntect style.
@@ -114,16 +114,25 @@ impl SmartQuoter {
     }
 
     /// Determine which smart quote to substitute given this quoter's nesting
-    /// state and the character immediately preceding the quote.
+    /// state and the characters immediately preceding and following the
+    /// quote.
     pub fn quote<'a>(
         &mut self,
         before: Option<char>,
+        after: Option<char>,
         quotes: &SmartQuotes<'a>,
         double: bool,
     ) -> &'a str {
         let opened = self.top();
         let before = before.unwrap_or(' ');
 
+        // A single quote between two letters is an apostrophe within a word,
+        // like in a contraction. This holds even within a single quotation,
+        // which it would otherwise close.
+        if !double && before.is_alphabetic() && after.is_some_and(char::is_alphabetic) {
+            return "’";
+        }
+
         // If we are after a number and haven't most recently opened a quote of
         // this kind, produce a prime. Otherwise, we prefer a closing quote.
         if before.is_numeric() && opened != Some(double) {
@@ -1245,6 +1245,14 @@ impl Color {
         self
     }
 
+    /// Multiplies the alpha channel of the color with the given factor.
+    ///
+    /// Colors without an alpha channel are converted to RGB first.
+    pub fn fade(self, opacity: f32) -> Self {
+        let color = if self.alpha().is_none() { self.to_rgb() } else { self };
+        color.with_alpha(color.alpha().unwrap_or(1.0) * opacity)
+    }
+
     /// Scales the alpha value of a color by a given amount.
     ///
     /// For positive scales, computes `alpha + scale - alpha * scale`.
@@ -1270,6 +1278,13 @@ impl Color {
         })
     }
 
+    /// The relative luminance of the color as defined by WCAG, ranging from
+    /// zero for black to one for white.
+    pub fn relative_luminance(self) -> f32 {
+        let [r, g, b, _] = self.to_linear_rgb().to_vec4();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
     /// Converts the color to a vec of four floats.
     pub fn to_vec4(&self) -> [f32; 4] {
         match self {
@@ -87,6 +87,24 @@ use crate::visualize::{Color, ColorSpace, WeightedColor};
 ///   created by show rules and elements. For example, a [`rotate`] will not
 ///   affect the parent of a gradient, but a [`grid`] will.
 ///
+/// This also determines how a gradient behaves when its shape is rotated. With
+/// `{"self"}`, the gradient is part of the shape and rotates along with it.
+/// With `{"parent"}`, the gradient is laid out in the coordinate system of the
+/// ancestor container, so its axis stays aligned with that container (say, the
+/// page) no matter how the shape inside is rotated. Keep in mind that a box or
+/// block is itself the container of its own fill, so to keep the gradient of a
+/// rotated box page-aligned, fill a [`rect`] instead.
+///
+/// ```example
+/// >>> #set page(width: auto, height: auto, margin: 12pt)
+/// #let spin(relative) = rotate(45deg, rect(
+///   width: 30pt,
+///   height: 30pt,
+///   fill: gradient.linear(red, blue, relative: relative),
+/// ))
+/// #spin("self") #h(20pt) #spin("parent")
+/// ```
+///
 /// # Color spaces and interpolation
 /// Gradients can be interpolated in any color space. By default, gradients are
 /// interpolated in the [Oklab]($color.oklab) color space, which is a
@@ -806,6 +824,18 @@ impl Gradient {
 
         self
     }
+    /// Scales the opacity of all stops of this gradient with the given factor.
+    pub fn fade(&mut self, opacity: f32) {
+        let stops = match self {
+            Self::Linear(linear) => &mut Arc::make_mut(linear).stops,
+            Self::Radial(radial) => &mut Arc::make_mut(radial).stops,
+            Self::Conic(conic) => &mut Arc::make_mut(conic).stops,
+        };
+        for (color, _) in stops {
+            *color = color.fade(opacity);
+        }
+    }
+
     /// Returns a reference to the stops of this gradient.
     pub fn stops_ref(&self) -> &[(Color, Ratio)] {
         match self {
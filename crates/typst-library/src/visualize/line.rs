@@ -30,6 +30,10 @@ pub struct LineElem {
     pub end: Option<Axes<Rel<Length>>>,
 
     /// The line's length. This is only respected if `end` is `{none}`.
+    ///
+    /// A relative length is resolved against the width of the surrounding
+    /// container's content area rather than the page. Inside a `{10cm}` wide
+    /// box, a line of length `{80%}` is thus `{8cm}` long.
     #[resolve]
     #[default(Abs::pt(30.0).into())]
     pub length: Rel<Length>,
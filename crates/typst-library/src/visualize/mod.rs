@@ -47,4 +47,5 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<CircleElem>();
     global.define_elem::<PolygonElem>();
     global.define_elem::<PathElem>();
+    global.define_elem::<TextOnPathElem>();
 }
@@ -34,6 +34,15 @@ impl Paint {
         }
     }
 
+    /// Scales the opacity of this paint with the given factor.
+    pub fn fade(&mut self, opacity: f32) {
+        match self {
+            Self::Solid(color) => *color = color.fade(opacity),
+            Self::Gradient(gradient) => gradient.fade(opacity),
+            Self::Pattern(pattern) => pattern.fade(opacity),
+        }
+    }
+
     /// Turns this paint into a paint for a text decoration.
     ///
     /// If this paint is a gradient, it will be converted to a gradient with
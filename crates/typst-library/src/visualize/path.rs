@@ -1,3 +1,4 @@
+use ecow::EcoString;
 use kurbo::ParamCurveExtrema;
 use typst_utils::Numeric;
 
@@ -8,7 +9,7 @@ use crate::foundations::{
     array, cast, elem, Array, Content, NativeElement, Packed, Reflect, Show, Smart,
     StyleChain,
 };
-use crate::layout::{Abs, Axes, BlockElem, Length, Point, Rel, Size};
+use crate::layout::{Abs, Axes, BlockElem, HAlignment, Length, Point, Rel, Size};
 use crate::visualize::{FillRule, Paint, Stroke};
 
 /// A path through a list of points, connected by Bezier curves.
@@ -95,6 +96,69 @@ impl Show for Packed<PathElem> {
     }
 }
 
+cast! {
+    PathElem,
+    v: Content => v.unpack::<Self>().map_err(|_| "expected a path")?,
+}
+
+/// Text set along a path.
+///
+/// Each glyph is placed on the path and rotated so that its baseline follows
+/// the direction of the curve at that point. This is useful for seals,
+/// badges, and other circular lettering. The path itself is not drawn, but
+/// you can draw it separately if you want it to be visible.
+///
+/// # Example
+/// ```example
+/// #let arc = path(
+///   ((0pt, 50pt), (0pt, 27.6pt)),
+///   ((50pt, 0pt), (-27.6pt, 0pt)),
+///   ((100pt, 50pt), (0pt, -27.6pt)),
+/// )
+///
+/// #text-on-path(arc, align: center, "Certified")
+/// ```
+#[elem(Show)]
+pub struct TextOnPathElem {
+    /// The path along which the text is set.
+    #[required]
+    pub path: Packed<PathElem>,
+
+    /// The text to set along the path.
+    #[required]
+    pub text: EcoString,
+
+    /// Where to place the text along the path.
+    ///
+    /// With `{start}`, the text begins at the start of the path. With
+    /// `{center}` and `{end}`, it is centered on the path or ends at its end,
+    /// respectively. Glyphs that extend beyond either end of the path
+    /// continue in the direction of that end.
+    ///
+    /// Right-to-left text reads from the end of the path towards its start,
+    /// so for such text, `{start}` refers to the end of the path.
+    ///
+    /// ```example
+    /// #let line = path((0pt, 0pt), (100pt, 0pt))
+    /// #text-on-path(line, align: end, "Typst")
+    /// ```
+    #[default(HAlignment::Start)]
+    pub align: HAlignment,
+
+    /// How far to shift the text along the path, after it was aligned.
+    ///
+    /// Positive values move the text towards the end of the path.
+    pub offset: Length,
+}
+
+impl Show for Packed<TextOnPathElem> {
+    fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(BlockElem::single_layouter(self.clone(), engine.routines.layout_text_on_path)
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
 /// A component used for path creation.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum PathVertex {
@@ -233,6 +233,11 @@ impl Pattern {
         self
     }
 
+    /// Scales the opacity of the pattern's contents with the given factor.
+    pub fn fade(&mut self, opacity: f32) {
+        Arc::make_mut(&mut self.0).frame.fade(opacity);
+    }
+
     /// Return the frame of the pattern.
     pub fn frame(&self) -> &Frame {
         &self.0.frame
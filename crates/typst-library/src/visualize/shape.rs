@@ -67,6 +67,25 @@ pub struct RectElem {
     #[fold]
     pub stroke: Smart<Sides<Option<Option<Stroke>>>>,
 
+    /// How to align the stroke with the edges of the rectangle.
+    ///
+    /// By default, the stroke straddles the edges. With `{"inside"}`, it stays
+    /// fully within the rectangle's size and with `{"outside"}`, it fully
+    /// surrounds it. Only the stroke is moved: The fill and the rectangle's
+    /// bounding box stay unchanged, so this does not affect the layout.
+    ///
+    /// ```example
+    /// #set rect(stroke: 4pt, height: 20pt)
+    /// #stack(
+    ///   dir: ltr,
+    ///   spacing: 1fr,
+    ///   rect(stroke-align: "inside"),
+    ///   rect(stroke-align: "center"),
+    ///   rect(stroke-align: "outside"),
+    /// )
+    /// ```
+    pub stroke_align: StrokeAlign,
+
     /// How much to round the rectangle's corners, relative to the minimum of
     /// the width and height divided by two. This can be:
     ///
@@ -190,6 +209,10 @@ pub struct SquareElem {
     #[fold]
     pub stroke: Smart<Sides<Option<Option<Stroke>>>>,
 
+    /// How to align the stroke with the edges of the square. See the
+    /// [rectangle's documentation]($rect.stroke-align) for more details.
+    pub stroke_align: StrokeAlign,
+
     /// How much to round the square's corners. See the
     /// [rectangle's documentation]($rect.radius) for more details.
     #[resolve]
@@ -261,6 +284,10 @@ pub struct EllipseElem {
     #[fold]
     pub stroke: Smart<Option<Stroke>>,
 
+    /// How to align the stroke with the edges of the ellipse. See the
+    /// [rectangle's documentation]($rect.stroke-align) for more details.
+    pub stroke_align: StrokeAlign,
+
     /// How much to pad the ellipse's content. See the
     /// [box's documentation]($box.inset) for more details.
     #[resolve]
@@ -352,6 +379,10 @@ pub struct CircleElem {
     #[default(Smart::Auto)]
     pub stroke: Smart<Option<Stroke>>,
 
+    /// How to align the stroke with the edges of the circle. See the
+    /// [rectangle's documentation]($rect.stroke-align) for more details.
+    pub stroke_align: StrokeAlign,
+
     /// How much to pad the circle's content. See the
     /// [box's documentation]($box.inset) for more details.
     #[resolve]
@@ -405,6 +436,30 @@ pub enum FillRule {
     EvenOdd,
 }
 
+/// How a stroke is aligned with the edges of a shape.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum StrokeAlign {
+    /// The stroke lies fully within the shape.
+    Inside,
+    /// The stroke is centered on the edges of the shape.
+    #[default]
+    Center,
+    /// The stroke lies fully outside of the shape.
+    Outside,
+}
+
+impl StrokeAlign {
+    /// By how much of its thickness the stroke's path should be moved
+    /// outwards.
+    pub fn shift(self) -> f64 {
+        match self {
+            Self::Inside => -0.5,
+            Self::Center => 0.0,
+            Self::Outside => 0.5,
+        }
+    }
+}
+
 /// A shape's geometry.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Geometry {
@@ -12,7 +12,7 @@ use typst_syntax::Span;
 use xmp_writer::{DateTime, LangId, RenditionClass, Timezone, XmpWriter};
 
 use crate::page::PdfPageLabel;
-use crate::{hash_base64, outline, TextStrExt, WithEverything};
+use crate::{hash_base64, outline, tags, TextStrExt, WithEverything};
 
 /// Write the document catalog.
 pub fn write_catalog(
@@ -39,6 +39,9 @@ pub fn write_catalog(
     // Write the page labels.
     let page_labels = write_page_labels(pdf, alloc, &ctx);
 
+    // Write the structure tree.
+    let struct_tree_root = tags::write_structure(pdf, alloc, &ctx);
+
     // Write the document information.
     let info_ref = alloc.bump();
     let mut info = pdf.document_info(info_ref);
@@ -196,6 +199,12 @@ pub fn write_catalog(
         catalog.outlines(outline_root_id);
     }
 
+    // Only elements with a structural role are tagged, so we don't claim that
+    // the document is a fully tagged PDF through `MarkInfo`.
+    if let Some(struct_tree_root) = struct_tree_root {
+        catalog.pair(Name(b"StructTreeRoot"), struct_tree_root);
+    }
+
     if let Some(lang) = lang {
         catalog.lang(TextStr(lang.as_str()));
     }
@@ -4,6 +4,9 @@
 //!
 //! There are various standards to represent color glyphs, but PDF readers don't
 //! support any of them natively, so Typst has to handle them manually.
+//!
+//! Glyphs of variable font instances are written as Type3 glyphs, too, since
+//! they can't be embedded in a subset with their variations applied.
 
 use std::collections::HashMap;
 
@@ -11,16 +14,17 @@ use ecow::eco_format;
 use indexmap::IndexMap;
 use pdf_writer::types::UnicodeCmap;
 use pdf_writer::writers::WMode;
-use pdf_writer::{Filter, Finish, Name, Rect, Ref};
+use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref};
+use ttf_parser::GlyphId;
 use typst_library::diag::{bail, error, SourceDiagnostic, SourceResult};
 use typst_library::foundations::Repr;
-use typst_library::layout::Em;
-use typst_library::text::color::glyph_frame;
+use typst_library::layout::{Em, Size};
+use typst_library::text::color::{glyph_frame, should_outline};
 use typst_library::text::{Font, Glyph, TextItemView};
 
 use crate::font::{base_font_name, write_font_descriptor, CMAP_NAME, SYSTEM_INFO};
 use crate::resources::{Resources, ResourcesRefs};
-use crate::{content, EmExt, PdfChunk, PdfOptions, WithGlobalRefs};
+use crate::{content, deflate_deferred, EmExt, PdfChunk, PdfOptions, WithGlobalRefs};
 
 /// Write color fonts in the PDF document.
 ///
@@ -243,20 +247,34 @@ impl ColorFontMap<()> {
                 self.total_slice_count += 1;
             }
 
-            let (frame, tofu) = glyph_frame(font, glyph.id);
-            if options.standards.pdfa && tofu {
-                bail!(failed_to_convert(text, glyph));
-            }
-
             let width = font.advance(glyph.id).unwrap_or(Em::new(0.0)).get()
                 * font.units_per_em();
-            let instructions = content::build(
-                options,
-                &mut self.resources,
-                &frame,
-                None,
-                Some(width as f32),
-            )?;
+            let instructions = if is_instanced(font, glyph) {
+                let content = shape_glyph(width as f32, |path| {
+                    font.ttf().outline_glyph(GlyphId(glyph.id), path)
+                });
+                content::Encoded {
+                    size: Size::zero(),
+                    content: deflate_deferred(content),
+                    uses_opacities: false,
+                    links: vec![],
+                    marked: vec![],
+                }
+            } else {
+                let (frame, tofu) = glyph_frame(font, glyph.id);
+                if options.standards.pdfa && tofu {
+                    bail!(failed_to_convert(text, glyph));
+                }
+
+                content::build(
+                    options,
+                    &mut self.resources,
+                    &frame,
+                    None,
+                    Some(width as f32),
+                    false,
+                )?
+            };
             color_font.glyphs.push(ColorGlyph { gid: glyph.id, instructions });
             color_font.glyph_indices.insert(glyph.id, index);
 
@@ -327,6 +345,86 @@ pub struct ColorFontSlice {
     pub subfont: usize,
 }
 
+/// Whether the glyph is an outline of a variable font instance.
+///
+/// The subsetter can't apply variation coordinates to the outlines, so such
+/// glyphs are drawn as Type3 glyphs from the instanced outlines instead of
+/// being embedded in a subset.
+fn is_instanced(font: &Font, glyph: &Glyph) -> bool {
+    !font.variations().is_empty() && should_outline(font, glyph)
+}
+
+/// Encodes an outline as an uncolored Type3 glyph program, which is filled
+/// with the current fill color like regular text.
+fn shape_glyph(
+    width: f32,
+    outline: impl FnOnce(&mut GlyphPath) -> Option<ttf_parser::Rect>,
+) -> Vec<u8> {
+    let mut path = GlyphPath { content: Content::new(), last: (0.0, 0.0) };
+    let bbox = outline(&mut path);
+
+    let mut content = Content::new();
+    match bbox {
+        Some(bbox) => content.start_shape_glyph(
+            width,
+            bbox.x_min.into(),
+            bbox.y_min.into(),
+            bbox.x_max.into(),
+            bbox.y_max.into(),
+        ),
+        None => content.start_shape_glyph(width, 0.0, 0.0, 0.0, 0.0),
+    };
+
+    let mut data = content.finish();
+    if bbox.is_some() {
+        path.content.fill_nonzero();
+        data.push(b'\n');
+        data.extend(path.content.finish());
+    }
+    data
+}
+
+/// Writes a glyph outline in font units into a content stream.
+struct GlyphPath {
+    content: Content,
+    last: (f32, f32),
+}
+
+impl ttf_parser::OutlineBuilder for GlyphPath {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.content.move_to(x, y);
+        self.last = (x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.content.line_to(x, y);
+        self.last = (x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        // PDF has no quadratic curves, so raise the degree of the curve.
+        let (x0, y0) = self.last;
+        self.content.cubic_to(
+            x0 + 2.0 / 3.0 * (x1 - x0),
+            y0 + 2.0 / 3.0 * (y1 - y0),
+            x + 2.0 / 3.0 * (x1 - x),
+            y + 2.0 / 3.0 * (y1 - y),
+            x,
+            y,
+        );
+        self.last = (x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.content.cubic_to(x1, y1, x2, y2, x, y);
+        self.last = (x, y);
+    }
+
+    fn close(&mut self) {
+        self.content.close_path();
+    }
+}
+
 /// The error when the glyph could not be converted.
 #[cold]
 fn failed_to_convert(text: &TextItemView, glyph: &Glyph) -> SourceDiagnostic {
@@ -342,3 +440,33 @@ fn failed_to_convert(text: &TextItemView, glyph: &Glyph) -> SourceDiagnostic {
 
     diag
 }
+
+#[cfg(test)]
+mod tests {
+    use ttf_parser::OutlineBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_shape_glyph_is_uncolored() {
+        let data = shape_glyph(500.0, |path| {
+            path.move_to(0.0, 0.0);
+            path.quad_to(30.0, 60.0, 60.0, 0.0);
+            path.close();
+            Some(ttf_parser::Rect { x_min: 0, y_min: 0, x_max: 60, y_max: 30 })
+        });
+        let ops: Vec<&str> = std::str::from_utf8(&data)
+            .unwrap()
+            .lines()
+            .map(|line| line.rsplit(' ').next().unwrap())
+            .collect();
+        assert!(data.starts_with(b"500 0 0 0 60 30 d1\n"));
+        assert_eq!(ops, ["d1", "m", "c", "h", "f"]);
+    }
+
+    #[test]
+    fn test_shape_glyph_without_outline() {
+        let data = shape_glyph(250.0, |_| None);
+        assert_eq!(data, b"250 0 0 0 0 0 d1");
+    }
+}
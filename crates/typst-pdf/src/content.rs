@@ -12,6 +12,7 @@ use pdf_writer::writers::PositionedItems;
 use pdf_writer::{Content, Finish, Name, Rect, Str};
 use typst_library::diag::{bail, error, SourceDiagnostic, SourceResult};
 use typst_library::foundations::Repr;
+use typst_library::introspection::{Location, Tag};
 use typst_library::layout::{
     Abs, Em, Frame, FrameItem, GroupItem, Point, Ratio, Size, Transform,
 };
@@ -30,6 +31,7 @@ use crate::color_font::ColorFontMap;
 use crate::extg::ExtGState;
 use crate::image::deferred_image;
 use crate::resources::Resources;
+use crate::tags::StructRole;
 use crate::{deflate_deferred, AbsExt, ContentExt, EmExt, PdfOptions, StrExt};
 
 /// Encode a [`Frame`] into a content stream.
@@ -46,9 +48,11 @@ pub fn build(
     frame: &Frame,
     fill: Option<Paint>,
     color_glyph_width: Option<f32>,
+    tagged: bool,
 ) -> SourceResult<Encoded> {
     let size = frame.size();
     let mut ctx = Builder::new(options, resources, size);
+    ctx.tagged = tagged;
 
     if let Some(width) = color_glyph_width {
         ctx.content.start_color_glyph(width);
@@ -64,7 +68,13 @@ pub fn build(
 
     if let Some(fill) = fill {
         let shape = Geometry::Rect(frame.size()).filled(fill);
+        if tagged {
+            ctx.content.begin_marked_content(Name(b"Artifact"));
+        }
         write_shape(&mut ctx, Point::zero(), &shape)?;
+        if tagged {
+            ctx.content.end_marked_content();
+        }
     }
 
     // Encode the frame into the content stream.
@@ -75,6 +85,7 @@ pub fn build(
         content: deflate_deferred(ctx.content.finish()),
         uses_opacities: ctx.uses_opacities,
         links: ctx.links,
+        marked: ctx.marked,
     })
 }
 
@@ -88,6 +99,9 @@ pub struct Encoded {
     pub uses_opacities: bool,
     /// Links in the PDF coordinate system.
     pub links: Vec<(Destination, Rect)>,
+    /// The elements of the marked content sequences in the stream, indexed by
+    /// their MCID.
+    pub marked: Vec<Location>,
 }
 
 /// An exporter for a single PDF content stream.
@@ -112,6 +126,17 @@ pub struct Builder<'a, R = ()> {
     uses_opacities: bool,
     /// All clickable links that are present in this content.
     links: Vec<(Destination, Rect)>,
+    /// Whether to mark up elements with a structural role.
+    tagged: bool,
+    /// The marked content sequences that were written so far.
+    marked: Vec<Location>,
+    /// The element of the currently open marked content sequence and the
+    /// frame nesting depth at which it was opened.
+    open: Option<(Location, usize)>,
+    /// The current frame nesting depth.
+    depth: usize,
+    /// Whether we are currently within an artifact.
+    artifact: bool,
 }
 
 impl<'a, R> Builder<'a, R> {
@@ -129,6 +154,11 @@ impl<'a, R> Builder<'a, R> {
             state: State::new(size),
             saves: vec![],
             links: vec![],
+            tagged: false,
+            marked: vec![],
+            open: None,
+            depth: 0,
+            artifact: false,
         }
     }
 }
@@ -369,6 +399,7 @@ impl Builder<'_, ()> {
 
 /// Encode a frame into the content stream.
 pub(crate) fn write_frame(ctx: &mut Builder, frame: &Frame) -> SourceResult<()> {
+    ctx.depth += 1;
     for &(pos, ref item) in frame.items() {
         let x = pos.x.to_f32();
         let y = pos.y.to_f32();
@@ -380,16 +411,82 @@ pub(crate) fn write_frame(ctx: &mut Builder, frame: &Frame) -> SourceResult<()>
                 write_image(ctx, x, y, image, *size, *span)?
             }
             FrameItem::Link(dest, size) => write_link(ctx, pos, dest, *size),
-            FrameItem::Tag(_) => {}
+            FrameItem::Tag(tag) => write_tag(ctx, tag),
         }
     }
+
+    // Marked content can't span multiple frames, so we close a sequence that
+    // was opened in this frame at its end.
+    if ctx.open.is_some_and(|(_, depth)| depth == ctx.depth) {
+        ctx.content.end_marked_content();
+        ctx.open = None;
+    }
+
+    ctx.depth -= 1;
     Ok(())
 }
 
+/// Open or close a marked content sequence for an element with a structural
+/// leaf role.
+fn write_tag(ctx: &mut Builder, tag: &Tag) {
+    if !ctx.tagged || ctx.artifact {
+        return;
+    }
+
+    match tag {
+        Tag::Start(elem) => {
+            // Marked content sequences with an MCID must not be nested.
+            if ctx.open.is_some() {
+                return;
+            }
+
+            // Elements that only group other elements get no content.
+            let Some(role) = StructRole::of(elem).filter(|role| !role.is_grouping())
+            else {
+                return;
+            };
+            let loc = elem.location().unwrap();
+            let mcid = ctx.marked.len() as i32;
+            let mut marked =
+                ctx.content.begin_marked_content_with_properties(role.name());
+            marked.properties().pair(Name(b"MCID"), mcid);
+            marked.finish();
+            ctx.marked.push(loc);
+            ctx.open = Some((loc, ctx.depth));
+        }
+        Tag::End(loc, _) => {
+            if ctx.open == Some((*loc, ctx.depth)) {
+                ctx.content.end_marked_content();
+                ctx.open = None;
+            }
+        }
+    }
+}
+
 /// Encode a group into the content stream.
 fn write_group(ctx: &mut Builder, pos: Point, group: &GroupItem) -> SourceResult<()> {
     let translation = Transform::translate(pos.x, pos.y);
 
+    // Groups that merely offset a soft frame, like the lines of a paragraph,
+    // don't need a graphics state of their own. Moving the origin there and
+    // back instead of saving and restoring the state keeps the current font
+    // and colors alive, so that consecutive runs with the same style don't
+    // have to set them again.
+    if !group.frame.kind().is_hard()
+        && group.transform.is_identity()
+        && group.clip_path.is_none()
+        && !(ctx.tagged && group.artifact)
+    {
+        let (transform, container) = (ctx.state.transform, ctx.state.container_transform);
+        ctx.transform(translation);
+        write_frame(ctx, &group.frame)?;
+        ctx.content
+            .transform([1.0, 0.0, 0.0, 1.0, -pos.x.to_f32(), -pos.y.to_f32()]);
+        ctx.state.transform = transform;
+        ctx.state.container_transform = container;
+        return Ok(());
+    }
+
     ctx.save_state()?;
 
     if group.frame.kind().is_hard() {
@@ -410,7 +507,21 @@ fn write_group(ctx: &mut Builder, pos: Point, group: &GroupItem) -> SourceResult
         ctx.content.end_path();
     }
 
+    // Mark decorative content like page headers as an artifact, so that it
+    // is excluded from the logical reading order.
+    let artifact = ctx.tagged && group.artifact && !ctx.artifact;
+    if artifact {
+        ctx.content.begin_marked_content(Name(b"Artifact"));
+        ctx.artifact = true;
+    }
+
     write_frame(ctx, &group.frame)?;
+
+    if artifact {
+        ctx.content.end_marked_content();
+        ctx.artifact = false;
+    }
+
     ctx.restore_state();
 
     Ok(())
@@ -426,8 +537,11 @@ fn write_text(ctx: &mut Builder, pos: Point, text: &TextItem) -> SourceResult<()
         );
     }
 
-    let outline_glyphs =
-        text.glyphs.iter().filter(|g| should_outline(&text.font, g)).count();
+    // Glyphs of variable font instances can't be embedded with their
+    // variations applied, so they are drawn like color glyphs.
+    let embed =
+        |g: &Glyph| should_outline(&text.font, g) && text.font.variations().is_empty();
+    let outline_glyphs = text.glyphs.iter().filter(|g| embed(g)).count();
 
     if outline_glyphs == text.glyphs.len() {
         write_normal_text(ctx, pos, TextItemView::full(text))?;
@@ -437,9 +551,7 @@ fn write_text(ctx: &mut Builder, pos: Point, text: &TextItem) -> SourceResult<()
         // Otherwise we need to split it into smaller text runs.
         let mut offset = 0;
         let mut position_in_run = Abs::zero();
-        for (should_outline, sub_run) in
-            text.glyphs.group_by_key(|g| should_outline(&text.font, g))
-        {
+        for (embedded, sub_run) in text.glyphs.group_by_key(embed) {
             let end = offset + sub_run.len();
 
             // Build a sub text-run
@@ -451,7 +563,7 @@ fn write_text(ctx: &mut Builder, pos: Point, text: &TextItem) -> SourceResult<()
             offset = end;
 
             // Actually write the sub text-run.
-            if should_outline {
+            if embedded {
                 write_normal_text(ctx, pos, text_item_view)?;
             } else {
                 write_complex_glyphs(ctx, pos, text_item_view)?;
@@ -592,7 +704,15 @@ fn write_complex_glyphs(
 
     let mut last_font = None;
 
-    ctx.reset_opacities();
+    // Glyphs of variable font instances are uncolored, so they take the fill
+    // of the text.
+    if text.item.font.variations().is_empty() {
+        ctx.reset_opacities();
+    } else {
+        let fill_transform = ctx.state.transforms(Size::zero(), pos);
+        ctx.set_fill(&text.item.fill, true, fill_transform)?;
+        ctx.set_opacities(None, Some(&text.item.fill));
+    }
 
     ctx.content.begin_text();
     ctx.content.set_text_matrix([1.0, 0.0, 0.0, -1.0, x, y]);
@@ -825,3 +945,135 @@ fn tofu(text: &TextItemView, glyph: &Glyph) -> SourceDiagnostic {
         text.glyph_text(glyph).repr(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::visualize::Color;
+
+    use super::*;
+
+    /// Encode a frame and return its uncompressed content stream.
+    fn encode(frame: &Frame, tagged: bool) -> String {
+        let mut resources = Resources::default();
+        let options = PdfOptions::default();
+        let encoded = build(&options, &mut resources, frame, None, None, tagged).unwrap();
+        let data =
+            miniz_oxide::inflate::decompress_to_vec_zlib(encoded.content.wait()).unwrap();
+        String::from_utf8(data).unwrap()
+    }
+
+    /// Count the lines of a content stream that end with the given operator.
+    fn ops(content: &str, op: &str) -> usize {
+        content
+            .lines()
+            .filter(|line| line.rsplit(' ').next() == Some(op))
+            .count()
+    }
+
+    /// A frame with a black square.
+    fn square(kind: fn(Size) -> Frame) -> Frame {
+        let size = Size::splat(Abs::pt(5.0));
+        let mut frame = kind(size);
+        let shape = Geometry::Rect(size).filled(Color::BLACK);
+        frame.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+        frame
+    }
+
+    /// Push a group with the given frame.
+    fn push_group(frame: &mut Frame, pos: Point, inner: Frame, artifact: bool) {
+        let mut group = GroupItem::new(inner);
+        group.artifact = artifact;
+        frame.push(pos, FrameItem::Group(group));
+    }
+
+    #[test]
+    fn test_marginals_are_artifacts() {
+        // A page with body content, a page number in the footer and a
+        // watermark in the foreground.
+        let mut page = square(Frame::hard);
+        let footer = square(Frame::soft);
+        let watermark = square(Frame::soft);
+        push_group(&mut page, Point::with_y(Abs::pt(50.0)), footer, true);
+        push_group(&mut page, Point::zero(), watermark, true);
+
+        let tagged = encode(&page, true);
+        assert_eq!(ops(&tagged, "BMC"), 2);
+        assert_eq!(ops(&tagged, "EMC"), 2);
+        assert!(tagged
+            .lines()
+            .all(|line| !line.ends_with("BMC") || line == "/Artifact BMC"));
+
+        // The body comes before the first artifact.
+        let first = tagged.find("/Artifact BMC").unwrap();
+        assert!(tagged[..first].lines().any(|line| line.ends_with(" re")));
+
+        let untagged = encode(&page, false);
+        assert_eq!(ops(&untagged, "BMC"), 0);
+    }
+
+    #[test]
+    fn test_soft_groups_are_offset_without_state() {
+        let mut frame = Frame::hard(Size::splat(Abs::pt(50.0)));
+        let pos = Point::new(Abs::pt(10.0), Abs::pt(20.0));
+        push_group(&mut frame, pos, square(Frame::soft), false);
+        push_group(&mut frame, pos, square(Frame::soft), false);
+
+        // An offset group writes the same content as a shape that is offset
+        // by hand, apart from moving the origin there and back.
+        let grouped = encode(&frame, false);
+        assert_eq!(ops(&grouped, "q"), 0);
+        assert_eq!(ops(&grouped, "Q"), 0);
+        assert_eq!(ops(&grouped, "cm"), 5);
+        assert_eq!(grouped.matches("1 0 0 1 10 20 cm").count(), 2);
+        assert_eq!(grouped.matches("1 0 0 1 -10 -20 cm").count(), 2);
+
+        // The fill survives from one group to the next.
+        assert_eq!(ops(&grouped, "sc"), 1);
+
+        // A hard group needs a graphics state of its own, which is restored
+        // afterwards, so the second square has to set the fill again.
+        let mut hard = Frame::hard(Size::splat(Abs::pt(50.0)));
+        push_group(&mut hard, pos, square(Frame::hard), false);
+        push_group(&mut hard, pos, square(Frame::hard), false);
+        let hard = encode(&hard, false);
+        assert_eq!(ops(&hard, "q"), 2);
+        assert_eq!(ops(&hard, "Q"), 2);
+        assert_eq!(ops(&hard, "sc"), 2);
+    }
+
+    #[test]
+    fn test_nested_soft_groups_restore_the_origin() {
+        let mut inner = Frame::soft(Size::splat(Abs::pt(20.0)));
+        push_group(&mut inner, Point::splat(Abs::pt(5.0)), square(Frame::soft), false);
+        let mut frame = Frame::hard(Size::splat(Abs::pt(50.0)));
+        push_group(&mut frame, Point::new(Abs::pt(10.0), Abs::pt(20.0)), inner, false);
+        frame.push(
+            Point::zero(),
+            FrameItem::Shape(
+                Geometry::Rect(Size::splat(Abs::pt(5.0))).filled(Color::BLACK),
+                Span::detached(),
+            ),
+        );
+
+        let content = encode(&frame, false);
+        let moves: Vec<&str> =
+            content.lines().filter(|line| line.ends_with(" cm")).collect();
+        assert_eq!(
+            moves[1..],
+            [
+                "1 0 0 1 10 20 cm",
+                "1 0 0 1 5 5 cm",
+                "1 0 0 1 -5 -5 cm",
+                "1 0 0 1 -10 -20 cm",
+            ]
+        );
+        assert_eq!(ops(&content, "q"), 0);
+
+        // Both squares are drawn at the same place in their own coordinate
+        // system as the origin is back at the top left for the second one.
+        let squares: Vec<&str> =
+            content.lines().filter(|line| line.ends_with(" re")).collect();
+        assert_eq!(squares.len(), 2);
+        assert_eq!(squares[0], squares[1]);
+    }
+}
@@ -206,7 +206,9 @@ pub fn write_font_descriptor<'a>(
 /// - For a font with TrueType outlines, this produces the whole OpenType font.
 /// - For a font with CFF outlines, this produces just the CFF font program.
 ///
-/// In both cases, this returns the already compressed data.
+/// In both cases, this returns the already compressed data. Instances of
+/// variable fonts never end up here as the subsetter can't apply variation
+/// coordinates to the outlines. Their glyphs are drawn as Type3 glyphs instead.
 #[comemo::memoize]
 #[typst_macros::time(name = "subset font")]
 fn subset_font(
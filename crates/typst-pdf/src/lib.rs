@@ -13,6 +13,7 @@ mod outline;
 mod page;
 mod pattern;
 mod resources;
+mod tags;
 
 use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
@@ -64,7 +64,7 @@ pub fn write_named_destinations(
 
         let pos = context.document.introspector.position(loc);
         let index = pos.page.get() - 1;
-        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
+        let y = (pos.point.y - context.document.info.link_offset).max(Abs::zero());
 
         if let Some((Some(page), Some(page_ref))) =
             context.pages.get(index).zip(context.globals.pages.get(index))
@@ -195,7 +195,7 @@ fn write_outline_item(
     if let Some((Some(page), Some(page_ref))) =
         ctx.pages.get(index).zip(ctx.globals.pages.get(index))
     {
-        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
+        let y = (pos.point.y - ctx.document.info.link_offset).max(Abs::zero());
         outline.dest().page(*page_ref).xyz(
             pos.point.x.to_f32(),
             (page.content.size.y - y).to_f32(),
@@ -71,6 +71,7 @@ fn construct_page(
             &page.frame,
             page.fill_or_transparent(),
             None,
+            true,
         )?,
         label: None,
     })
@@ -163,7 +164,7 @@ fn write_page(
         };
 
         let index = pos.page.get() - 1;
-        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
+        let y = (pos.point.y - ctx.document.info.link_offset).max(Abs::zero());
 
         // Don't add links to non-exported pages.
         if let Some((Some(page), Some(page_ref))) =
@@ -199,6 +200,12 @@ fn write_page(
 
     page_writer.annotations(annotations);
 
+    // Link the page's marked content to the structure tree.
+    if !page.content.marked.is_empty() {
+        page_writer.pair(Name(b"StructParents"), i as i32);
+        page_writer.pair(Name(b"Tabs"), Name(b"S"));
+    }
+
     page_writer.finish();
 
     chunk
@@ -259,7 +266,7 @@ impl PdfPageLabel {
                 Kind::LowerRoman => Some(Style::LowerRoman),
                 Kind::UpperRoman => Some(Style::UpperRoman),
                 Kind::LowerLatin if number <= 26 => Some(Style::LowerAlpha),
-                Kind::LowerLatin if number <= 26 => Some(Style::UpperAlpha),
+                Kind::UpperLatin if number <= 26 => Some(Style::UpperAlpha),
                 _ => None,
             }
         } else {
@@ -308,3 +315,86 @@ pub struct EncodedPage {
     pub content: content::Encoded,
     pub label: Option<PdfPageLabel>,
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::{Content, Smart};
+    use typst_library::layout::{Frame, FrameItem, PagedDocument, Point, Position, Size};
+    use typst_library::model::{DocumentInfo, NumberingPattern};
+
+    use super::*;
+
+    fn label(pattern: &str, number: usize) -> Option<PdfPageLabel> {
+        let pattern: NumberingPattern = pattern.parse().unwrap();
+        PdfPageLabel::generate(&Numbering::Pattern(pattern), number)
+    }
+
+    #[test]
+    fn test_upper_latin_page_labels() {
+        assert_eq!(
+            label("A", 3),
+            Some(PdfPageLabel {
+                prefix: None,
+                style: Some(PdfPageLabelStyle::UpperAlpha),
+                offset: NonZeroUsize::new(3),
+            })
+        );
+        assert_eq!(
+            label("A", 27),
+            Some(PdfPageLabel {
+                prefix: Some("AA".into()),
+                style: None,
+                offset: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_lower_latin_page_labels() {
+        assert_eq!(label("a", 26).unwrap().style, Some(PdfPageLabelStyle::LowerAlpha));
+        assert_eq!(label("a", 27).unwrap().prefix.as_deref(), Some("aa"));
+    }
+
+    /// Export a page with a link to a point 50pt from its top and return the
+    /// destination's coordinates.
+    fn link_destination(link_offset: Abs) -> (f32, f32) {
+        let size = Size::splat(Abs::pt(100.0));
+        let mut frame = Frame::hard(size);
+        let position = Position {
+            page: NonZeroUsize::MIN,
+            point: Point::new(Abs::pt(20.0), Abs::pt(50.0)),
+        };
+        frame.push(
+            Point::zero(),
+            FrameItem::Link(Destination::Position(position), Size::splat(Abs::pt(5.0))),
+        );
+
+        let page = Page {
+            frame,
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 1,
+        };
+        let document = PagedDocument {
+            pages: vec![page],
+            info: DocumentInfo { link_offset, ..DocumentInfo::default() },
+            ..PagedDocument::default()
+        };
+
+        let data = crate::pdf(&document, &PdfOptions::default()).unwrap();
+        let data = String::from_utf8_lossy(&data);
+        let (_, rest) = data.split_once("/XYZ ").unwrap();
+        let mut coords = rest.split(' ').map(|v| v.parse::<f32>().unwrap());
+        (coords.next().unwrap(), coords.next().unwrap())
+    }
+
+    #[test]
+    fn test_link_destination_is_offset() {
+        // PDF coordinates start at the bottom of the page.
+        assert_eq!(link_destination(Abs::pt(10.0)), (20.0, 60.0));
+        assert_eq!(link_destination(Abs::pt(30.0)), (20.0, 80.0));
+        assert_eq!(link_destination(Abs::zero()), (20.0, 50.0));
+        assert_eq!(link_destination(Abs::pt(80.0)), (20.0, 100.0));
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+
+use pdf_writer::{Finish, Name, Pdf, Ref};
+use typst_library::foundations::{Content, StyleChain};
+use typst_library::introspection::{Location, Tag};
+use typst_library::layout::{Frame, FrameItem};
+use typst_library::model::{
+    EnumElem, EnumItem, FigureElem, HeadingElem, ListElem, ListItem, ParElem, TableCell,
+    TableElem,
+};
+
+use crate::WithEverything;
+
+/// The role of an element in the logical structure of the document.
+///
+/// Elements with a role are referenced from the structure tree, which allows
+/// assistive technology to discover the document's hierarchy. The content of
+/// elements with a leaf role is wrapped in marked content sequences in the
+/// page content streams. Elements with a grouping role only contain other
+/// structure elements. Content without a role, like list markers and table
+/// lines, isn't part of the structure tree, which is why the document isn't
+/// marked as a fully tagged PDF.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StructRole {
+    /// A paragraph.
+    Paragraph,
+    /// A heading of the given level.
+    Heading(NonZeroUsize),
+    /// A bullet or numbered list.
+    List,
+    /// An item of a list.
+    ListItem,
+    /// A table.
+    Table,
+    /// A row of a table. Rows aren't elements of their own, so they are
+    /// derived from the position of the cells.
+    TableRow,
+    /// A table cell in the row with the given index.
+    TableCell(usize),
+    /// A figure.
+    Figure,
+}
+
+impl StructRole {
+    /// Determine the role of an element, if it has one.
+    pub fn of(elem: &Content) -> Option<Self> {
+        let styles = StyleChain::default();
+        Some(if elem.is::<ParElem>() {
+            Self::Paragraph
+        } else if let Some(heading) = elem.to_packed::<HeadingElem>() {
+            Self::Heading(heading.resolve_level(styles))
+        } else if elem.is::<ListElem>() || elem.is::<EnumElem>() {
+            Self::List
+        } else if elem.is::<ListItem>() || elem.is::<EnumItem>() {
+            Self::ListItem
+        } else if elem.is::<TableElem>() {
+            Self::Table
+        } else if let Some(cell) = elem.to_packed::<TableCell>() {
+            Self::TableCell(cell.y(styles).custom().unwrap_or_default())
+        } else if elem.is::<FigureElem>() {
+            Self::Figure
+        } else {
+            return None;
+        })
+    }
+
+    /// Whether elements with this role only group other structure elements
+    /// instead of having content of their own.
+    pub fn is_grouping(self) -> bool {
+        matches!(self, Self::List | Self::Table | Self::TableRow)
+    }
+
+    /// The name of the standard structure type for this role.
+    pub fn name(self) -> Name<'static> {
+        match self {
+            Self::Paragraph => Name(b"P"),
+            Self::Heading(level) => Name(match level.get() {
+                1 => b"H1",
+                2 => b"H2",
+                3 => b"H3",
+                4 => b"H4",
+                5 => b"H5",
+                _ => b"H6",
+            }),
+            Self::List => Name(b"L"),
+            Self::ListItem => Name(b"LI"),
+            Self::Table => Name(b"Table"),
+            Self::TableRow => Name(b"TR"),
+            Self::TableCell(_) => Name(b"TD"),
+            Self::Figure => Name(b"Figure"),
+        }
+    }
+}
+
+/// An element in the structure tree.
+struct Node {
+    /// The element's role.
+    role: StructRole,
+    /// The index of the parent element, if it isn't a direct child of the
+    /// document.
+    parent: Option<usize>,
+    /// The structure elements within this element, in document order.
+    children: Vec<usize>,
+    /// The marked content sequences of the element, as pairs of the page they
+    /// are on and their MCID.
+    content: Vec<(Ref, usize)>,
+}
+
+/// Determines the structure elements from the nesting of the introspection
+/// tags in the document.
+#[derive(Default)]
+struct Nesting {
+    /// The structure elements in document order, so that parents precede
+    /// their children.
+    nodes: Vec<Node>,
+    /// The indices of the elements with a role.
+    indices: HashMap<Location, usize>,
+    /// The indices of the synthesized table rows, per table index and row.
+    rows: HashMap<(usize, usize), usize>,
+    /// The currently open elements with a role.
+    stack: Vec<(Location, usize)>,
+}
+
+impl Nesting {
+    /// Visit the tags in a frame.
+    fn visit(&mut self, frame: &Frame) {
+        for (_, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => self.visit(&group.frame),
+                FrameItem::Tag(Tag::Start(elem)) => {
+                    let Some(role) = StructRole::of(elem) else { continue };
+                    let loc = elem.location().unwrap();
+
+                    // Repeated content, like the header of a table, appears
+                    // multiple times, but is only one element.
+                    let index = match self.indices.get(&loc) {
+                        Some(&index) => index,
+                        None => {
+                            let parent = self.stack.last().map(|&(_, index)| index);
+                            let index = self.push(role, parent);
+                            self.indices.insert(loc, index);
+                            index
+                        }
+                    };
+                    self.stack.push((loc, index));
+                }
+                FrameItem::Tag(Tag::End(loc, _)) => {
+                    if let Some(i) = self.stack.iter().rposition(|&(l, _)| l == *loc) {
+                        self.stack.truncate(i);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Add a structure element.
+    fn push(&mut self, role: StructRole, mut parent: Option<usize>) -> usize {
+        // Cells are grouped into rows within their table.
+        if let (StructRole::TableCell(y), Some(table)) = (role, parent) {
+            if self.nodes[table].role == StructRole::Table {
+                parent = Some(match self.rows.get(&(table, y)) {
+                    Some(&row) => row,
+                    None => {
+                        let row = self.push(StructRole::TableRow, Some(table));
+                        self.rows.insert((table, y), row);
+                        row
+                    }
+                });
+            }
+        }
+
+        let index = self.nodes.len();
+        self.nodes
+            .push(Node { role, parent, children: vec![], content: vec![] });
+        if let Some(parent) = parent {
+            self.nodes[parent].children.push(index);
+        }
+        index
+    }
+}
+
+/// Write the structure tree for the marked content of all pages.
+///
+/// Each page's marked content sequences are identified by their index, which
+/// is used as the sequence's MCID. The parent tree maps each page's
+/// `StructParents` key, which is the page's index, to the structure elements
+/// the sequences belong to. Elements without any content, e.g. because they
+/// are nested in another element with content, are left out.
+pub(crate) fn write_structure(
+    chunk: &mut Pdf,
+    alloc: &mut Ref,
+    ctx: &WithEverything,
+) -> Option<Ref> {
+    let mut nesting = Nesting::default();
+    for page in &ctx.document.pages {
+        nesting.visit(&page.frame);
+    }
+
+    let mut parents = vec![];
+    for (i, page) in ctx.pages.iter().enumerate() {
+        let Some((page, page_ref)) = page.as_ref().zip(ctx.globals.pages[i]) else {
+            continue;
+        };
+
+        if page.content.marked.is_empty() {
+            continue;
+        }
+
+        let mut indices = Vec::with_capacity(page.content.marked.len());
+        for (mcid, loc) in page.content.marked.iter().enumerate() {
+            let index = nesting.indices[loc];
+            nesting.nodes[index].content.push((page_ref, mcid));
+            indices.push(index);
+        }
+
+        parents.push((i, indices));
+    }
+
+    // Children follow their parents, so going backwards determines whether
+    // an element has content before its parent is visited.
+    let nodes = &nesting.nodes;
+    let mut filled = vec![false; nodes.len()];
+    for (i, node) in nodes.iter().enumerate().rev() {
+        filled[i] = !node.content.is_empty() || node.children.iter().any(|&c| filled[c]);
+    }
+
+    if !filled.contains(&true) {
+        return None;
+    }
+
+    let root_ref = alloc.bump();
+    let document_ref = alloc.bump();
+    let refs: Vec<Option<Ref>> =
+        filled.iter().map(|&filled| filled.then(|| alloc.bump())).collect();
+
+    for (node, &id) in nodes.iter().zip(&refs) {
+        let Some(id) = id else { continue };
+        let mut element = chunk.indirect(id).dict();
+        element.pair(Name(b"Type"), Name(b"StructElem"));
+        element.pair(Name(b"S"), node.role.name());
+        element
+            .pair(Name(b"P"), node.parent.and_then(|p| refs[p]).unwrap_or(document_ref));
+
+        let mut array = element.insert(Name(b"K")).array();
+        array.items(node.children.iter().filter_map(|&c| refs[c]));
+        for &(page_ref, mcid) in &node.content {
+            let mut reference = array.push().dict();
+            reference.pair(Name(b"Type"), Name(b"MCR"));
+            reference.pair(Name(b"Pg"), page_ref);
+            reference.pair(Name(b"MCID"), mcid as i32);
+        }
+    }
+
+    let mut document = chunk.indirect(document_ref).dict();
+    document.pair(Name(b"Type"), Name(b"StructElem"));
+    document.pair(Name(b"S"), Name(b"Document"));
+    document.pair(Name(b"P"), root_ref);
+    document.insert(Name(b"K")).array().items(
+        nodes
+            .iter()
+            .zip(&refs)
+            .filter(|(node, _)| node.parent.is_none())
+            .filter_map(|(_, &id)| id),
+    );
+    document.finish();
+
+    let mut root = chunk.indirect(root_ref).dict();
+    root.pair(Name(b"Type"), Name(b"StructTreeRoot"));
+    root.pair(Name(b"K"), document_ref);
+
+    let mut tree = root.insert(Name(b"ParentTree")).dict();
+    let mut nums = tree.insert(Name(b"Nums")).array();
+    for (key, indices) in &parents {
+        nums.item(*key as i32);
+        nums.push()
+            .array()
+            .items(indices.iter().filter_map(|&index| refs[index]));
+    }
+    nums.finish();
+    tree.finish();
+
+    root.pair(Name(b"ParentTreeNextKey"), ctx.pages.len() as i32);
+    root.finish();
+
+    Some(root_ref)
+}
+
+#[cfg(test)]
+mod tests {
+    use typst_library::foundations::{Content, Smart};
+    use typst_library::introspection::{Introspector, Location, Tag};
+    use typst_library::layout::{
+        Abs, Frame, FrameItem, Page, PagedDocument, Point, Size,
+    };
+    use typst_library::model::{DocumentInfo, HeadingElem, TableCell, TableElem};
+    use typst_library::visualize::{Color, Geometry};
+    use typst_syntax::Span;
+
+    use crate::PdfOptions;
+
+    /// Export a single page with the given frame.
+    fn export(frame: Frame) -> Vec<u8> {
+        let pages = vec![Page {
+            frame,
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 1,
+        }];
+        let document = PagedDocument {
+            introspector: Introspector::paged(&pages),
+            pages,
+            info: DocumentInfo::default(),
+        };
+        crate::pdf(&document, &PdfOptions::default()).unwrap()
+    }
+
+    /// Count the occurrences of a byte string in the exported PDF.
+    fn count(pdf: &[u8], needle: &[u8]) -> usize {
+        pdf.windows(needle.len()).filter(|&w| w == needle).count()
+    }
+
+    #[test]
+    fn test_structure_tree_for_heading() {
+        let size = Size::splat(Abs::pt(10.0));
+        let loc = Location::new(1);
+        let heading = HeadingElem::new(Content::empty()).pack().located(loc);
+        let shape = Geometry::Rect(size).filled(Color::BLACK);
+
+        let mut frame = Frame::hard(size);
+        frame.push(Point::zero(), FrameItem::Tag(Tag::Start(heading)));
+        frame.push(Point::zero(), FrameItem::Shape(shape.clone(), Span::detached()));
+        frame.push(Point::zero(), FrameItem::Tag(Tag::End(loc, 0)));
+        frame.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+
+        let pdf = export(frame);
+        let contains = |needle: &[u8]| count(&pdf, needle) > 0;
+        assert!(contains(b"/StructTreeRoot"));
+        assert!(contains(b"/S /Document"));
+        assert!(contains(b"/S /H1"));
+        assert!(contains(b"/MCID 0"));
+        assert!(contains(b"/StructParents 0"));
+        assert!(!contains(b"/MCID 1"));
+        assert!(!contains(b"/Marked"));
+    }
+
+    #[test]
+    fn test_structure_tree_for_table() {
+        let size = Size::splat(Abs::pt(10.0));
+        let shape = Geometry::Rect(size).filled(Color::BLACK);
+        let table_loc = Location::new(1);
+        let table = TableElem::new(vec![]).pack().located(table_loc);
+
+        // A table with two rows, the first of which has two cells.
+        let mut frame = Frame::hard(size);
+        frame.push(Point::zero(), FrameItem::Tag(Tag::Start(table)));
+        for (i, y) in [0, 0, 1].into_iter().enumerate() {
+            let loc = Location::new(2 + i as u128);
+            let cell = TableCell::new(Content::empty())
+                .with_y(Smart::Custom(y))
+                .pack()
+                .located(loc);
+            let mut inner = Frame::hard(size);
+            inner.push(Point::zero(), FrameItem::Tag(Tag::Start(cell)));
+            inner.push(Point::zero(), FrameItem::Shape(shape.clone(), Span::detached()));
+            inner.push(Point::zero(), FrameItem::Tag(Tag::End(loc, 0)));
+            frame.push_frame(Point::zero(), inner);
+        }
+        frame.push(Point::zero(), FrameItem::Tag(Tag::End(table_loc, 0)));
+
+        // The table and its rows only hold structure elements, while each
+        // cell has its own marked content.
+        let pdf = export(frame);
+        assert_eq!(count(&pdf, b"/S /Table"), 1);
+        assert_eq!(count(&pdf, b"/S /TR"), 2);
+        assert_eq!(count(&pdf, b"/S /TD"), 3);
+        assert_eq!(count(&pdf, b"/Type /MCR"), 3);
+        assert_eq!(count(&pdf, b"/MCID 2"), 1);
+        assert_eq!(count(&pdf, b"/MCID 3"), 0);
+    }
+}
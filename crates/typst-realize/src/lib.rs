@@ -6,11 +6,14 @@
 
 use std::borrow::Cow;
 use std::cell::LazyCell;
+use std::ops::Range;
+use std::sync::LazyLock;
 
 use arrayvec::ArrayVec;
 use bumpalo::collections::{String as BumpString, Vec as BumpVec};
 use comemo::Track;
 use ecow::EcoString;
+use regex::Regex;
 use typst_library::diag::{bail, At, SourceResult};
 use typst_library::engine::Engine;
 use typst_library::foundations::{
@@ -26,10 +29,12 @@ use typst_library::layout::{
 use typst_library::math::{EquationElem, Mathy};
 use typst_library::model::{
     CiteElem, CiteGroup, DocumentElem, EnumElem, ListElem, ListItemLike, ListLike,
-    ParElem, ParbreakElem, TermsElem,
+    ParElem, ParbreakElem, RefGroup, RefPart, TermsElem,
 };
 use typst_library::routines::{Arenas, Pair, RealizationKind};
-use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
+use typst_library::text::{
+    KernElem, Lang, LinebreakElem, SmartQuoteElem, SpaceElem, SuperElem, TextElem,
+};
 use typst_syntax::Span;
 use typst_utils::{SliceExt, SmallBitSet};
 
@@ -313,6 +318,27 @@ fn visit_math_rules<'a>(
     Ok(false)
 }
 
+/// Whether the suffix of an ordinal is the right one for its number in the
+/// given language.
+fn ordinal_agrees(lang: Lang, number: &str, suffix: &str) -> bool {
+    let mut digits = number.bytes().rev();
+    let (last, tens) = (digits.next(), digits.next());
+    match lang {
+        Lang::ENGLISH => {
+            let expected = match (tens, last) {
+                (Some(b'1'), _) => "th",
+                (_, Some(b'1')) => "st",
+                (_, Some(b'2')) => "nd",
+                (_, Some(b'3')) => "rd",
+                _ => "th",
+            };
+            suffix == expected
+        }
+        Lang::FRENCH => (number == "1") == matches!(suffix, "er" | "re"),
+        _ => false,
+    }
+}
+
 /// Tries to apply show rules to or prepare content. Returns `true` if the
 /// element was handled.
 fn visit_show_rules<'a>(
@@ -777,17 +803,19 @@ fn finish_innermost_grouping(s: &mut State) -> SourceResult<()> {
 const MAX_GROUP_NESTING: usize = 3;
 
 /// Grouping rules used in layout realization.
-static LAYOUT_RULES: &[&GroupingRule] = &[&TEXTUAL, &PAR, &CITES, &LIST, &ENUM, &TERMS];
+static LAYOUT_RULES: &[&GroupingRule] =
+    &[&TEXTUAL, &PAR, &CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Grouping rules used in HTML root realization.
 static HTML_DOCUMENT_RULES: &[&GroupingRule] =
-    &[&TEXTUAL, &PAR, &CITES, &LIST, &ENUM, &TERMS];
+    &[&TEXTUAL, &PAR, &CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Grouping rules used in HTML fragment realization.
-static HTML_FRAGMENT_RULES: &[&GroupingRule] = &[&TEXTUAL, &CITES, &LIST, &ENUM, &TERMS];
+static HTML_FRAGMENT_RULES: &[&GroupingRule] =
+    &[&TEXTUAL, &CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Grouping rules used in math realizatio.
-static MATH_RULES: &[&GroupingRule] = &[&CITES, &LIST, &ENUM, &TERMS];
+static MATH_RULES: &[&GroupingRule] = &[&CITES, &REFS, &LIST, &ENUM, &TERMS];
 
 /// Groups adjacent textual elements for text show rule application.
 static TEXTUAL: GroupingRule = GroupingRule {
@@ -814,6 +842,7 @@ static PAR: GroupingRule = GroupingRule {
         let elem = content.elem();
         elem == TextElem::elem()
             || elem == HElem::elem()
+            || elem == KernElem::elem()
             || elem == LinebreakElem::elem()
             || elem == SmartQuoteElem::elem()
             || elem == InlineElem::elem()
@@ -842,6 +871,18 @@ static CITES: GroupingRule = GroupingRule {
     finish: finish_cites,
 };
 
+/// Collects `RefPart`s into `RefGroup`s.
+static REFS: GroupingRule = GroupingRule {
+    priority: 2,
+    tags: false,
+    trigger: |content, _| content.elem() == RefPart::elem(),
+    inner: |content| content.elem() == SpaceElem::elem(),
+    interrupt: |elem| {
+        elem == RefGroup::elem() || elem == ParElem::elem() || elem == AlignElem::elem()
+    },
+    finish: finish_refs,
+};
+
 /// Builds a `ListElem` from grouped `ListItems`s.
 static LIST: GroupingRule = list_like_grouping::<ListElem>();
 
@@ -881,6 +922,11 @@ fn finish_textual(Grouped { s, mut start }: Grouped) -> SourceResult<()> {
         return Ok(());
     }
 
+    // Raise ordinal suffixes in the text that no regex show rule claimed.
+    if visit_ordinals(s, start)? {
+        return Ok(());
+    }
+
     // There was no regex match, so we need to collect the text into a paragraph
     // grouping. To do that, we first terminate all non-paragraph groupings.
     if in_non_par_grouping(s) {
@@ -944,6 +990,24 @@ fn finish_cites(grouped: Grouped) -> SourceResult<()> {
     visit(s, s.store(elem), trunk)
 }
 
+/// Builds the `RefGroup` from `RefPart`s.
+fn finish_refs(grouped: Grouped) -> SourceResult<()> {
+    // Collect the children.
+    let elems = grouped.get();
+    let span = select_span(elems);
+    let trunk = elems[0].1;
+    let children = elems
+        .iter()
+        .filter_map(|(c, _)| c.to_packed::<RefPart>())
+        .cloned()
+        .collect();
+
+    // Create and visit the reference group.
+    let s = grouped.end();
+    let elem = RefGroup::new(children).pack().spanned(span);
+    visit(s, s.store(elem), trunk)
+}
+
 /// Builds the `ListLike` element from `ListItemLike` elements.
 fn finish_list_like<T: ListLike>(grouped: Grouped) -> SourceResult<()> {
     // Collect the children.
@@ -984,6 +1048,84 @@ fn visit_textual(s: &mut State, start: usize) -> SourceResult<bool> {
     Ok(false)
 }
 
+/// Visit textual elements in `s.sink[start..]` and set the suffix of the first
+/// ordinal like "1st" in superscript if enabled through `text.ordinals`.
+///
+/// This only happens after regex show rules had their chance, so that they
+/// still see the whole ordinal. The remaining text is visited again and may
+/// contain further ordinals.
+fn visit_ordinals(s: &mut State, start: usize) -> SourceResult<bool> {
+    let Some((i, elem, suffix)) =
+        s.sink[start..]
+            .iter()
+            .enumerate()
+            .find_map(|(i, &(content, styles))| {
+                let elem = content.to_packed::<TextElem>()?;
+                let suffix = find_ordinal_suffix(&elem.text, styles)?;
+                Some((start + i, elem, suffix))
+            })
+    else {
+        return Ok(false);
+    };
+
+    let piece = |text: &str| {
+        let mut piece = elem.clone();
+        piece.text = text.into();
+        piece.pack()
+    };
+
+    let elems = s.store_slice(&s.sink[start..]);
+    s.sink.truncate(start);
+    for (k, &(content, styles)) in elems.iter().enumerate() {
+        if start + k != i {
+            visit(s, content, styles)?;
+            continue;
+        }
+
+        // Visit the text before the suffix, the raised suffix, and then the
+        // rest.
+        let span = elem.span();
+        let raised = SuperElem::new(TextElem::packed(&elem.text[suffix.clone()]))
+            .pack()
+            .spanned(span);
+        visit(s, s.store(piece(&elem.text[..suffix.start])), styles)?;
+        visit(s, s.store(raised), styles)?;
+        if suffix.end < elem.text.len() {
+            visit(s, s.store(piece(&elem.text[suffix.end..])), styles)?;
+        }
+    }
+
+    Ok(true)
+}
+
+/// Finds the byte range of the first ordinal suffix in the text that agrees
+/// with its number, if ordinals are enabled for the text's language.
+fn find_ordinal_suffix(text: &str, styles: StyleChain) -> Option<Range<usize>> {
+    static ENGLISH: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\b(\d+)(st|nd|rd|th)\b").unwrap());
+    static FRENCH: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\b(\d+)(er|re|e)\b").unwrap());
+
+    if !TextElem::ordinals_in(styles) {
+        return None;
+    }
+
+    let lang = TextElem::lang_in(styles);
+    let regex = match lang {
+        Lang::ENGLISH => &ENGLISH,
+        Lang::FRENCH => &FRENCH,
+        _ => return None,
+    };
+
+    // Only raise suffixes that agree with their number, e.g. not the one in
+    // "2st".
+    regex
+        .captures_iter(text)
+        .find(|caps| ordinal_agrees(lang, &caps[1], &caps[2]))
+        .and_then(|caps| caps.get(2))
+        .map(|suffix| suffix.range())
+}
+
 /// Finds the leftmost regex match for this style chain in the given textual
 /// elements.
 ///
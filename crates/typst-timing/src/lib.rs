@@ -2,6 +2,7 @@
 
 #![cfg_attr(target_arch = "wasm32", allow(dead_code, unused_variables))]
 
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::io::Write;
 use std::num::NonZeroU64;
@@ -179,6 +180,56 @@ macro_rules! timed {
     }};
 }
 
+/// Aggregated timings of all scopes with the same name.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Summary {
+    /// The name of the scopes.
+    pub name: &'static str,
+    /// How often a scope with this name was entered.
+    pub calls: usize,
+    /// The total time spent in scopes with this name.
+    pub total: Duration,
+}
+
+/// Summarize the recorded events by scope name, sorted by descending total
+/// time.
+///
+/// Time spent in a nested scope counts towards both the nested and the
+/// enclosing scope. Scopes that haven't ended yet are ignored.
+pub fn summarize() -> Vec<Summary> {
+    let recorder = RECORDER.lock();
+    let mut starts = HashMap::new();
+    let mut indices = HashMap::new();
+    let mut summaries: Vec<Summary> = vec![];
+
+    for event in recorder.events.iter() {
+        match event.kind {
+            EventKind::Start => {
+                starts.insert(event.id, event.timestamp);
+            }
+            EventKind::End => {
+                let Some(start) = starts.remove(&event.id) else { continue };
+                let index = *indices.entry(event.name).or_insert_with(|| {
+                    summaries.push(Summary {
+                        name: event.name,
+                        calls: 0,
+                        total: Duration::ZERO,
+                    });
+                    summaries.len() - 1
+                });
+
+                let summary = &mut summaries[index];
+                summary.calls += 1;
+                summary.total +=
+                    event.timestamp.duration_since(start).unwrap_or(Duration::ZERO);
+            }
+        }
+    }
+
+    summaries.sort_by(|a, b| b.total.cmp(&a.total).then(a.name.cmp(b.name)));
+    summaries
+}
+
 /// Export data as JSON for Chrome's tracing tool.
 ///
 /// The `source` function is called for each span to get the source code
@@ -245,3 +296,25 @@ pub fn export_json<W: Write>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize() {
+        enable();
+        clear();
+        for _ in 0..3 {
+            let _outer = TimingScope::new("outer");
+            let _inner = TimingScope::new("inner");
+        }
+
+        let summaries = summarize();
+        let find = |name| summaries.iter().find(|s| s.name == name).unwrap();
+        let (outer, inner) = (find("outer"), find("inner"));
+        assert_eq!(summaries.len(), 2);
+        assert_eq!((outer.calls, inner.calls), (3, 3));
+        assert!(outer.total >= inner.total);
+    }
+}
@@ -347,8 +347,10 @@ pub static ROUTINES: Routines = Routines {
     layout_skew: typst_layout::layout_skew,
     layout_repeat: typst_layout::layout_repeat,
     layout_pad: typst_layout::layout_pad,
+    layout_watermark: typst_layout::layout_watermark,
     layout_line: typst_layout::layout_line,
     layout_path: typst_layout::layout_path,
+    layout_text_on_path: typst_layout::layout_text_on_path,
     layout_polygon: typst_layout::layout_polygon,
     layout_rect: typst_layout::layout_rect,
     layout_square: typst_layout::layout_square,
@@ -11,6 +11,42 @@
 #v(10pt)
 #context test(here().position().y, 20pt)
 
+--- locate-position-origin ---
+// Test measuring the position from other corners of the page.
+#context {
+  test(here().position(), (page: 1, x: 10pt, y: 10pt))
+  test(here().position(origin: bottom + left), (page: 1, x: 10pt, y: 10pt))
+  test(here().position(origin: top + right), (page: 1, x: 110pt, y: 10pt))
+  test(here().position(origin: center + horizon), (page: 1, x: -50pt, y: 0pt))
+}
+
+--- locate-end-page-single ---
+// An element on a single page ends where it starts.
+#hide[= Introduction <intro>]
+#context test(locate(<intro>).end-page(), locate(<intro>).page())
+
+--- locate-end-page-continued ---
+// A footer marks the pages onto which a table continues.
+#set page(height: 60pt, footer: context {
+  let table = locate(<long>)
+  let page = here().page()
+  metadata((page, table.page() < page and page <= table.end-page()))
+})
+
+#table(columns: 1, ..range(8).map(str)) <long>
+#pagebreak()
+
+#context {
+  let end = locate(<long>).end-page()
+  test(locate(<long>).page(), 1)
+  test(end, here().page() - 1)
+  test(end > 1, true)
+  test(
+    query(metadata).map(it => it.value),
+    range(1, end + 2).map(page => (page, 1 < page and page <= end)),
+  )
+}
+
 --- locate-missing-label ---
 // Error: 10-25 label `<intro>` does not exist in the document
 #context locate(<intro>)
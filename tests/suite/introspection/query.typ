@@ -250,3 +250,11 @@
   t("b")
   block(height: 1fr, metadata("b"))
 }
+
+--- query-page ---
+#metadata(1)
+#metadata(2)
+#context {
+  test(query(metadata, page: here().page()).map(m => m.value), (1, 2))
+  test(query(metadata, page: 2), ())
+}
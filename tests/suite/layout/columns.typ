@@ -129,3 +129,42 @@ Hallo
 A
 #colbreak(weak: true)
 B
+
+--- columns-balance ---
+// Test that balanced columns split the content evenly.
+#context {
+  let body = {
+    set block(spacing: 0pt)
+    for _ in range(9) {
+      block(width: 100%, height: 10pt)
+    }
+  }
+  let balanced = measure(width: 120pt, height: 200pt, columns(3, balance: true, body))
+  let unbalanced = measure(width: 120pt, height: 200pt, columns(3, body))
+  test(balanced.height, 30pt)
+  test(unbalanced.height, 90pt)
+}
+
+--- columns-balance-unbreakable ---
+// Test that balancing keeps unbreakable blocks intact.
+#context {
+  let body = {
+    set block(spacing: 0pt)
+    block(width: 100%, height: 10pt)
+    block(width: 100%, height: 10pt)
+    block(width: 100%, height: 10pt)
+    block(width: 100%, height: 40pt)
+  }
+  let size = measure(width: 120pt, height: 200pt, columns(2, balance: true, body))
+  test(size.height, 40pt)
+}
+
+--- colbreak-before-column-is-full ---
+// A manual column break moves to the next column even though the first one
+// still has room.
+#context {
+  let broken = measure(width: 100pt, columns(2)[A \ B #colbreak() C])
+  let filled = measure(width: 100pt, columns(2)[A \ B \ C])
+  test(broken.height, measure[A \ B].height)
+  test(broken.height < filled.height, true)
+}
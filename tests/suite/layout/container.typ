@@ -125,6 +125,31 @@ Paragraph
   #text(size: 8pt, block(above: 1.25em)[B])
 ]
 
+--- block-spacing-mode-exact ---
+// The spacing below the heading is measured between the baselines, regardless
+// of the font sizes on both sides.
+#show heading: set block(below: 30pt, spacing-mode: "exact")
+#place(hide(block(width: 100pt, {
+  heading(text(24pt)[Big #metadata(none) <big>])
+  text(6pt)[Small #metadata(none) <small>]
+})))
+
+#context {
+  let gap = locate(<small>).position().y - locate(<big>).position().y
+  assert(calc.abs(gap - 30pt) < 0.01pt)
+}
+
+--- block-spacing-mode-exact-region-end ---
+// The space freed up by measuring between the baselines is available to the
+// following content, so that it still fits into the page.
+#set page(height: 20pt, margin: 0pt)
+#set text(5pt)
+#hide[
+  #block(below: 15pt, spacing-mode: "exact")[A]
+  B #metadata(none) <b>
+]
+#context test(locate(<b>).page(), 1)
+
 --- block-fixed-height ---
 #set page(height: 100pt)
 #set align(center)
@@ -186,6 +211,22 @@ C
 #block(sticky: true, lines(4))
 E
 
+--- block-sticky-keep-with-next ---
+// A sticky block, like a heading, moves to the next region together with the
+// following block if that one doesn't fit anymore.
+#context {
+  let body(sticky) = {
+    set block(spacing: 0pt)
+    // Sticky blocks only take effect after a non-empty block.
+    block(width: 100%, height: 20pt, fill: aqua)
+    block(sticky: sticky, width: 100%, height: 5pt)
+    block(breakable: false, width: 100%, height: 10pt)
+  }
+  let size(sticky) = measure(width: 100pt, height: 30pt, columns(2, body(sticky)))
+  test(size(false).height, 25pt)
+  test(size(true).height, 20pt)
+}
+
 --- box-clip-rect ---
 // Test box clipping with a rectangle
 Hello #box(width: 1em, height: 1em, clip: false)[#rect(width: 3em, height: 3em, fill: red)]
@@ -196,6 +237,57 @@ Space
 Hello #box(width: 1em, height: 1em, clip: true)[#rect(width: 3em, height: 3em, fill: red)]
 world 2
 
+--- block-keep-together ---
+// A block that is kept together moves to the next region if it would fit
+// there wholly. Otherwise, it breaks as usual.
+#context {
+  let body(keep) = {
+    set block(spacing: 0pt)
+    block(width: 100%, height: 15pt)
+    block(keep-together: keep, {
+      block(width: 100%, height: 10pt)
+      block(width: 100%, height: 10pt)
+    })
+  }
+  let size(keep) = measure(width: 100pt, height: 30pt, columns(2, body(keep)))
+  test(size(false).height, 25pt)
+  test(size(true).height, 20pt)
+}
+
+--- block-open-breaks ---
+// Open breaks only affect decorations, not the layout of the fragments.
+#context {
+  let body(open) = block(
+    open-breaks: open,
+    stroke: 1pt,
+    radius: 2pt,
+    inset: 2pt,
+    { block(height: 15pt); block(height: 15pt) },
+  )
+  let size(open) = measure(width: 100pt, height: 30pt, columns(2, body(open)))
+  test(size(true), size(false))
+}
+#set block(open-breaks: true)
+#context test(block.open-breaks, true)
+
+--- block-overflow-clip ---
+// Content that does not fit into a fixed-height block is dropped instead of
+// spilling onto the next page.
+#set page(height: 20pt, margin: 0pt)
+#hide(block(height: 10pt, overflow: "clip", lorem(20)))
+#context test(counter(page).final(), (1,))
+
+--- block-overflow-clip-introspection ---
+// Elements in the dropped content remain visible to introspection.
+#set page(height: 20pt, margin: 0pt)
+#hide(block(height: 10pt, overflow: "clip")[#lorem(20) #metadata("end") <end>])
+#context test(query(<end>).map(it => it.value), ("end",))
+
+--- block-overflow-error ---
+// Error: 2-51 content does not fit into the block
+// Hint: 2-51 try increasing its height or using `overflow: "clip"`
+#block(height: 10pt, overflow: "error", lorem(20))
+
 --- block-clip-text ---
 // Test clipping text
 #block(width: 5em, height: 2em, clip: false, stroke: 1pt + black)[
@@ -279,3 +371,59 @@ First!
 // Test box in 100% width block.
 #block(width: 100%, fill: red, box("a box"))
 #block(width: 100%, fill: red, [#box("a box") #box()])
+
+--- box-fit ---
+// A long label shrinks until it fits on one line.
+#let label = [An exceptionally long label]
+#context {
+  let line = measure(label).height
+  let wrapped = measure(box(width: 3cm, label))
+  let fitted = measure(box(width: 3cm, fit: true, label))
+  test(wrapped.height > line, true)
+  test(fitted.height < line, true)
+  test(fitted.width, 3cm)
+}
+
+--- box-fit-min ---
+// The text is not shrunk below the minimum size, so it still wraps.
+#let label = [An exceptionally long label]
+#context {
+  let line = measure(label).height
+  let fitted = measure(box(width: 3cm, fit: true, fit-min: 10pt, label))
+  test(fitted.height > line, true)
+}
+
+--- box-fit-auto-width ---
+// Without a fixed width, fitting and overflow handling have no effect.
+#let label = [An exceptionally long label]
+#context {
+  let plain = measure(box(label), width: 3cm)
+  test(measure(box(fit: true, label), width: 3cm), plain)
+  test(measure(box(text-overflow: "ellipsis", label), width: 3cm), plain)
+}
+
+--- box-text-overflow-ellipsis ---
+// A long name is truncated with an ellipsis instead of wrapping.
+#let name = [An exceptionally *long* name]
+#context {
+  let line = measure(name).height
+  let wrapped = measure(box(width: 3cm, name))
+  let truncated = measure(box(width: 3cm, text-overflow: "ellipsis", name))
+  let clipped = measure(box(width: 3cm, text-overflow: "clip", name))
+  let short = measure(box(width: 3cm, text-overflow: "ellipsis")[Name])
+  test(wrapped.height > line, true)
+  test(truncated.height, line)
+  test(truncated.width, 3cm)
+  test(clipped, truncated)
+  test(short, measure(box(width: 3cm)[Name]))
+}
+
+--- box-text-overflow-styled ---
+// The truncated text keeps its styling.
+#show strong: it => [#metadata(it.body)#it]
+#place(hide(box(width: 2cm, text-overflow: "ellipsis")[A *very long* name]))
+#context test(query(metadata).map(m => m.value), ([very long],))
+
+--- box-text-overflow-bad ---
+// Error: 33-39 expected "clip", "ellipsis", or none
+#box(width: 1cm, text-overflow: "fade")[Name]
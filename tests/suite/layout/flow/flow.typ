@@ -80,3 +80,111 @@ Hi
 --- issue-5024-spill-backlog ---
 #set page(columns: 2, height: 50pt)
 #columns(2)[Hello]
+
+--- flow-no-trailing-empty-region ---
+// Content that exactly fills its regions doesn't produce an additional empty
+// region for trailing weak spacing.
+#set page(height: 20pt, margin: 0pt)
+#context test(counter(page).final(), (1,))
+#block(breakable: true, {
+  hide(block(height: 20pt))
+  colbreak()
+  v(4pt, weak: true)
+})
+
+--- flow-zero-height-block-at-full-region ---
+// A zero-height block at the end of an exactly full region stays in that
+// region instead of creating an otherwise empty one.
+#set page(height: 20pt, margin: 0pt)
+#context {
+  test(counter(page).final(), (1,))
+  test(locate(<anchor>).page(), 1)
+}
+#hide(block(height: 20pt))
+#block(height: 0pt)[#metadata(none) <anchor>]
+
+--- flow-spacing-depends-on-pair ---
+// The gap between two blocks depends on the spacing of both, so a list can be
+// spaced differently after a heading than after a paragraph.
+#show heading: set block(below: 20pt)
+#show list: set block(above: 5pt)
+#context {
+  let gap(first) = {
+    let body = list[Item]
+    let total = measure({ first; body }).height
+    total - measure(first).height - measure(body).height
+  }
+  assert(calc.abs((gap(heading[Title]) - 20pt).pt()) < 0.01)
+  assert(calc.abs((gap(par[Text]) - 5pt).pt()) < 0.01)
+}
+
+--- flow-baseline-grid-opt-out ---
+// A block that opts out of the baseline grid is placed directly after the
+// preceding content, but the lines after it are aligned to the grid again.
+#set page(height: 20pt, margin: 0pt)
+#set text(4pt)
+#set block(grid: 6pt)
+#context {
+  let on-grid(label) = {
+    let steps = locate(label).position().y.pt() / 6
+    calc.abs(steps - calc.round(steps)) < 0.01
+  }
+  assert(on-grid(<first>))
+  assert(on-grid(<after>))
+  // The first baseline lands on 6pt and the block follows 1pt below it.
+  let block-y = locate(<figure>).position().y
+  assert(calc.abs((block-y - 7pt).pt()) < 0.01)
+}
+
+#hide[
+  Text #metadata(none) <first>
+  #block(grid: none, above: 1pt, height: 2pt)[#metadata(none) <figure>]
+  Text #metadata(none) <after>
+]
+
+--- flow-grid-report ---
+// An image whose height isn't a multiple of the grid is reported along with the
+// space that was absorbed to bring the following block back onto the grid.
+#set page(height: 20pt, margin: 0pt)
+#hide[
+  #set block(grid: 4pt, grid-report: true, spacing: 0pt)
+  #block(height: 4pt)
+  // Warning: 4-50 block is 3pt off the baseline grid
+  // Hint: 4-50 the following content was moved down by 1pt to return to the grid
+  #image("/assets/images/tiger.jpg", height: 7pt)
+  #block(height: 4pt)
+  #block(height: 4pt)
+]
+
+--- flow-baseline-grid-nested ---
+// Only the page's own flow is aligned to the baseline grid, not the flows of
+// nested containers.
+#context test(
+  locate(<grid>).position().y,
+  locate(<plain>).position().y,
+)
+#let nested(grid, body) = place(hide(block({
+  set block(grid: grid)
+  block(height: 5pt)
+  body
+})))
+#nested(12pt)[Text #metadata(none) <grid>]
+#nested(none)[Text #metadata(none) <plain>]
+
+--- flow-fr-block-reaches-region-bottom ---
+// A fractionally sized block extends to the bottom of the region regardless of
+// how much content precedes it.
+#context test(query(<end>).map(m => m.location().position().y), (110pt, 110pt))
+#let sidebar(before) = place(hide(block(height: 100pt, {
+  block(height: before)
+  block(height: 1fr, width: 100%, fill: aqua)[#v(1fr) #metadata(none) <end>]
+})))
+#sidebar(10pt)
+#sidebar(60pt)
+
+--- flow-no-trailing-empty-page ---
+// The same holds for expanding regions like pages.
+#set page(height: 20pt, margin: 0pt)
+#context test(counter(page).final(), (1,))
+#hide(block(height: 20pt))
+#v(4pt, weak: true)
@@ -47,6 +47,31 @@ Beautiful footnotes. #footnote[Wonderful, aren't they?]
 #lines(6)
 #footnote[V] // 5
 
+--- footnote-break-continuation ---
+// The remainder of a broken footnote is placed below the next region's body,
+// with the continuation separator above it.
+#set page(height: 20pt, margin: 0pt, columns: 2)
+#set text(4pt)
+#set footnote.entry(
+  separator: hide[#metadata(none) <sep>#line(length: 100%)],
+  continuation: hide[#metadata(none) <cont>#line(length: 100%)],
+)
+#show footnote.entry: hide
+
+#hide[A#footnote[A \ B \ C \ #metadata(none) <rest> D \ E]]
+#colbreak()
+#hide[#metadata(none) <body> B]
+
+#context {
+  let (sep, cont) = (locate(<sep>).position(), locate(<cont>).position())
+  let (body, rest) = (locate(<body>).position(), locate(<rest>).position())
+  test(sep.x, 0pt)
+  test(cont.x > 50pt, true)
+  test(body.y < cont.y, true)
+  test(cont.y < rest.y, true)
+  test(rest.x > 50pt, true)
+}
+
 --- footnote-break-across-pages-block ---
 #set page(height: 100pt)
 #block[
@@ -315,3 +340,24 @@ A #footnote(numbering: "*")[B]<fn>, C @fn, D @fn, E @fn.
   float: true,
   footnote[b]
 )
+
+--- footnote-entry-min-body ---
+#set footnote.entry(min-body: 3em)
+
+--- footnote-entry-min-body-bad ---
+// Error: 31-34 expected length, found ratio
+#set footnote.entry(min-body: 20%)
+
+--- footnote-entry-min-body-deferred ---
+// A footnote in the first line of a column is deferred to the next column when
+// there isn't enough body content above its marker.
+#set page(height: 20pt, margin: 0pt, columns: 2)
+#set text(size: 4pt)
+#set footnote.entry(min-body: 3em, separator: none)
+#show footnote.entry: it => [#metadata(none)<entry>#hide(it)]
+#hide[A#footnote[Note]<marker> #colbreak() B]
+#context {
+  test(locate(<marker>).position().x < 60pt, true)
+  test(locate(<entry>).page(), 1)
+  test(locate(<entry>).position().x > 60pt, true)
+}
@@ -404,3 +404,18 @@
   table.cell(rowspan: 2)[a], table.cell(rowspan: 2)[b],
   table.footer()
 )
+
+--- grid-footer-no-repeat-kept-with-last-row ---
+// A non-repeated footer is kept together with the last body row. There is
+// room for three rows per column, so without this, the footer would be alone
+// in the second column.
+#context {
+  let body = table(
+    rows: 10pt,
+    inset: 0pt,
+    stroke: none,
+    [a], [b], [c],
+    table.footer(repeat: false)[Total],
+  )
+  test(measure(width: 100pt, height: 30pt, columns(2, body)).height, 20pt)
+}
@@ -30,6 +30,36 @@
   rect(fill: rgb("dddddd"))[aaa],
 )
 
+--- grid-gutter-independent-of-inset ---
+// Gutter separates the cells without enlarging them.
+#let cell = box(width: 10pt)
+#context {
+  let sizes = (0pt, 2pt).map(inset => measure(grid(
+    columns: 2,
+    column-gutter: 1cm,
+    inset: inset,
+    cell, cell,
+  )).width)
+  test(sizes, (20pt + 1cm, 28pt + 1cm))
+  test(measure(table(columns: 2, column-gutter: 1cm, cell, cell)).width, 40pt + 1cm)
+}
+
+--- grid-auto-column-max-width ---
+// An auto column with long text stops growing at its maximum width and wraps.
+#let text = lorem(40)
+#context {
+  let line = measure(grid(text))
+  let capped = measure(grid(max-width: 6cm, text))
+  test(capped.width, 6cm)
+  test(capped.height > line.height, true)
+  test(measure(grid(max-width: 6cm, [Short])).width < 6cm, true)
+  test(measure(grid(columns: 2, max-width: (none, 1cm), text, text)).width > 6cm, true)
+}
+
+--- grid-max-width-bad ---
+// Error: 18-23 expected relative length, none, or array, found string
+#grid(max-width: "1cm")
+
 --- grid-row-sizing-manual-align ---
 #set page(height: 3cm, margin: 0pt)
 #grid(
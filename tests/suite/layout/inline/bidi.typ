@@ -54,6 +54,23 @@ Lריווח #h(1cm) R
 #linebreak()
 ב #text("12:00 - 13:00", dir: ltr) בצהריים
 
+--- bidi-auto-dir ---
+// Without a language, a paragraph starting with Arabic is right-to-left and
+// one starting with Latin left-to-right. The alignment follows.
+#let pars(lang) = place(hide(block(width: 100pt, {
+  set text(lang: lang) if lang != none
+  [#metadata(lang) <arabic>مرحبا]
+  parbreak()
+  [#metadata(lang) <latin>Hello مرحبا]
+})))
+#pars("en")
+#pars(none)
+#context {
+  let xs(label) = query(label).map(m => m.location().position().x)
+  test(xs(<arabic>).at(0), xs(<latin>).at(0))
+  test(xs(<arabic>).at(1), xs(<latin>).at(1) + 100pt)
+}
+
 --- bidi-raw ---
 // Mixing raw
 #set text(lang: "he")
@@ -158,10 +158,39 @@ el objetivo de protegerle de las patrullas de milicianos.
 #set text(costs: (hyphenation: auto))
 
 --- costs-invalid-key ---
-// Error: 18-52 unexpected key "invalid-key", valid keys are "hyphenation", "runt", "widow", and "orphan"
+// Error: 18-52 unexpected key "invalid-key", valid keys are "hyphenation", "runt", "widow", "orphan", and "balance"
 #set text(costs: (hyphenation: 1%, invalid-key: 3%))
 
 --- costs-access ---
 #set text(costs: (hyphenation: 1%, runt: 2%))
 #set text(costs: (widow: 3%))
-#context test(text.costs, (hyphenation: 1%, runt: 2%, widow: 3%, orphan: 100%))
+#context test(
+  text.costs,
+  (hyphenation: 1%, runt: 2%, widow: 3%, orphan: 100%, balance: 0%),
+)
+
+--- costs-balance ---
+// Balancing the final lines doesn't make the paragraph longer.
+#set par(justify: true)
+#let sample = [Balancing moves a word down so that the final lines are even.]
+#context {
+  let plain = measure(block(width: 100pt, sample))
+  let balanced = measure(block(width: 100pt, text(costs: (balance: 1000%), sample)))
+  test(balanced.height <= plain.height, true)
+}
+
+--- costs-balance-line-widths ---
+// The balance cost lengthens the final line of the paragraph, but leaves lines
+// ending in a forced linebreak alone.
+#let sample(suffix) = [
+  Short. #metadata(none)#label("forced-" + suffix) \
+  Balancing moves a word down so that the final lines are even.
+  #metadata(none)#label("end-" + suffix)
+]
+#place(hide(block(width: 100pt, sample("plain"))))
+#place(hide(block(width: 100pt, text(costs: (balance: 1000%), sample("balanced")))))
+#context {
+  let x(key) = locate(label(key)).position().x
+  test(x("forced-balanced"), x("forced-plain"))
+  test(x("end-balanced") >= x("end-plain"), true)
+}
@@ -177,3 +177,53 @@ int main() {
 // Test whether `Formal` would be in one line.
 #set par(justify: true)
 #table(columns: 1, [Formal])
+
+--- justify-min-width ---
+// A long word is hyphenated when justified, but not when ragged.
+#let word = [incomprehensibilities]
+#let height(width, ..args) = measure(
+  width: width,
+  par(justify: true, ..args, word),
+).height
+
+#context {
+  let ragged = measure(width: 3cm, par(justify: false, word)).height
+  test(height(3cm, justify-min-width: 5cm), ragged)
+  test(height(3cm, justify-min-width: 2cm), height(3cm))
+  test(height(10cm, justify-min-width: 5cm), height(10cm))
+  test(height(3cm) > ragged, true)
+}
+
+--- justify-limit ---
+#let word = [incomprehensibilities]
+#context {
+  let ragged = measure(width: 3cm, par(justify: false, word)).height
+  test(measure(width: 3cm, par(justify: true, justify-limit: 0%, word)).height, ragged)
+}
+
+--- justify-limit-hyphenate ---
+// The ragged fallback doesn't hyphenate automatically, but keeps explicit
+// hyphenation.
+#let body = [A extraordinarily long word]
+#context {
+  let height(..args) = measure(width: 1.5cm, par(..args)).height
+  test(height(justify: true, justify-limit: 0%, body), height(body))
+  test(
+    height(justify: true, justify-limit: 0%, text(hyphenate: true, body)),
+    height(text(hyphenate: true, body)),
+  )
+}
+
+--- justify-shrink ---
+// A line that only fits by shrinking its spaces is broken instead when
+// shrinking is limited.
+#let body = [Tight lines are bad]
+#context {
+  let width = measure(body).width - 1pt
+  let height(..args) = measure(
+    width: width,
+    par(justify: true, ..args, body),
+  ).height
+  test(height(), measure(body).height)
+  test(height(justify-shrink: 0%) > height(), true)
+}
@@ -93,6 +93,55 @@ http://mydataurl/hash/12098541029831025981024980124124214/incremental/progress%l
 
 For info see #link("https://myhost.tld").
 
+--- linebreak-unbreakable-pattern ---
+// A number and its unit stay on the same line when the pair is unbreakable.
+#let parcel(kept) = context {
+  let width = measure[Parcel 5].width + 1pt
+  place(hide(block(width: width, {
+    set par(unbreakable: if kept { (regex("\d+ kg"),) } else { () })
+    [Parcel 5#metadata(kept) <number> kg#metadata(kept) <unit>]
+  })))
+}
+#parcel(false)
+#parcel(true)
+#context {
+  let ys(label) = query(label).map(m => m.location().position().y)
+  let (loose, kept) = ys(<number>).zip(ys(<unit>))
+  test(loose.at(0) < loose.at(1), true)
+  test(kept.at(0), kept.at(1))
+}
+
+--- linebreak-emergency-breaks ---
+// A word that is wider than the line is only broken when emergency breaks
+// are enabled.
+#let word(broken) = place(hide(block(width: 4cm, {
+  set par(emergency-breaks: broken)
+  [#metadata(broken) <word-start>#("x" * 60)#metadata(broken) <word-end>]
+})))
+#word(false)
+#word(true)
+#context {
+  let ys(label) = query(label).map(m => m.location().position().y)
+  let (overflowing, broken) = ys(<word-start>).zip(ys(<word-end>))
+  test(overflowing.at(0), overflowing.at(1))
+  test(broken.at(0) < broken.at(1), true)
+}
+
+--- linebreak-hyphenation-fill ---
+// A nearly empty line doesn't end with a hyphenated word, while a nearly full
+// one does.
+#let widest(fill, width) = measure(width: width, {
+  set text(hyphenate: true)
+  set par(linebreaks: "simple", hyphenation-fill: fill)
+  [A hyphenation]
+}).width
+#context {
+  let roomy = measure[A hyphenation].width - 1pt
+  test(widest(0%, roomy) < widest(90%, roomy), true)
+  let tight = measure[A hyphen-].width + 1pt
+  test(widest(90%, tight), widest(0%, tight))
+}
+
 --- issue-2105-linebreak-tofu ---
 #linebreak()中文
 
@@ -63,3 +63,11 @@ A🐈ዲሞB
 --- shaping-emoji-bad-zwj ---
 // These two shouldn't be affected by a zero-width joiner.
 🏞‍🌋
+
+--- shaping-merge-identical-styles ---
+// Adjacent text with identical styles from separate rules is shaped as one
+// run, so the font's kerning applies across the boundary.
+#context test(
+  measure[#text(red)[A]#text(red)[V]].width,
+  measure(text(red)[AV]).width,
+)
@@ -5,6 +5,31 @@
 #text(kerning: true)[Tq] \
 #text(kerning: false)[Tq]
 
+--- text-kerning-disabled-widens ---
+// Disabling kerning restores the un-kerned advance of a kerned pair.
+#context {
+  let kerned = measure[AV].width
+  let unkerned = measure(text(kerning: false)[AV]).width
+  assert(unkerned > kerned)
+}
+
+--- kern-manual ---
+// A manual kern tightens a specific pair. The glyphs around it are shaped
+// separately, so the font's kerning does not apply to the pair.
+#context {
+  let unkerned = measure(text(kerning: false)[AV]).width
+  let manual = measure[A#kern(-0.1em)V].width
+  assert(calc.abs((manual - (unkerned - 0.1em.to-absolute())).pt()) < 0.01)
+  assert(manual < unkerned)
+}
+
+--- kern-no-break ---
+// A manual kern never provides a line break opportunity.
+#context test(
+  measure(width: 1pt)[A#kern(0.5em)V].height,
+  measure[AV].height,
+)
+
 --- text-alternates-and-stylistic-sets ---
 // Test alternates and stylistic sets.
 #set text(font: "IBM Plex Serif")
@@ -38,6 +63,19 @@ waltz vs #text(discretionary-ligatures: true)[waltz]
 0 vs. #text(slashed-zero: true)[0] \
 1/2 vs. #text(fractions: true)[1/2]
 
+--- text-zero-form ---
+// A synthesized mark on the zero doesn't change its size.
+#set text(font: "DejaVu Sans Mono")
+#context test(measure(text(zero: "dotted")[0]), measure[0])
+#context test(measure(text(zero: "slashed")[0O]), measure[0O])
+#context test(text.zero, auto)
+#set text(zero: "slashed")
+#context test(text.zero, "slashed")
+
+--- text-zero-form-bad ---
+// Error: 17-25 expected "slashed", "dotted", or auto
+#set text(zero: "hollow")
+
 --- text-features ---
 // Test raw features array or dictionary.
 #text(features: ("smcp",))[Smcp] \
@@ -63,6 +101,49 @@ fi vs. #text(features: (liga: 0))[No fi]
 // Error: 21-35 expected string, found boolean
 #set text(features: ("tag", false))
 
+--- text-variations-fold ---
+#set text(variations: (wght: 650))
+#set text(variations: (wdth: 75, wght: 500))
+#context test(text.variations, (wght: 500.0, wdth: 75.0))
+
+--- text-optical-size ---
+#context test(text.optical-size, auto)
+#set text(optical-size: 48pt)
+#context test(text.optical-size, 48pt)
+
+--- text-optical-size-bad ---
+// Error: 24-29 expected length or auto, found string
+#set text(optical-size: "big")
+
+--- text-substitutions ---
+#set text(substitutions: ("&": 5))
+#set text(substitutions: ("a": "a.alt"))
+#context test(text.substitutions, ("&": 5, "a": "a.alt"))
+
+--- text-substitutions-width ---
+// The substituted glyph brings its own advance.
+#context {
+  let swapped = measure(text(substitutions: ("i": "m"))[i]).width
+  test(swapped, measure[m].width)
+  test(swapped > measure[i].width, true)
+}
+
+--- text-substitutions-bad-key ---
+// Error: 25-34 substituted text must be a single character
+#set text(substitutions: ("ab": 1))
+
+--- text-substitutions-bad-glyph ---
+// Error: 25-37 expected integer or string, found boolean
+#set text(substitutions: ("&": false))
+
+--- text-variations-bad ---
+// Error: 22-27 expected dictionary, found boolean
+#set text(variations: false)
+
+--- text-variations-bad-tag ---
+// Error: 22-35 variation axis tag must be four ASCII characters
+#set text(variations: (weight: 500))
+
 --- text-tracking-negative ---
 // Test tracking.
 #set text(tracking: -0.01em)
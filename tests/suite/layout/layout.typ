@@ -12,3 +12,18 @@
   h(1em)
   place(left, rect(width: 80pt, stroke: blue))
 })
+
+--- responsive-choice ---
+// The two-column variant is chosen in wide and the stacked one in narrow
+// containers.
+#let adaptive = responsive(
+  (10cm, [#metadata("columns") <choice>]),
+  [#metadata("stacked") <choice>],
+)
+#place(hide(block(width: 16cm, adaptive)))
+#place(hide(block(width: 6cm, adaptive)))
+#context test(query(<choice>).map(it => it.value), ("columns", "stacked"))
+
+--- responsive-bad-alternative ---
+// Error: 13-28 alternative must be content or an array of a width and content
+#responsive((1cm, [A], [B]))
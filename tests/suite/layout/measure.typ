@@ -21,6 +21,37 @@
   assert(d2.height > 50pt)
 }
 
+--- measure-paragraph-narrower-is-taller ---
+// A paragraph measured in a narrower width wraps into more lines.
+#context {
+  let body = lorem(60)
+  let narrow = measure(width: 8cm, body)
+  let wide = measure(width: 16cm, body)
+  assert(narrow.height > wide.height)
+  assert(narrow.width <= 8cm)
+  assert(wide.width <= 16cm)
+}
+
+--- measure-baselines ---
+// The baseline of a line lies at the font's cap height, which is the default
+// top edge, and scales with the font size.
+#context {
+  let small = measure(text(10pt)[Hello])
+  let large = measure(text(20pt)[Hello])
+  assert(calc.abs(small.baseline - small.cap-height) < 0.01pt)
+  assert(calc.abs(small.baseline - small.height) < 0.01pt)
+  assert(small.x-height < small.cap-height)
+  assert(calc.abs(large.baseline - 2 * small.baseline) < 0.01pt)
+}
+
+#context {
+  let m = measure(block(width: 100pt)[A \ B])
+  assert(m.last-baseline > m.baseline)
+  assert(calc.abs(m.last-baseline - m.height) < 0.01pt)
+  test(measure(rect()).baseline, none)
+  test(measure(rect()).cap-height, none)
+}
+
 --- measure-counter-width ---
 // Measure a counter. Tests that the introspector-assisted location assignment
 // is able to take `here()` from the context into account to find the closest
@@ -93,6 +93,18 @@
 // Ensure that specific margins override general margins.
 #[#set page(margin: (rest: 0pt, left: 20pt)); Overridden]
 
+--- page-margin-fractional ---
+// A fractional margin shares the space left by the fixed margins with the
+// text block.
+#set page(height: 20pt, margin: (left: 20pt, right: 1fr, y: 0pt))
+#layout(size => assert.eq(size.width, 50pt))
+#context assert.eq(here().position().x, 20pt)
+
+--- page-margin-fractional-both ---
+#set page(height: 20pt, margin: (left: 1fr, right: 2fr, y: 0pt))
+#layout(size => assert.eq(size.width, 30pt))
+#context assert.eq(here().position().x, 30pt)
+
 --- page-margin-inside-outside-override ---
 #set page(height: 100pt, margin: (inside: 30pt, outside: 20pt))
 #set par(justify: true)
@@ -137,6 +149,62 @@
 // Error: 20-23 must be `left` or `right`
 #set page(binding: top)
 
+--- page-min-fill ---
+#context test(page.min-fill, none)
+#set page(min-fill: 20%)
+#context test(page.min-fill, 20%)
+
+--- page-min-fill-footer ---
+// The previous page extends its body up to the footer to avoid a widow page.
+#set page(
+  height: 20pt,
+  margin: (x: 10pt, top: 0pt, bottom: 10pt),
+  min-fill: 50%,
+  footer-descent: 2pt,
+  footer: [#metadata(none) <footer>],
+)
+#set block(spacing: 0pt)
+#hide(block(height: 9pt))
+#hide(block(height: 2pt))
+#metadata(none) <last>
+#context {
+  test(counter(page).final(), (1,))
+  test(locate(<last>).position().y, 11pt)
+  test(query(<footer>).first().location().position().y, 12pt)
+}
+
+--- page-min-fill-bad ---
+// Error: 20-23 expected ratio or none, found length
+#set page(min-fill: 2pt)
+
+--- page-shrink ---
+// Content that would take 1.2 pages is scaled down to fit onto one.
+#set page(height: 20pt, margin: 0pt, shrink: 50%)
+#set block(spacing: 0pt)
+#hide(block(height: 12pt))
+#hide(block(height: 12pt))
+#metadata(none) <end>
+#context {
+  test(page.shrink, 50%)
+  test(counter(page).final(), (1,))
+  let y = locate(<end>).position().y
+  assert(19pt < y and y <= 20pt)
+}
+
+--- page-shrink-min-fill ---
+// Content that can't be shrunk enough still avoids a widow page by extending
+// into the bottom margin.
+#set page(
+  height: 20pt,
+  margin: (top: 0pt, x: 0pt, bottom: 10pt),
+  shrink: 95%,
+  min-fill: 50%,
+)
+#set block(spacing: 0pt)
+#hide(block(height: 10pt))
+#hide(block(height: 1pt))
+#context test(counter(page).final(), (1,))
+
 --- page-marginals ---
 #set page(
   paper: "a8",
@@ -157,6 +225,18 @@
 #set page(header: none, height: auto, margin: (top: 15pt, bottom: 25pt))
 Z
 
+--- page-corners ---
+// Corner content is anchored at a distance from the page's edges.
+#set page(height: 20pt, corners: (
+  page.corner(top + right, dx: 1cm, dy: 5pt)[#metadata(none) <logo>],
+))
+
+#context {
+  let pos = locate(<logo>).position()
+  assert(calc.abs(pos.x - (120pt - 1cm)) < 0.01pt)
+  assert(calc.abs(pos.y - 5pt) < 0.01pt)
+}
+
 --- page-number-align-top-right ---
 #set page(
   height: 100pt,
@@ -384,3 +464,38 @@ text
 #metadata(none)
 #set page(fill: red)
 2
+
+--- page-exclude ---
+// Lines next to an exclusion are shortened and resume at full width below it.
+#set page(
+  height: 20pt,
+  margin: (x: 10pt, y: 0pt),
+  exclude: ((x: 0pt, y: 0pt, width: 40pt, height: 4pt),),
+)
+#set text(size: 4pt)
+#hide[#metadata(none) <beside> Beside]
+
+#hide[#metadata(none) <below> Below]
+
+#context {
+  test(locate(<beside>).position().x, 50pt)
+  test(locate(<below>).position().x, 10pt)
+}
+
+--- page-exclude-after-float ---
+// Lines below a top float are shortened where they end up, not where they
+// would be without the float.
+#set page(
+  height: 30pt,
+  margin: (x: 10pt, y: 0pt),
+  exclude: ((x: 0pt, y: 8pt, width: 40pt, height: 4pt),),
+)
+#set text(size: 4pt)
+#place(top, float: true, clearance: 0pt, block(height: 8pt))
+#hide[#metadata(none) <beside> Beside]
+
+#context test(locate(<beside>).position().x, 50pt)
+
+--- page-exclude-bad-key ---
+// Error: 20-39 unexpected key "z", valid keys are "x", "y", "width", and "height"
+#set page(exclude: ((x: 1pt, z: 2pt),))
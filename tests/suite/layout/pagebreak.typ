@@ -114,6 +114,18 @@ Second
 
 Third
 
+--- pagebreak-weak-chapter ---
+// A weak pagebreak before a chapter heading at the top of a fresh page doesn't
+// insert an empty page.
+#set page(height: 20pt, margin: 0pt)
+#set text(6pt)
+#show heading: it => pagebreak(weak: true) + hide(it)
+= Chapter
+#context {
+  test(counter(page).final(), (1,))
+  test(locate(heading).page(), 1)
+}
+
 --- issue-2134-pagebreak-bibliography ---
 // Test weak pagebreak before bibliography.
 #pagebreak(weak: true)
@@ -13,3 +13,14 @@
 --- double-percent-error ---
 // Error: 7-8 the character `%` is not valid in code
 #(3.1%%)
+
+--- relative-to-container-width ---
+// Relative lengths resolve against the content width of the surrounding
+// container, not against the page.
+#context {
+  let x(label) = locate(label).position().x
+  assert(calc.abs((x(<plain>) - 10pt - 8cm).pt()) < 0.01)
+  assert(calc.abs((x(<padded>) - 10pt - 1cm - 6.4cm).pt()) < 0.01)
+}
+#place(hide(box(width: 10cm)[#box(line(length: 80%))#metadata(none) <plain>]))
+#place(hide(box(width: 10cm, inset: 1cm)[#box(line(length: 80%))#metadata(none) <padded>]))
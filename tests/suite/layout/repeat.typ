@@ -57,3 +57,20 @@ A#box(width: 1fr, repeat(rect(width: 2em, height: 1em), gap: 1em))B
 #set repeat(justify: false)
 #set align(right)
 A#box(width: 1fr, repeat(rect(width: 2em, height: 1em), gap: 1em))B
+
+--- watermark-fills-region ---
+#context test(
+  measure(watermark[CONFIDENTIAL], width: 50pt, height: 30pt),
+  (width: 50pt, height: 30pt),
+)
+
+--- watermark-unbounded ---
+// Error: 18-30 watermark with no size restrictions
+#context measure(watermark[X])
+
+--- watermark-tiles ---
+// The tiling is clipped to the region, however large the rotated tiles get.
+#context test(
+  measure(watermark(angle: 30deg, spacing: 10pt, rect(width: 40pt)), width: 30pt, height: 20pt),
+  (width: 30pt, height: 20pt),
+)
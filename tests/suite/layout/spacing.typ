@@ -76,3 +76,187 @@ This is the first line \ #h(2cm, weak: false) A new line
 
 --- issue-5253-consecutive-weak-space-math ---
 $= thin thin$ a
+
+--- spacing-v-fr-before-aligned-block ---
+// Fractional spacing consumes the free space before alignment, so a
+// bottom-aligned block isn't offset twice.
+#set page(height: 20pt, margin: 0pt)
+#v(1fr)
+#align(bottom, hide(block(height: 10pt)[#metadata(none)<bottom>]))
+#context test(locate(<bottom>).position().y, 10pt)
+
+--- spacing-v-fr-order ---
+// Fractional spacing takes the free space from a centered block by default,
+// but fills the gap that its alignment leaves when distributed after it.
+#let region(order, key, align-y) = place(hide({
+  set block(spacing: 0pt)
+  block(fr-order: order, height: 100pt, {
+    block(height: 10pt)[#metadata(none)#label(key + "-top")]
+    v(1fr)
+    align(align-y, block(height: 10pt)[#metadata(none)#label(key)])
+  })
+}))
+#region("before-align", "before", horizon)
+#region("after-align", "after", horizon)
+#region("after-align", "after-bottom", bottom)
+#context {
+  let offset(key) = {
+    locate(label(key)).position().y - locate(label(key + "-top")).position().y
+  }
+  test(offset("before"), 90pt)
+  test(offset("after"), 50pt)
+  test(offset("after-bottom"), 90pt)
+}
+
+--- spacing-v-fr-order-bad ---
+// Error: 22-28 expected "before-align" or "after-align"
+#set block(fr-order: "late")
+
+--- spacing-v-em-scales-with-font-size ---
+// Em-based vertical spacing resolves against the current font size.
+#let gap(size) = measure({
+  set text(size: size)
+  set block(spacing: 0pt)
+  block(height: 0pt)
+  v(2em)
+  block(height: 0pt)
+}).height
+
+#context {
+  test(gap(10pt), 20pt)
+  test(gap(20pt), 40pt)
+}
+
+--- spacing-block-em-scales-with-nested-font-size ---
+// Em-based spacing around a block resolves against the block's own font size,
+// also when it is set in nested styled scopes.
+#let gap(body) = measure({
+  set block(spacing: 0pt)
+  block(height: 0pt)
+  body
+  block(height: 0pt)
+}).height
+
+#let spaced = block(above: 1em, below: 1em, height: 0pt)
+#context {
+  test(gap(text(size: 10pt, spaced)), 20pt)
+  test(gap(text(size: 10pt, text(size: 200%, spaced))), 40pt)
+}
+
+--- spacing-v-penalty ---
+// The column breaks at the preferred spacing instead of the last possible
+// point, and never at spacing that avoids a break.
+#let item(name) = block(width: 100%, height: 10pt, fill: aqua, metadata(name))
+#let col(penalties) = place(hide(block(width: 100pt, height: 30pt, {
+  set block(spacing: 0pt)
+  columns(2, gutter: 0pt, {
+    item("a")
+    v(2pt, penalty: penalties.at(0))
+    item("b")
+    v(2pt, penalty: penalties.at(1))
+    item("c")
+  })
+})))
+
+#col((auto, auto))
+#col(("prefer", auto))
+#col((auto, "avoid"))
+
+#context {
+  let xs(name) = query(metadata)
+    .filter(it => it.value == name)
+    .map(it => it.location().position().x)
+  let (a, b, c) = ("a", "b", "c").map(xs)
+  // Without penalties, only the last item moves to the second column.
+  test(a.at(0) == b.at(0) and b.at(0) < c.at(0), true)
+  // With a preferred break after the first item, the second one follows
+  // the third into the second column.
+  test(a.at(1) < b.at(1) and b.at(1) == c.at(1), true)
+  // Avoiding a break between the last two items moves them together.
+  test(a.at(2) < b.at(2) and b.at(2) == c.at(2), true)
+}
+
+--- spacing-v-penalty-limit ---
+// A preferred break that would leave most of the column empty is ignored.
+#place(hide(block(width: 100pt, height: 30pt, {
+  set block(spacing: 0pt)
+  columns(2, gutter: 0pt, {
+    block(height: 4pt, metadata("a"))
+    v(1pt, penalty: "prefer")
+    block(height: 20pt, metadata("b"))
+    block(height: 10pt, metadata("c"))
+  })
+})))
+
+#context {
+  let x(name) = query(metadata)
+    .find(it => it.value == name)
+    .location()
+    .position()
+    .x
+  test(x("a"), x("b"))
+  test(x("b") < x("c"), true)
+}
+
+--- spacing-v-penalty-bad ---
+// Error: 18-25 expected "prefer", "avoid", or auto
+#v(1em, penalty: "never")
+
+--- spacing-v-collapse ---
+// Adjacent strong spacings add up by default and take the maximum under
+// `max`.
+#let gap(mode) = place(hide({
+  set v(collapse: mode)
+  [#metadata(mode) <start>]
+  v(1cm)
+  v(1cm)
+  [#metadata(mode) <end>]
+}))
+#gap("sum")
+#gap("max")
+#context {
+  let distance(mode) = {
+    let start = query(<start>).find(m => m.value == mode)
+    let end = query(<end>).find(m => m.value == mode)
+    end.location().position().y - start.location().position().y
+  }
+  test(calc.abs(distance("sum") - 2cm) < 0.01pt, true)
+  test(calc.abs(distance("max") - 1cm) < 0.01pt, true)
+}
+
+--- spacing-v-collapse-argument ---
+// The collapse mode can also be given to an individual spacing.
+#context {
+  let plain = measure(block[A #v(1cm) #v(1cm) B]).height
+  let collapsed = measure(block[A #v(1cm) #v(1cm, collapse: "max") B]).height
+  test(calc.abs(plain - collapsed - 1cm) < 0.01pt, true)
+}
+
+--- spacing-v-trim-document-start ---
+// Trimmed spacing at the start of the document leaves no gap.
+#set v(trim: true)
+#v(2cm)
+#context test(here().position().y, 10pt)
+
+--- spacing-v-trim ---
+#let body(trim) = block[
+  #set v(trim: trim)
+  #v(1cm)
+  A
+  #v(1cm)
+  B
+  #v(1cm)
+]
+#context {
+  let plain = measure(block[A #v(1cm) B]).height
+  test(calc.abs(measure(body(true)).height - plain) < 0.01pt, true)
+  test(calc.abs(measure(body(false)).height - plain - 2cm) < 0.01pt, true)
+}
+
+--- spacing-v-trim-argument ---
+// Trimming can also be requested for an individual spacing.
+#context {
+  let plain = measure(block[A]).height
+  let trimmed = measure(block[#v(1cm, trim: true) A #v(1cm, trim: true)]).height
+  test(calc.abs(trimmed - plain) < 0.01pt, true)
+}
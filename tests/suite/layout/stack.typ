@@ -89,3 +89,18 @@ World! 🌍
   // Error: 3-40 stack spacing is infinite
   stack(spacing: infinite-length)[A][B]
 })
+
+--- stack-uniform ---
+// All items take the size of the largest one along the cross axis only.
+#let button = rect.with(inset: 4pt)
+#context {
+  let items = (button[A], button[Longest], button[Mid \ Two])
+  let widest = measure(items.at(1)).width
+  let tallest = measure(items.at(2)).height
+  let natural = items.map(it => measure(it).width).sum()
+  let column = measure(stack(uniform: true, ..items))
+  let row = measure(stack(dir: ltr, uniform: true, ..items))
+  test(calc.abs(column.width - widest) < 0.01pt, true)
+  test(calc.abs(row.height - tallest) < 0.01pt, true)
+  test(calc.abs(row.width - natural) < 0.01pt, true)
+}
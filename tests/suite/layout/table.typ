@@ -3,6 +3,63 @@
 --- table-empty ---
 #table()
 
+--- table-empty-placeholder ---
+// Omitted cells show the placeholder, explicitly empty ones don't.
+#place(hide({
+  show table.cell: it => {
+    if it.body == [---] {
+      [#metadata((it.x, it.y)) <placeholder>]
+    }
+    it
+  }
+  table(columns: 2, empty: [---], [a], [], [c])
+}))
+#context test(query(<placeholder>).map(m => m.value), ((1, 1),))
+
+--- table-continued ---
+// A broken row reserves space for the continuation note, so that more of
+// its content moves to the next column.
+#let split(..args) = place(hide(block(width: 200pt, height: 40pt, columns(2, {
+  table(..args, lorem(6))
+  [#metadata(none) <end>]
+}))))
+#split()
+#split(continued: block(height: 20pt))
+#context {
+  let (plain, noted) = query(<end>).map(m => m.location().position())
+  test(plain.x, noted.x)
+  assert(noted.y > plain.y)
+}
+
+--- table-continued-position ---
+// The note is laid out once, directly below the unfinished part of the row,
+// and space is only reserved in regions the row continues past.
+#place(hide(block(height: 40pt, columns(2, table(
+  inset: 0pt,
+  continued: [#metadata(none) <note> (cont.)],
+  [#block(height: 30pt) #block(height: 20pt)],
+)))))
+#context {
+  let notes = query(<note>)
+  test(notes.len(), 1)
+  let pos = notes.first().location().position()
+  test(pos.x, 10pt)
+  assert(pos.y > 40pt)
+}
+
+--- table-continued-fits ---
+// A row that fits without the reserved space doesn't break.
+#place(hide(block(height: 40pt, columns(2, table(
+  inset: 0pt,
+  continued: [#metadata(none) <note> (cont.)],
+  block(height: 38pt),
+)))))
+#context test(query(<note>).len(), 0)
+
+--- table-continued-bad ---
+// Error: 19-22 expected content or none, found length
+#table(continued: 1pt)
+
 --- table-newlines ---
 #set page(height: 70pt)
 #set table(fill: (x, y) => if calc.even(x + y) { rgb("aaa") })
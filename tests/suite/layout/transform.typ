@@ -165,3 +165,16 @@ Hello #skewed[World]!\
 
 #set skew(reflow: true)
 Hello #skewed[World]!
+
+--- transform-rotate-group ---
+// A group of stacked boxes is rotated as one unit.
+#context {
+  let group = stack(
+    box(width: 10pt, height: 5pt),
+    box(width: 10pt, height: 5pt),
+    box(width: 10pt, height: 5pt),
+  )
+  let size = measure(rotate(90deg, reflow: true, group))
+  test(calc.abs((size.width - 15pt).pt()) < 0.01, true)
+  test(calc.abs((size.height - 10pt).pt()) < 0.01, true)
+}
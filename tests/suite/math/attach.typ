@@ -98,6 +98,16 @@ $ limits(A)_1^2 != A_1^2 $
 $ scripts(sum)_1^2 != sum_1^2 $
 $ limits(integral)_a^b != integral_a^b $
 
+--- math-attach-limits-display-and-inline ---
+// Limits go above and below large operators in display math and beside them
+// in inline math. Placing them beside the operator makes it wider.
+#context {
+  let width(eq) = measure(eq).width
+  test(width($sum_(i=1)^n$) > width($ sum_(i=1)^n $), true)
+  test(width($limits(sum)_(i=1)^n$) < width($sum_(i=1)^n$), true)
+  test(width($ scripts(sum)_(i=1)^n $) > width($ sum_(i=1)^n $), true)
+}
+
 --- issue-math-attach-realize-panic ---
 // Error: 25-29 unknown variable: oops
 $ attach(A, t: #context oops) $
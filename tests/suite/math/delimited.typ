@@ -125,3 +125,20 @@ $ lr(size: #3em, |)_a^b lr(size: #3em, zws|)_a^b
 --- issue-4188-lr-corner-brackets ---
 // Test positioning of U+231C to U+231F
 $⌜a⌟⌞b⌝$ = $⌜$$a$$⌟$$⌞$$b$$⌝$
+
+--- math-lr-multiline ---
+// Delimiters around multi-line content enclose all of its rows.
+#context {
+  let row = measure($ (b b b b) $)
+  let multi = measure($ (a \ b b b b \ c) $)
+  assert(multi.width >= row.width)
+  assert(multi.height > 2 * row.height)
+}
+
+--- math-lr-multiline-align ---
+// Rows with alignment points keep aligning with the rest of the equation
+// instead of being enclosed as a block.
+#context test(
+  measure($ x &= (a \ &= b c) $).height,
+  measure($ x &= paren.l a \ &= b c paren.r $).height,
+)
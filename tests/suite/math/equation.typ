@@ -260,6 +260,20 @@ $ q &= sum_k ln A \
 // Test numbering on empty equations.
 #math.equation(numbering: "1", block: true, [])
 
+--- math-equation-number-break ---
+// A number that would overlap a wide equation moves to its own line.
+#set math.equation(numbering: "(1)", number-break: true)
+#let eq = $ a + b + c + d + e $
+#context {
+  let plain = measure(block(width: 100pt, {
+    set math.equation(numbering: none)
+    eq
+  }))
+  let numbered = measure(block(width: 100pt, eq))
+  let number = measure[(1)]
+  assert(calc.abs(numbered.height - plain.height - number.height) < 0.01pt)
+}
+
 --- math-equation-tag-affects-row-height ---
 // Tags should not affect the row height of equations.
 #box($ - - $, fill: silver)
@@ -279,6 +293,56 @@ $ q &= sum_k ln A \
 #box($ &- - \ &a $, fill: silver)
 #box($ &a \ &- - $, fill: silver)
 
+--- math-equation-hang-punctuation ---
+// Trailing punctuation is excluded from centering by padding the equation
+// on the left.
+#context {
+  let plain = measure($ x = 1 . $).width
+  let bare = measure($ x = 1 $).width
+  let hang(body) = measure({
+    set math.equation(hang-punctuation: true)
+    body
+  })
+  test(hang($ x = 1 . $).width > plain, true)
+  test(hang($ x = 1 $).width, bare)
+}
+
+--- math-equation-wrap ---
+// A too wide equation is broken into multiple lines and fits afterwards.
+#context {
+  let eq = $ a + b + c + d + e + f + g + h + i + j + k + l + m + n + o + p $
+  let wrap(body) = measure(width: 100pt, {
+    set math.equation(wrap: true)
+    body
+  })
+  let plain = measure(width: 100pt, eq)
+  let wrapped = wrap(eq)
+  test(plain.width > 100pt, true)
+  test(wrapped.width <= 100pt, true)
+  test(wrapped.height > plain.height, true)
+
+  // Equations that fit or are broken manually are left as is.
+  test(wrap($ a + b $), measure(width: 100pt, $ a + b $))
+  let manual = $ a + b + c + d + e + f + g + h \ + i + j + k + l + m + n + o + p $
+  test(wrap(manual), measure(width: 100pt, manual))
+}
+
+--- math-equation-wrap-indent ---
+// Continuation lines are indented by the configured amount, so a large indent
+// leaves less space per line.
+#context {
+  let eq = $ a + b + c + d + e + f + g + h + i + j + k + l + m + n + o + p $
+  let wrap(indent) = measure(width: 100pt, {
+    set math.equation(wrap: true, wrap-indent: indent)
+    eq
+  })
+  test(wrap(60pt).height > wrap(0pt).height, true)
+  test(wrap(2em), measure(width: 100pt, {
+    set math.equation(wrap: true)
+    eq
+  }))
+}
+
 --- issue-numbering-hint ---
 // In this bug, the hint and error messages for an equation
 // being reference mentioned that it was a "heading" and was
@@ -7,6 +7,13 @@ What's up?
 --- document-set-author-date ---
 #set document(author: ("A", "B"), date: datetime.today())
 
+--- document-set-link-offset ---
+#set document(link-offset: 1cm)
+
+--- document-link-offset-bad ---
+// Error: 28-31 expected length, found ratio
+#set document(link-offset: 20%)
+
 --- document-date-bad ---
 // Error: 21-28 expected datetime, none, or auto, found string
 #set document(date: "today")
@@ -52,3 +59,7 @@ Hello
   set document(author: "Changed") if "Normal" in document.author
   set document(title: "Changed") if document.title ==  "Normal"
 }
+
+--- document-booklet-bad ---
+// Error: 24-25 number must be positive
+#set document(booklet: 0)
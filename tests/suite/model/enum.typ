@@ -124,6 +124,23 @@ a + 0.
 10. b
 100. c
 
+--- enum-number-align-periods ---
+// With the default end alignment, the periods of one- and two-digit numbers
+// line up and the bodies start at the same position.
+#place(hide[
+  #set enum(numbering: n => [#n.#metadata(n) <period>], start: 9)
+  + #metadata(9) <body> Nine
+  + #metadata(10) <body> Ten
+])
+
+#context {
+  let xs(label) = query(label).map(m => m.location().position().x)
+  let (p9, p10) = xs(<period>)
+  let (b9, b10) = xs(<body>)
+  test(p9, p10)
+  test(b9, b10)
+}
+
 --- enum-number-align-specified ---
 #set enum(number-align: start)
 1.  a
@@ -175,3 +192,14 @@ a + 0.
 + f
 #align(right)[+ align]
 + h
+
+--- enum-hanging ---
+// Item bodies are flush with the surrounding text, also with an indent.
+#place(hide[
+  #set enum(hanging: true)
+  #metadata(none)<par>Text
+  + #metadata(none)<item>Item
+  #enum(indent: 5pt)[#metadata(none)<indented>Item]
+])
+#context test(locate(<item>).position().x, locate(<par>).position().x)
+#context test(locate(<indented>).position().x, locate(<par>).position().x + 5pt)
@@ -141,6 +141,29 @@ We can clearly see that @fig-cylinder and
   caption: [Hello world in _rust_],
 )
 
+--- figure-kind-independent-numbering ---
+// Figures of different kinds are numbered independently and referenced with
+// their own supplement.
+#set figure(numbering: "1")
+#let photo = figure.with(kind: "photo", supplement: [Photo])
+#let chart = figure.with(kind: "chart", supplement: [Chart])
+#place(hide[
+  #photo([A], caption: [First photo]) <photo-1>
+  #chart([B], caption: [First chart]) <chart-1>
+  #photo([C], caption: [Second photo]) <photo-2>
+  #chart([D], caption: [Second chart]) <chart-2>
+])
+
+#context {
+  let photos = counter(figure.where(kind: "photo"))
+  let charts = counter(figure.where(kind: "chart"))
+  test(photos.at(<photo-2>), (2,))
+  test(charts.at(<chart-1>), (1,))
+  test(charts.at(<chart-2>), (2,))
+  test(measure(ref(<chart-1>)), measure[Chart~1])
+  test(measure(ref(<photo-2>)), measure[Photo~2])
+}
+
 --- figure-breakable ---
 // Test breakable figures
 #set page(height: 6em)
@@ -148,6 +171,38 @@ We can clearly see that @fig-cylinder and
 
 #figure(table[a][b][c][d][e], caption: [A table])
 
+--- figure-kept-together ---
+// A figure with breakable contents that doesn't fit into the remaining space
+// moves to the next region as a whole instead of breaking.
+#context {
+  let body = {
+    set block(spacing: 0pt)
+    block(width: 100%, height: 15pt)
+    figure({
+      block(width: 100%, height: 10pt)
+      block(width: 100%, height: 10pt)
+    })
+  }
+  // The figure overflows the first column, which would get 25pt of content
+  // if the figure broke.
+  test(measure(width: 100pt, height: 30pt, columns(2, body)).height, 20pt)
+}
+
+--- figure-taller-than-region ---
+// A figure that is taller than a full region breaks if its contents are
+// breakable.
+#context {
+  let body = {
+    set block(spacing: 0pt)
+    figure({
+      for _ in range(4) {
+        block(width: 100%, height: 10pt)
+      }
+    })
+  }
+  test(measure(width: 100pt, height: 30pt, columns(2, body)).height, 30pt)
+}
+
 --- figure-caption-separator ---
 // Test custom separator for figure caption
 #set figure.caption(separator: [ --- ])
@@ -216,9 +271,21 @@ We can clearly see that @fig-cylinder and
 #set figure.caption(position: top)
 
 --- figure-caption-position-bad ---
-// Error: 31-38 expected `top` or `bottom`, found horizon
+// Error: 31-38 expected `top`, `bottom`, `left`, or `right`, found horizon
 #set figure.caption(position: horizon)
 
+--- figure-caption-position-right ---
+// A caption on the right is placed beside the body and vertically centered.
+#context {
+  let pos = locate(<side>).position()
+  assert(pos.x > 10pt + 40pt)
+  assert(pos.y > 10pt + 15pt and pos.y < 10pt + 30pt)
+}
+#place(hide(figure(
+  rect(width: 40pt, height: 40pt),
+  caption: figure.caption(position: right)[#metadata(none) <side>Side],
+)))
+
 --- figure-localization-fr ---
 // Test French
 #set text(lang: "fr")
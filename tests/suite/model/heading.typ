@@ -109,9 +109,44 @@ comment spans lines
 Not in heading
 =Nope
 
+--- heading-hanging-indent-auto ---
+// Wrapped lines are indented by the width of the numbering.
+#set heading(numbering: n => box(width: 50pt))
+#context {
+  let body = [Aa Bb Cc Dd Ee Ff Gg]
+  let hanging = measure(width: 80pt, heading(body))
+  let flush = measure(width: 80pt, heading(hanging-indent: 0pt, body))
+  test(hanging.height > flush.height, true)
+}
+
 --- heading-numbering-hint ---
 = Heading <intro>
 
 // Error: 1:19-1:25 cannot reference heading without numbering
 // Hint: 1:19-1:25 you can enable heading numbering with `#set heading(numbering: "1.")`
 Cannot be used as @intro
+
+--- heading-running ---
+// The running heads reflect the headings on and before the page.
+#set page(header: context {
+  test(heading.running(level: 1), [Usage])
+  test(heading.running(level: 2), [])
+})
+
+#place(hide[
+  = Intro
+  == Basics
+  = Usage
+])
+
+#context test(heading.running(), [Usage])
+
+--- heading-running-section ---
+#place(hide[
+  = Intro
+  == Basics
+  === Details
+])
+
+#context test(heading.running(level: 2), [Basics])
+#context test(heading.running(level: 4), [])
@@ -0,0 +1,56 @@
+// Test the index.
+
+--- index-repeated-terms ---
+#let plain(it) = if it.has("children") {
+  it.children.map(plain).join()
+} else if it.has("text") { it.text } else { " " }
+#show index.entry: it => metadata((it.term, plain(it.pages)))
+#{
+  index("Zebra")
+  index("apple")
+  counter(page).update(3)
+  index("Apple")
+  index("Zebra")
+  counter(page).update(2)
+  index("Apple")
+  index("Apple")
+  counter(page).update(4)
+  index("Apple")
+  counter(page).update(7)
+  index("Apple")
+}
+
+#place(hide(make-index()))
+
+#context test(
+  query(metadata).map(it => it.value),
+  (("Apple", "2–4, 7"), ("apple", "1"), ("Zebra", "1, 3")),
+)
+
+--- index-page-numbering ---
+// Pages are displayed with their own numbering and only pages with the same
+// numbering form a range.
+#set page(width: 120pt, height: 30pt, numbering: "i")
+#set make-index(range-separator: [ to ])
+#let plain(it) = if it.has("children") {
+  it.children.map(plain).join()
+} else if it.has("text") { it.text } else { " " }
+#show index.entry: it => metadata((it.term, plain(it.pages)))
+
+#index("Apple") #index("apple")
+#pagebreak()
+#index("Apple") #index("Zebra")
+#pagebreak()
+#index("Apple")
+
+#set page(numbering: "1")
+#counter(page).update(1)
+#index("Zebra") #index("Apple")
+#pagebreak()
+#index("Apple")
+
+#place(hide(make-index()))
+#context test(
+  query(metadata).map(it => it.value),
+  (("Apple", "i to iii, 1 to 2"), ("apple", "i"), ("Zebra", "ii, 1")),
+)
@@ -71,7 +71,9 @@ Text <hey>
 #link(<hey>)[Nope.]
 
 --- link-to-label-duplicate ---
+// Error: 1-5 label `<hey>` is attached here
 Text <hey>
+// Error: 1-5 label `<hey>` is attached here
 Text <hey>
 // Error: 2-20 label `<hey>` occurs multiple times in the document
 #link(<hey>)[Nope.]
@@ -34,6 +34,21 @@ _Shopping list_
    - C
 - D
 
+--- list-indent-body-indent ---
+// The marker is placed after `indent` and all lines of the body start
+// `body-indent` after the marker.
+#place(hide[
+  #set list(indent: 0pt, body-indent: 2em)
+  - #metadata(none)<first> A body that spans \
+    #metadata(none)<wrapped> multiple lines.
+])
+
+#context {
+  let x = 10pt + measure([•]).width + 2em.to-absolute()
+  assert(calc.abs((locate(<first>).position().x - x).pt()) < 0.01)
+  assert(calc.abs((locate(<wrapped>).position().x - x).pt()) < 0.01)
+}
+
 --- list-indent-trivia-nesting ---
 // Test indent nesting behavior with odd trivia (comments and spaces). The
 // comments should _not_ affect the nesting. Only the final column matters.
@@ -233,3 +248,20 @@ World
 - h
 #align(right)[- i]
 - j
+
+--- list-wide-spacing-collapse ---
+// The gap above a wide list equals the one between two paragraphs.
+#context {
+  let pars = measure(block(width: 100pt)[A #parbreak() B])
+  let list = measure(block(width: 100pt)[A #list(tight: false)[B]])
+  test(list.height, pars.height)
+}
+
+--- list-hanging ---
+// Item bodies are flush with the surrounding text.
+#place(hide[
+  #set list(hanging: true)
+  #metadata(none)<par>Text
+  - #metadata(none)<item>Item
+])
+#context test(locate(<item>).position().x, locate(<par>).position().x)
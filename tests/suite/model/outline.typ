@@ -118,6 +118,27 @@ A
 = Analysis
 == Setup
 
+--- outline-indent-fill-levels ---
+// Sub-entries are indented per level and all entries receive the custom fill.
+#set heading(numbering: "1.")
+#show heading: none
+#show outline.entry: it => {
+  test(it.fill, repeat[-])
+  [#metadata(it.level)<entry>#it]
+}
+
+#place(hide(outline(indent: 1.5em, fill: repeat[-])))
+
+= Introduction
+== Details
+
+#context {
+  let (first, second) = query(<entry>)
+  test((first.value, second.value), (1, 2))
+  let x(it) = it.location().position().x
+  test(x(second) - x(first), 15pt)
+}
+
 --- outline-entry-complex ---
 #set page(width: 150pt, numbering: "I", margin: (bottom: 20pt))
 #set heading(numbering: "1.")
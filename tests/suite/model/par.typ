@@ -26,6 +26,38 @@ But, soft! what light through yonder window breaks?
 
 It is the east, and Juliet is the sun.
 
+--- par-spacing-mode-exact ---
+// In exact mode, fonts with different ascent have the same baseline distance.
+#let lines(font) = place(hide({
+  set text(font: font)
+  set par(spacing-mode: "exact", leading: 15pt)
+  [#metadata(font) <first>A \ #metadata(font) <second>B]
+}))
+#lines("Libertinus Serif")
+#lines("DejaVu Sans Mono")
+#context {
+  let ys(label) = query(label).map(m => m.location().position().y)
+  for (a, b) in ys(<first>).zip(ys(<second>)) {
+    assert(calc.abs(b - a - 15pt) < 0.01pt)
+  }
+}
+
+--- par-short-spacing ---
+// The spacing after single-line paragraphs is reduced when enabled.
+#let height(ratio) = measure(block(width: 100pt)[
+  #set par(spacing: 10pt, short-spacing: ratio)
+  A
+
+  B
+
+  C
+]).height
+#context {
+  let full = height(none)
+  test(calc.abs(height(100%) - full) < 0.01pt, true)
+  test(calc.abs(full - height(50%) - 10pt) < 0.01pt, true)
+}
+
 --- par-spacing-context ---
 #set par(spacing: 10pt)
 #context test(par.spacing, 10pt)
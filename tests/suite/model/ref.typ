@@ -14,7 +14,9 @@ As seen in @intro, we proceed.
 @foo
 
 --- ref-label-duplicate ---
+// Error: 1-8 label `<foo>` is attached here
 = First <foo>
+// Error: 1-9 label `<foo>` is attached here
 = Second <foo>
 
 // Error: 1-5 label `<foo>` occurs multiple times in the document
@@ -81,6 +83,65 @@ Text seen on #ref(<text>, form: "page", supplement: "Page").
 #ref(<quark>, form: "page")
 #bibliography("/assets/bib/works.bib")
 
+--- ref-group ---
+// Adjacent references to elements of the same kind are collapsed.
+#set ref(group: true)
+#place(hide[
+  #figure(rect(), caption: [A]) <ga>
+  #figure(rect(), caption: [B]) <gb>
+  #figure(rect(), caption: [C]) <gc>
+  #figure(table[], caption: [D]) <gd>
+])
+#context {
+  let width(body) = measure(body).width
+  test(width[@ga @gb], width[Figures~1 and 2])
+  test(width[@ga @gb @gc], width[Figures~1, 2, and 3])
+  test(width[@gc @gd], width[Figure~3 Table~1])
+  test(width[@ga, @gb], width[Figure~1, Figure~2])
+}
+
+--- ref-group-plural ---
+// The plural supplement is localized or user-provided.
+#set ref(group: true)
+#place(hide[
+  #figure(rect(), caption: [A]) <pa>
+  #figure(rect(), caption: [B]) <pb>
+  #figure(rect(), caption: [C]) <pc>
+])
+#context {
+  let width(body) = measure(body).width
+  test(width[#set ref(supplement: [Fig.]); @pa @pb], width[Fig.~1 and 2])
+  test(width[#set ref(plural: [Figs.]); @pa @pb], width[Figs.~1 and 2])
+  test(width[#set ref(plural: none); @pa @pb], width[1 and 2])
+}
+
+--- ref-group-plural-localized ---
+#set text(lang: "de")
+#set ref(group: true)
+#place(hide[
+  #figure(rect(), caption: [A]) <la>
+  #figure(rect(), caption: [B]) <lb>
+  #figure(rect(), caption: [C]) <lc>
+])
+#context test(
+  measure[@la @lb @lc].width,
+  measure[Abbildungen~1, 2 und 3].width,
+)
+
+--- ref-separator ---
+// Test an abbreviated supplement with a custom separator per kind.
+#set figure(supplement: [Fig.])
+#set ref(separator: it => if it.kind == image { sym.space.nobreak.narrow } else { [~] })
+#place(hide[
+  #figure(image("/assets/images/tiger.jpg", width: 10pt), caption: [A]) <sep-image>
+  #figure(table[x], caption: [B]) <sep-table>
+])
+#context {
+  let width(body) = measure(body).width
+  test(width[@sep-image], width[Fig.#sym.space.nobreak.narrow;1])
+  test(width[@sep-table], width[Fig.~1])
+}
+
 --- issue-4536-non-whitespace-before-ref ---
 // Test reference with non-whitespace before it.
 #figure[] <1>
@@ -0,0 +1,40 @@
+// Test sidenotes.
+
+--- sidenote-stacking ---
+// Two notes anchored in the same line are pushed apart.
+#set page(height: 20pt, margin: (right: 1fr, rest: 0pt))
+#box(height: 1em) #sidenote(hide[#metadata(none) <a>A])
+#box(height: 1em) #sidenote(hide[#metadata(none) <b>B])
+
+#context {
+  let a = locate(<a>).position()
+  let b = locate(<b>).position()
+  assert.eq(a.x, 60pt + 1em)
+  assert.eq(a.x, b.x)
+  assert(calc.abs(b.y - a.y - measure[B].height - 0.5em) < 0.01pt)
+}
+
+--- sidenote-two-sided ---
+// On two-sided pages, notes go into the outside margin.
+#set page(height: 20pt, margin: (inside: 20pt, outside: 1fr, y: 0pt))
+#box(height: 1em) #sidenote(hide[#metadata(none) <c>C])
+#context assert.eq(locate(<c>).position().x, 70pt + 1em)
+
+--- sidenote-gap-em ---
+// Relative lengths are resolved with the styles at the anchor.
+#set page(height: 20pt, margin: (right: 1fr, rest: 0pt))
+#box(height: 1em) #text(size: 5pt, sidenote[#metadata(none) <g>])
+#context assert.eq(locate(<g>).position().x, 60pt + 5pt)
+
+--- sidenote-clamped ---
+// Notes don't run off the bottom of the page.
+#set page(height: 20pt, margin: (right: 1fr, rest: 0pt))
+#v(12pt)
+#box(height: 1em) #sidenote(block(height: 15pt)[#metadata(none) <n>])
+#context assert.eq(locate(<n>).position().y, 5pt)
+
+--- sidenote-hidden ---
+// Notes with a hidden anchor are left out.
+#set page(height: 20pt, margin: (right: 1fr, rest: 0pt))
+#hide[A #sidenote[#metadata(none) <h>]]
+#context test(query(<h>), ())
@@ -64,3 +64,11 @@ $<==>$\
 $[|$\
 $|]$\
 $||$
+
+--- shorthand-dashes-and-ellipsis-literal-in-raw ---
+#test([--], [–])
+#test([---], [—])
+#test([...], […])
+#test(`pages 3--5`.text, "pages 3--5")
+#test(`wait...`.text, "wait...")
+#test(raw("a---b").text, "a---b")
@@ -0,0 +1,35 @@
+// Test tracked changes.
+
+--- changes-accept ---
+// Accepting drops deletions and shows insertions as normal text.
+#let draft = [Typst is #delete[not] #insert[very] great.]
+#context test(
+  measure(text(changes: "accept", draft)),
+  measure[Typst is  very great.],
+)
+
+--- changes-reject ---
+// Rejecting drops insertions and shows deletions as normal text.
+#let draft = [Typst is #delete[not] #insert[very] great.]
+#context test(
+  measure(text(changes: "reject", draft)),
+  measure[Typst is not  great.],
+)
+
+--- changes-track-keeps-both ---
+#let draft = [Typst is #delete[not] #insert[very] great.]
+#context test(measure(draft).width, measure[Typst is not very great.].width)
+
+--- changes-show-rule ---
+#set text(changes: "accept")
+#show insert: it => metadata(it.body)
+#show delete: it => metadata(it.body)
+#{
+  insert[a]
+  delete[b]
+}
+#context test(query(metadata).map(it => it.value), ([a], [b]))
+
+--- changes-bad-mode ---
+// Error: 20-28 expected "track", "accept", or "reject"
+#set text(changes: "ignore")
@@ -0,0 +1,23 @@
+// Test configurable dashes.
+
+--- dash-british ---
+// The same markup renders as a spaced en dash with the British convention.
+#context test(measure[a#dash()b].width, measure[a—b].width)
+#set dash(form: "en", spacing: "word")
+#context test(measure[a#dash()b].width, measure[a – b].width)
+
+--- dash-shorthand-show ---
+// The shorthand stays an em dash, but can be opted in with a show rule.
+#set dash(form: "en")
+#context test(measure[a---b].width, measure[a—b].width)
+#show "—": dash()
+#context test(measure[a---b].width, measure[a–b].width)
+
+--- dash-spacing ---
+#set dash(spacing: "thin")
+#context test(measure[a#dash()b].width, measure[a#sym.space.thin;—#sym.space.thin;b].width)
+#test(dash(spacing: none).spacing, none)
+
+--- dash-spacing-bad ---
+// Error: 20-26 expected "hair", "thin", "word", or none
+#set dash(spacing: "wide")
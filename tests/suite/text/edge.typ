@@ -39,3 +39,37 @@
 --- text-edge-wrong-edge ---
 // Error: 24-36 expected "baseline", "descender", "bounds", or length
 #set text(bottom-edge: "cap-height")
+
+--- text-edge-span-affects-own-lines ---
+// A span with a larger top edge only expands the lines it ends up on, even
+// when it crosses a line boundary.
+#place(hide(block(width: 100pt)[
+  #metadata(none) <l1> A \
+  #metadata(none) <l2> #text(top-edge: 20pt)[B \ #metadata(none) <l3> C] \
+  #metadata(none) <l4> D \
+  #metadata(none) <l5> E
+]))
+
+#context {
+  let ys = (<l1>, <l2>, <l3>, <l4>, <l5>).map(l => locate(l).position().y)
+  let ds = range(4).map(i => ys.at(i + 1) - ys.at(i))
+  test(ds.at(0) > ds.at(3), true)
+  test(ds.at(1) > ds.at(3), true)
+  test(calc.abs((ds.at(2) - ds.at(3)).pt()) < 0.01, true)
+}
+
+--- text-edge-inline-box-single-line ---
+// A tall inline box only increases the height of its own line.
+#place(hide(block(width: 100pt)[
+  #metadata(none) <l1> A \
+  #metadata(none) <l2> B #box(height: 20pt) \
+  #metadata(none) <l3> C \
+  #metadata(none) <l4> D
+]))
+
+#context {
+  let ys = (<l1>, <l2>, <l3>, <l4>).map(l => locate(l).position().y)
+  let ds = range(3).map(i => ys.at(i + 1) - ys.at(i))
+  test(ds.at(0) > ds.at(2), true)
+  test(calc.abs((ds.at(1) - ds.at(2)).pt()) < 0.01, true)
+}
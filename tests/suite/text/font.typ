@@ -77,6 +77,11 @@ I
 #let var = text(font: ("list-of", "nonexistent-fonts"))[don't]
 #var
 
+--- text-fill-auto ---
+// An automatic fill is kept as such and only resolved during layout.
+#set text(fill: auto)
+#context test(text.fill, auto)
+
 --- text-font-linux-libertine ---
 // Warning: 17-34 Typst's default font has changed from Linux Libertine to its successor Libertinus Serif
 // Hint: 17-34 please set the font to `"Libertinus Serif"` instead
@@ -25,6 +25,17 @@
   text(lang: "de")["Eingabeaufforderung"],
 )
 
+--- text-lang-hyphenate-mid-paragraph ---
+// Ensure that a French phrase embedded in an English sentence is hyphenated
+// with French rules.
+#set text(hyphenate: true)
+#context {
+  let phrase = [anticonstitutionnellement]
+  let mixed = measure(width: 20pt)[a #text(lang: "fr", phrase) b]
+  let french = measure(width: 20pt, text(lang: "fr")[a #phrase b])
+  test(mixed, french)
+}
+
 --- text-lang-shaping ---
 // Test that the language passed to the shaper has an effect.
 #set text(font: "Ubuntu")
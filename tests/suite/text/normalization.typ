@@ -0,0 +1,17 @@
+// Test Unicode normalization.
+
+--- text-normalization-nfc ---
+// Precomposed and decomposed input render identically when normalized.
+#set text(normalization: "nfc")
+#context test(measure("\u{e9}"), measure("e\u{301}"))
+
+--- text-normalization-nfd ---
+#set text(normalization: "nfd")
+#context test(measure("\u{e9}"), measure("e\u{301}"))
+
+--- text-normalization-none ---
+#context test(text.normalization, none)
+
+--- text-normalization-bad ---
+// Error: 25-30 expected "nfc", "nfd", or none
+#set text(normalization: "nfe")
@@ -594,6 +594,40 @@ print(x)
 print(y)
 ```
 
+--- raw-trim-dedent ---
+// Test trimming blank lines and removing shared indentation.
+#show raw: code => {
+  let expected = if code.dedent {
+    ("def f():", "", "    pass")
+  } else {
+    ("", "  def f():", "", "      pass", "")
+  }
+  test(code.lines.map(line => line.text), expected)
+}
+
+#let text = "\n  def f():\n\n      pass\n"
+#raw(block: true, trim: true, dedent: true, text)
+#raw(block: true, trim: false, dedent: false, text)
+
+--- raw-highlight-lines ---
+// Test that only the listed lines are highlighted.
+#show block: it => {
+  if it.fill != none { metadata(it.body.number) }
+  it
+}
+#place(hide(raw(block: true, highlight-lines: (2, 5), "a\nb\nc\n\ne\nf")))
+#place(hide(raw(highlight-lines: (1,), "inline")))
+#context test(query(metadata).map(m => m.value), (2, 5))
+
+--- raw-highlight-lines-layout ---
+// Highlighted lines keep their position and still wrap.
+#let code = "short\n" + "a very long line that has to wrap" + "\nend\n\nlast"
+#context {
+  let size(..args) = measure(width: 80pt, raw(block: true, ..args, code))
+  test(size(highlight-lines: (2, 4)), size())
+  test(size().height > measure(raw(block: true, "a\nb\nc\nd\ne")).height, true)
+}
+
 --- issue-3601-empty-raw ---
 // Test that empty raw block with `typ` language doesn't cause a crash.
 ```typ
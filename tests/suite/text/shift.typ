@@ -17,3 +17,31 @@ n#super[1], n#sub[2], ... n#super[N]
 #underline[The claim#super[\[4\]]] has been disputed. \
 The claim#super[#underline[\[4\]]] has been disputed. \
 It really has been#super(box(text(baseline: 0pt, underline[\[4\]]))) \
+
+--- super-ordinals ---
+// Ordinal suffixes are raised only when enabled and in supported languages.
+#show super: it => [#metadata(it.body.text) <raised>]
+#hide(place[
+  1st and 2nd \
+  #text(ordinals: true)[1st and 22nd, but not 3 rd or 4thx] \
+  #text(ordinals: true, lang: "de")[5th] \
+  #text(ordinals: true, lang: "fr")[1er et 2e, but not 2er or 1e]
+])
+#context test(query(<raised>).map(m => m.value), ("st", "nd", "er", "e"))
+
+--- super-ordinals-agreement ---
+// Suffixes must agree with their number and raw text stays flat.
+#show super: it => [#metadata(it.body.text) <raised>]
+#hide(place(text(ordinals: true)[
+  1th, 2st, 11st, 12nd, 13rd, 111th, 21st, 102nd, `3rd`, 1th or 3rd
+]))
+#context test(query(<raised>).map(m => m.value), ("th", "st", "nd", "rd"))
+
+--- super-ordinals-show-rule ---
+// Text show rules still see whole ordinals.
+#show super: it => [#metadata(it.body.text) <raised>]
+#show "1st": it => [#metadata(it.text) <matched>]
+#show regex("\d+nd"): it => [#metadata(it.text) <matched>]
+#hide(place(text(ordinals: true)[1st, 2nd, and 3rd]))
+#context test(query(<matched>).map(m => m.value), ("1st", "2nd"))
+#context test(query(<raised>).map(m => m.value), ("rd",))
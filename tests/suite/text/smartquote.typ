@@ -142,6 +142,15 @@ Some people's thought on this would be #[#set smartquote(enabled: false); "stran
 // Error: 25-45 expected 2 quotes, found 4 quotes
 #set smartquote(quotes: (single: ("'",) * 4))
 
+--- smartquote-contraction-in-single-quotes ---
+// A single quote between two letters is an apostrophe, even within a
+// single quotation that it would otherwise close.
+#set smartquote(quotes: (single: ("<", ">"), double: ("<<", ">>")))
+#context test(
+  measure['Don't].width,
+  measure(text("<Don’t")).width,
+)
+
 --- issue-3662-pdf-smartquotes ---
 // Smart quotes were not appearing in the PDF outline, because they didn't
 // implement `PlainText`.
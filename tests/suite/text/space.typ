@@ -58,3 +58,27 @@ LLLLLLLLLLLLLLLLLL R _L_
 // Test that thin spaces are preserved.
 | | U+0020 regular space \
 | | U+2009 thin space
+
+--- space-french-punctuation ---
+// French high punctuation gets a narrow no-break space, but only once.
+#let thin = sym.space.nobreak.narrow
+#context {
+  let width(lang, body) = measure(text(lang: lang, punctuation-spacing: true, body)).width
+  let manual = width("fr")[texte#thin: valeur]
+  test(width("fr")[texte: valeur], manual)
+  test(width("fr")[texte : valeur], manual)
+  test(width("fr")[#"texte :" valeur], manual)
+  test(width("fr")[« valeur »], width("fr")[«#thin;valeur#thin;»])
+  test(width("en")[texte: valeur] < manual, true)
+}
+
+--- space-french-punctuation-math-raw ---
+// Inline math gets the same spacing as text, but raw text is left alone.
+#let thin = sym.space.nobreak.narrow
+#show raw: set text(lang: "fr")
+#context {
+  let width(spacing, body) = measure(text(lang: "fr", punctuation-spacing: spacing, body)).width
+  test(width(true)[«$x$»], width(false)[«#thin$x$#thin»])
+  test(width(true)[$x$: y], width(false)[$x$#thin: y])
+  test(width(true, raw("a: « b »!")), width(false, raw("a: « b »!")))
+}
@@ -87,3 +87,17 @@
     (10pt, 10pt),
   ),
 )
+
+--- text-on-path-arc ---
+// Text set along a semicircle takes the size of the path.
+#let arc = path(
+  ((0pt, 50pt), (0pt, 27.6pt)),
+  ((50pt, 0pt), (-27.6pt, 0pt)),
+  ((100pt, 50pt), (0pt, -27.6pt)),
+)
+#context test(measure(text-on-path(arc, align: center, "Certified")), measure(arc))
+#place(hide(text-on-path(arc, offset: 5pt, "Typst")))
+
+--- text-on-path-bad-path ---
+// Error: 15-21 expected a path
+#text-on-path(rect(), "Typst")
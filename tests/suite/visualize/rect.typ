@@ -58,6 +58,17 @@
 // Error: 15-21 expected length, color, gradient, pattern, dictionary, stroke, none, or auto, found array
 #rect(stroke: (1, 2))
 
+--- rect-stroke-align-layout ---
+// The stroke alignment doesn't affect the layout.
+#set rect(width: 20pt, height: 10pt, stroke: 4pt)
+#context for align in ("inside", "center", "outside") {
+  test(measure(rect(stroke-align: align)), (width: 20pt, height: 10pt))
+}
+
+--- rect-stroke-align-bad ---
+// Error: 20-26 expected "inside", "center", or "outside"
+#rect(stroke-align: "edge")
+
 --- rect-fill-stroke ---
 #let variant = rect.with(width: 20pt, height: 10pt)
 #let items = for (i, item) in (